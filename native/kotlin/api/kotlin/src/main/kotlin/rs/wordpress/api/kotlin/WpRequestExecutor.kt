@@ -6,6 +6,7 @@ import kotlinx.coroutines.withContext
 import okhttp3.OkHttpClient
 import okhttp3.Request
 import okhttp3.RequestBody.Companion.toRequestBody
+import java.util.concurrent.TimeUnit
 import uniffi.wp_api.RequestExecutor
 import uniffi.wp_api.WpNetworkHeaderMap
 import uniffi.wp_api.WpNetworkRequest
@@ -28,7 +29,11 @@ internal class WpRequestExecutor(private val dispatcher: CoroutineDispatcher = D
                 }
             }
 
-            client.newCall(requestBuilder.build()).execute().use { response ->
+            val requestClient = request.timeoutMs()?.let { timeoutMs ->
+                client.newBuilder().callTimeout(timeoutMs.toLong(), TimeUnit.MILLISECONDS).build()
+            } ?: client
+
+            requestClient.newCall(requestBuilder.build()).execute().use { response ->
                 return@withContext WpNetworkResponse(
                     body = response.body?.bytes() ?: ByteArray(0),
                     statusCode = response.code.toUShort(),
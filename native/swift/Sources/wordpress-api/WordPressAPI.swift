@@ -127,6 +127,9 @@ public extension WpNetworkRequest {
         request.httpMethod = self.method().rawValue
         request.allHTTPHeaderFields = self.headerMap().toFlatMap()
         request.httpBody = self.body()?.contents()
+        if let timeoutMs = self.timeoutMs() {
+            request.timeoutInterval = TimeInterval(timeoutMs) / 1000
+        }
         return request
     }
 
@@ -207,8 +210,10 @@ extension RequestMethod {
         case .get: "GET"
         case .post: "POST"
         case .put: "PUT"
+        case .patch: "PATCH"
         case .delete: "DELETE"
         case .head: "HEAD"
+        case .options: "OPTIONS"
         }
     }
 }
@@ -64,6 +64,8 @@ class LoginTests: XCTestCase {
                 }
 
                 XCTAssertTrue(notWordPressSiteError, "Error is not 'fetchApiRootUrlFailed': \(error)")
+            case .XmlRpcAvailable:
+                XCTFail("Unexpected error: \(error)")
             }
         } catch {
             XCTFail("Unexpected error: \(error)")
@@ -109,6 +111,8 @@ class LoginTests: XCTestCase {
                 }
 
                 XCTAssertTrue(notWordPressSiteError, "Error is not 'fetchApiRootUrlFailed': \(error)")
+            case .XmlRpcAvailable:
+                XCTFail("Unexpected error: \(error)")
             }
         } catch {
             XCTFail("Unexpected error: \(error)")
@@ -0,0 +1,7 @@
+#!/bin/bash -eu
+
+# The project should be mounted to this location
+cd /app
+
+# Record the cassettes of the tests that don't modify the test server
+WP_API_CASSETTE_MODE=record cargo test -p wp_api_integration_tests --test '*_immut' --test '*_err' --no-fail-fast
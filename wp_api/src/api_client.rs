@@ -3,11 +3,34 @@ use crate::request::{
         application_passwords_endpoint::{
             ApplicationPasswordsRequestBuilder, ApplicationPasswordsRequestExecutor,
         },
+        block_patterns_endpoint::{BlockPatternsRequestBuilder, BlockPatternsRequestExecutor},
+        block_renderer_endpoint::{BlockRendererRequestBuilder, BlockRendererRequestExecutor},
+        block_types_endpoint::{BlockTypesRequestBuilder, BlockTypesRequestExecutor},
+        font_collections_endpoint::{
+            FontCollectionsRequestBuilder, FontCollectionsRequestExecutor,
+        },
+        font_faces_endpoint::{FontFacesRequestBuilder, FontFacesRequestExecutor},
+        font_families_endpoint::{FontFamiliesRequestBuilder, FontFamiliesRequestExecutor},
+        global_styles_endpoint::{GlobalStylesRequestBuilder, GlobalStylesRequestExecutor},
+        menu_items_endpoint::{MenuItemsRequestBuilder, MenuItemsRequestExecutor},
+        menu_locations_endpoint::{MenuLocationsRequestBuilder, MenuLocationsRequestExecutor},
+        menus_endpoint::{MenusRequestBuilder, MenusRequestExecutor},
+        navigation_endpoint::{NavigationRequestBuilder, NavigationRequestExecutor},
+        oembed_endpoint::{OEmbedRequestBuilder, OEmbedRequestExecutor},
         plugins_endpoint::{PluginsRequestBuilder, PluginsRequestExecutor},
         post_types_endpoint::{PostTypesRequestBuilder, PostTypesRequestExecutor},
         posts_endpoint::{PostsRequestBuilder, PostsRequestExecutor},
+        search_endpoint::{SearchRequestBuilder, SearchRequestExecutor},
+        sidebars_endpoint::{SidebarsRequestBuilder, SidebarsRequestExecutor},
         site_settings_endpoint::{SiteSettingsRequestBuilder, SiteSettingsRequestExecutor},
+        statuses_endpoint::{StatusesRequestBuilder, StatusesRequestExecutor},
+        template_parts_endpoint::{TemplatePartsRequestBuilder, TemplatePartsRequestExecutor},
+        templates_endpoint::{TemplatesRequestBuilder, TemplatesRequestExecutor},
+        themes_endpoint::{ThemesRequestBuilder, ThemesRequestExecutor},
         users_endpoint::{UsersRequestBuilder, UsersRequestExecutor},
+        widget_types_endpoint::{WidgetTypesRequestBuilder, WidgetTypesRequestExecutor},
+        widgets_endpoint::{WidgetsRequestBuilder, WidgetsRequestExecutor},
+        wp_blocks_endpoint::{WpBlocksRequestBuilder, WpBlocksRequestExecutor},
         wp_site_health_tests_endpoint::{
             WpSiteHealthTestsRequestBuilder, WpSiteHealthTestsRequestExecutor,
         },
@@ -36,12 +59,33 @@ impl UniffiWpApiRequestBuilder {
 #[derive(Debug)]
 pub struct WpApiRequestBuilder {
     application_passwords: Arc<ApplicationPasswordsRequestBuilder>,
+    block_patterns: Arc<BlockPatternsRequestBuilder>,
+    block_renderer: Arc<BlockRendererRequestBuilder>,
+    block_types: Arc<BlockTypesRequestBuilder>,
+    font_collections: Arc<FontCollectionsRequestBuilder>,
+    font_faces: Arc<FontFacesRequestBuilder>,
+    font_families: Arc<FontFamiliesRequestBuilder>,
+    global_styles: Arc<GlobalStylesRequestBuilder>,
+    menu_items: Arc<MenuItemsRequestBuilder>,
+    menu_locations: Arc<MenuLocationsRequestBuilder>,
+    menus: Arc<MenusRequestBuilder>,
+    navigation: Arc<NavigationRequestBuilder>,
+    oembed: Arc<OEmbedRequestBuilder>,
     plugins: Arc<PluginsRequestBuilder>,
     post_types: Arc<PostTypesRequestBuilder>,
     posts: Arc<PostsRequestBuilder>,
+    search: Arc<SearchRequestBuilder>,
+    sidebars: Arc<SidebarsRequestBuilder>,
     site_settings: Arc<SiteSettingsRequestBuilder>,
+    statuses: Arc<StatusesRequestBuilder>,
+    template_parts: Arc<TemplatePartsRequestBuilder>,
+    templates: Arc<TemplatesRequestBuilder>,
+    themes: Arc<ThemesRequestBuilder>,
     users: Arc<UsersRequestBuilder>,
+    widget_types: Arc<WidgetTypesRequestBuilder>,
+    widgets: Arc<WidgetsRequestBuilder>,
     wp_site_health_tests: Arc<WpSiteHealthTestsRequestBuilder>,
+    wp_blocks: Arc<WpBlocksRequestBuilder>,
 }
 
 impl WpApiRequestBuilder {
@@ -51,12 +95,33 @@ impl WpApiRequestBuilder {
             api_base_url,
             authentication;
             application_passwords,
+            block_patterns,
+            block_renderer,
+            block_types,
+            font_collections,
+            font_faces,
+            font_families,
+            global_styles,
+            menu_items,
+            menu_locations,
+            menus,
+            navigation,
+            oembed: OEmbed,
             plugins,
             post_types,
             posts,
             users,
             site_settings,
-            wp_site_health_tests
+            statuses,
+            template_parts,
+            templates,
+            themes,
+            search,
+            sidebars,
+            widget_types,
+            widgets,
+            wp_site_health_tests,
+            wp_blocks
         )
     }
 }
@@ -83,12 +148,33 @@ impl UniffiWpApiClient {
 #[derive(Debug)]
 pub struct WpApiClient {
     application_passwords: Arc<ApplicationPasswordsRequestExecutor>,
+    block_patterns: Arc<BlockPatternsRequestExecutor>,
+    block_renderer: Arc<BlockRendererRequestExecutor>,
+    block_types: Arc<BlockTypesRequestExecutor>,
+    font_collections: Arc<FontCollectionsRequestExecutor>,
+    font_faces: Arc<FontFacesRequestExecutor>,
+    font_families: Arc<FontFamiliesRequestExecutor>,
+    global_styles: Arc<GlobalStylesRequestExecutor>,
+    menu_items: Arc<MenuItemsRequestExecutor>,
+    menu_locations: Arc<MenuLocationsRequestExecutor>,
+    menus: Arc<MenusRequestExecutor>,
+    navigation: Arc<NavigationRequestExecutor>,
+    oembed: Arc<OEmbedRequestExecutor>,
     plugins: Arc<PluginsRequestExecutor>,
     post_types: Arc<PostTypesRequestExecutor>,
     posts: Arc<PostsRequestExecutor>,
+    search: Arc<SearchRequestExecutor>,
+    sidebars: Arc<SidebarsRequestExecutor>,
     site_settings: Arc<SiteSettingsRequestExecutor>,
+    statuses: Arc<StatusesRequestExecutor>,
+    template_parts: Arc<TemplatePartsRequestExecutor>,
+    templates: Arc<TemplatesRequestExecutor>,
+    themes: Arc<ThemesRequestExecutor>,
     users: Arc<UsersRequestExecutor>,
+    widget_types: Arc<WidgetTypesRequestExecutor>,
+    widgets: Arc<WidgetsRequestExecutor>,
     wp_site_health_tests: Arc<WpSiteHealthTestsRequestExecutor>,
+    wp_blocks: Arc<WpBlocksRequestExecutor>,
 }
 
 impl WpApiClient {
@@ -104,86 +190,134 @@ impl WpApiClient {
             authentication,
             request_executor;
             application_passwords,
+            block_patterns,
+            block_renderer,
+            block_types,
+            font_collections,
+            font_faces,
+            font_families,
+            global_styles,
+            menu_items,
+            menu_locations,
+            menus,
+            navigation,
+            oembed: OEmbed,
             plugins,
             post_types,
             posts,
             site_settings,
+            statuses,
+            template_parts,
+            templates,
+            themes,
             users,
-            wp_site_health_tests
+            search,
+            sidebars,
+            widget_types,
+            widgets,
+            wp_site_health_tests,
+            wp_blocks
         )
     }
 }
 
 macro_helper::generate_endpoint_impl!(application_passwords);
+macro_helper::generate_endpoint_impl!(block_patterns);
+macro_helper::generate_endpoint_impl!(block_renderer);
+macro_helper::generate_endpoint_impl!(block_types);
+macro_helper::generate_endpoint_impl!(font_collections);
+macro_helper::generate_endpoint_impl!(font_faces);
+macro_helper::generate_endpoint_impl!(font_families);
+macro_helper::generate_endpoint_impl!(global_styles);
+macro_helper::generate_endpoint_impl!(menu_items);
+macro_helper::generate_endpoint_impl!(menu_locations);
+macro_helper::generate_endpoint_impl!(menus);
+macro_helper::generate_endpoint_impl!(navigation);
+macro_helper::generate_endpoint_impl!(oembed: OEmbed);
 macro_helper::generate_endpoint_impl!(plugins);
 macro_helper::generate_endpoint_impl!(post_types);
 macro_helper::generate_endpoint_impl!(posts);
+macro_helper::generate_endpoint_impl!(search);
+macro_helper::generate_endpoint_impl!(sidebars);
 macro_helper::generate_endpoint_impl!(site_settings);
+macro_helper::generate_endpoint_impl!(statuses);
+macro_helper::generate_endpoint_impl!(template_parts);
+macro_helper::generate_endpoint_impl!(templates);
+macro_helper::generate_endpoint_impl!(themes);
 macro_helper::generate_endpoint_impl!(users);
+macro_helper::generate_endpoint_impl!(widget_types);
+macro_helper::generate_endpoint_impl!(widgets);
 macro_helper::generate_endpoint_impl!(wp_site_health_tests);
+macro_helper::generate_endpoint_impl!(wp_blocks);
 
 mod macro_helper {
+    // The type of an endpoint is named after its camel cased `$ident`, unless a `$type_prefix` is
+    // given for names `paste` can't derive, i.e. `oembed: OEmbed`
+    macro_rules! endpoint_type {
+        ($ident:ident, $suffix:ident) => {
+            paste::paste! { [<$ident:camel $suffix>] }
+        };
+        ($ident:ident: $type_prefix:ident, $suffix:ident) => {
+            paste::paste! { [<$type_prefix $suffix>] }
+        };
+    }
+
     macro_rules! generate_endpoint_impl {
-        ($ident:ident) => {
-            paste::paste! {
-                #[uniffi::export]
-                impl UniffiWpApiRequestBuilder {
-                    fn $ident(&self) -> Arc<[<$ident:camel RequestBuilder>]> {
-                        self.inner.$ident.clone()
-                    }
+        ($ident:ident $(: $type_prefix:ident)?) => {
+            #[uniffi::export]
+            impl UniffiWpApiRequestBuilder {
+                fn $ident(&self) -> Arc<macro_helper::endpoint_type!($ident $(: $type_prefix)?, RequestBuilder)> {
+                    self.inner.$ident.clone()
                 }
+            }
 
-                impl WpApiRequestBuilder {
-                    pub fn $ident(&self) -> &[<$ident:camel RequestBuilder>] {
-                        self.$ident.as_ref()
-                    }
+            impl WpApiRequestBuilder {
+                pub fn $ident(&self) -> &macro_helper::endpoint_type!($ident $(: $type_prefix)?, RequestBuilder) {
+                    self.$ident.as_ref()
                 }
+            }
 
-                #[uniffi::export]
-                impl UniffiWpApiClient {
-                    fn $ident(&self) -> Arc<[<$ident:camel RequestExecutor>]> {
-                        self.inner.$ident.clone()
-                    }
+            #[uniffi::export]
+            impl UniffiWpApiClient {
+                fn $ident(&self) -> Arc<macro_helper::endpoint_type!($ident $(: $type_prefix)?, RequestExecutor)> {
+                    self.inner.$ident.clone()
                 }
+            }
 
-                impl WpApiClient {
-                    pub fn $ident(&self) -> &[<$ident:camel RequestExecutor>] {
-                        self.$ident.as_ref()
-                    }
+            impl WpApiClient {
+                pub fn $ident(&self) -> &macro_helper::endpoint_type!($ident $(: $type_prefix)?, RequestExecutor) {
+                    self.$ident.as_ref()
                 }
             }
         };
     }
 
     macro_rules! wp_api_request_builder {
-        ($api_base_url:ident, $authentication:ident; $($element:expr),*) => {
-            paste::paste! {
-                Self {
-                    $($element: [<$element:camel RequestBuilder>]::new(
-                        $api_base_url.clone(),
-                        $authentication.clone(),
-                    )
-                    .into(),)*
-                }
+        ($api_base_url:ident, $authentication:ident; $($element:ident $(: $type_prefix:ident)?),*) => {
+            Self {
+                $($element: <macro_helper::endpoint_type!($element $(: $type_prefix)?, RequestBuilder)>::new(
+                    $api_base_url.clone(),
+                    $authentication.clone(),
+                )
+                .into(),)*
             }
         };
     }
 
     macro_rules! wp_api_client {
-        ($api_base_url:ident, $authentication:ident, $request_executor:ident; $($element:expr),*) => {
-            paste::paste! {
-                Self {
-                    $($element: [<$element:camel RequestExecutor>]::new(
-                        $api_base_url.clone(),
-                        $authentication.clone(),
-                        $request_executor.clone(),
-                    )
-                    .into(),)*
-                }
+        ($api_base_url:ident, $authentication:ident, $request_executor:ident; $($element:ident $(: $type_prefix:ident)?),*) => {
+            Self {
+                $($element: <macro_helper::endpoint_type!($element $(: $type_prefix)?, RequestExecutor)>::new(
+                    $api_base_url.clone(),
+                    $authentication.clone(),
+                    $request_executor.clone(),
+                )
+                .into(),)*
             }
         };
     }
 
+    pub(super) use endpoint_type;
     pub(super) use generate_endpoint_impl;
     pub(super) use wp_api_client;
     pub(super) use wp_api_request_builder;
@@ -1,6 +1,6 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Eq, thiserror::Error, uniffi::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error, uniffi::Error)]
 pub enum RequestExecutionError {
     #[error(
         "Request execution failed!\nStatus Code: '{:?}'.\nResponse: '{}'",
@@ -58,7 +58,7 @@ pub(crate) struct WpError {
     pub message: String,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Eq, uniffi::Error)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, uniffi::Error)]
 pub enum WpErrorCode {
     #[serde(rename = "rest_application_password_not_found")]
     ApplicationPasswordNotFound,
@@ -94,6 +94,8 @@ pub enum WpErrorCode {
     CannotViewPlugin,
     #[serde(rename = "rest_cannot_view_plugins")]
     CannotViewPlugins,
+    #[serde(rename = "rest_forbidden")]
+    Forbidden,
     #[serde(rename = "rest_forbidden_context")]
     ForbiddenContext,
     #[serde(rename = "rest_forbidden_orderby")]
@@ -102,6 +104,9 @@ pub enum WpErrorCode {
     ForbiddenWho,
     #[serde(rename = "rest_invalid_param")]
     InvalidParam,
+    /// If the URL of an oEmbed request isn't a post on the site, or a proxied URL can't be embedded.
+    #[serde(rename = "oembed_invalid_url")]
+    OEmbedInvalidUrl,
     #[serde(rename = "rest_plugin_not_found")]
     PluginNotFound,
     #[serde(rename = "rest_type_invalid")]
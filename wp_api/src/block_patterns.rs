@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+pub struct BlockPattern {
+    pub name: String,
+    pub title: String,
+    /// The block markup of the pattern.
+    pub content: String,
+    pub description: Option<String>,
+    pub viewport_width: Option<u32>,
+    /// Whether the pattern should be shown in the inserter. Missing means `true`.
+    pub inserter: Option<bool>,
+    #[serde(default)]
+    pub categories: Vec<String>,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    /// The block types the pattern is intended to be used with, i.e. `core/template-part/header`.
+    #[serde(default)]
+    pub block_types: Vec<String>,
+    #[serde(default)]
+    pub post_types: Vec<String>,
+    #[serde(default)]
+    pub template_types: Vec<String>,
+    /// Where the pattern comes from, i.e. `core`, `theme` or `pattern-directory/core`.
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+pub struct BlockPatternCategory {
+    pub name: String,
+    pub label: String,
+    pub description: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_block_pattern_with_missing_optional_fields() {
+        let pattern: BlockPattern = serde_json::from_str(
+            r#"{
+                "name": "core/query-standard-posts",
+                "title": "Standard",
+                "content": "<!-- wp:query /-->",
+                "block_types": ["core/query"],
+                "source": "core"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(pattern.block_types, vec!["core/query".to_string()]);
+        assert!(pattern.categories.is_empty());
+        assert_eq!(pattern.inserter, None);
+    }
+}
@@ -0,0 +1,182 @@
+use std::{collections::HashMap, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+use wp_contextual::WpContextual;
+use wp_derive_request_builder::WpQueryParams;
+
+use crate::{posts::PostId, JsonValue};
+
+#[derive(Debug, Default, PartialEq, Eq, uniffi::Record, WpQueryParams)]
+pub struct BlockTypeListParams {
+    /// Limit results to block types of the given namespace, i.e. `core`.
+    #[uniffi(default = None)]
+    pub namespace: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseBlockType {
+    #[WpContext(edit, embed, view)]
+    pub api_version: Option<u32>,
+    #[WpContext(edit, embed, view)]
+    pub title: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub name: Option<BlockTypeName>,
+    #[WpContext(edit, embed, view)]
+    pub description: Option<String>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub icon: Option<String>,
+    /// The attributes of the block type, each one described by a JSON Schema.
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub attributes: Option<HashMap<String, JsonValue>>,
+    #[WpContext(edit, embed, view)]
+    pub provides_context: Option<HashMap<String, String>>,
+    #[WpContext(edit, embed, view)]
+    pub uses_context: Option<Vec<String>>,
+    #[WpContext(edit, embed, view)]
+    pub selectors: Option<JsonValue>,
+    /// The block supports, i.e. `{ "align": true, "color": { "text": true } }`.
+    #[WpContext(edit, embed, view)]
+    pub supports: Option<JsonValue>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub category: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub is_dynamic: Option<bool>,
+    #[WpContext(edit, embed, view)]
+    pub editor_script_handles: Option<Vec<String>>,
+    #[WpContext(edit, embed, view)]
+    pub script_handles: Option<Vec<String>>,
+    #[WpContext(edit, embed, view)]
+    pub view_script_handles: Option<Vec<String>>,
+    #[WpContext(edit, embed, view)]
+    pub editor_style_handles: Option<Vec<String>>,
+    #[WpContext(edit, embed, view)]
+    pub style_handles: Option<Vec<String>>,
+    #[WpContext(edit, embed, view)]
+    pub styles: Option<Vec<BlockTypeStyle>>,
+    #[WpContext(edit, embed, view)]
+    pub variations: Option<Vec<BlockTypeVariation>>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub textdomain: Option<String>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub parent: Option<Vec<BlockTypeName>>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub ancestor: Option<Vec<BlockTypeName>>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub allowed_blocks: Option<Vec<BlockTypeName>>,
+    #[WpContext(edit, embed, view)]
+    pub keywords: Option<Vec<String>>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub example: Option<JsonValue>,
+    #[WpContext(edit, embed, view)]
+    pub block_hooks: Option<HashMap<String, String>>,
+}
+
+/// The fully qualified name of a block type, i.e. `core/paragraph`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, uniffi::Record)]
+#[serde(transparent)]
+pub struct BlockTypeName {
+    pub name: String,
+}
+
+impl BlockTypeName {
+    pub fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+impl From<&str> for BlockTypeName {
+    fn from(value: &str) -> Self {
+        Self {
+            name: value.to_string(),
+        }
+    }
+}
+
+impl Display for BlockTypeName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record)]
+pub struct BlockTypeStyle {
+    pub name: String,
+    pub label: Option<String>,
+    pub inline_style: Option<String>,
+    pub style_handle: Option<String>,
+    pub is_default: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record)]
+pub struct BlockTypeVariation {
+    pub name: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub category: Option<String>,
+    /// Either the name of a Dashicon or an object describing the icon.
+    pub icon: Option<JsonValue>,
+    pub is_default: Option<bool>,
+    pub attributes: Option<JsonValue>,
+    #[serde(rename = "innerBlocks")]
+    pub inner_blocks: Option<JsonValue>,
+    pub example: Option<JsonValue>,
+    pub scope: Option<Vec<String>>,
+    pub keywords: Option<Vec<String>>,
+}
+
+// WordPress only includes the `rendered` field in the `edit` context, so the params always
+// request it. `tag` adds `"context": "edit"` to the serialized params.
+#[derive(Debug, Serialize, uniffi::Record)]
+#[serde(tag = "context", rename = "edit")]
+pub struct BlockRendererParams {
+    /// The attributes of the block, which don't need to be known to the client.
+    pub attributes: JsonValue,
+    /// The post to render the block for, i.e. for blocks that show the post title.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_id: Option<PostId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
+pub struct BlockRendererResponse {
+    pub rendered: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, unit_test_common::assert_expected_query_pairs};
+    use rstest::*;
+    use serde_json::json;
+
+    #[rstest]
+    #[case(BlockTypeListParams::default(), &[])]
+    #[case(generate!(BlockTypeListParams, (namespace, Some("core".to_string()))), &[("namespace", "core")])]
+    #[trace]
+    fn test_block_type_list_params(
+        #[case] params: BlockTypeListParams,
+        #[case] expected_pairs: &[(&str, &str)],
+    ) {
+        assert_expected_query_pairs(params.query_pairs(), expected_pairs);
+    }
+
+    #[test]
+    fn block_renderer_params_request_edit_context() {
+        let params = BlockRendererParams {
+            attributes: JsonValue(json!({ "showLabel": true })),
+            post_id: Some(PostId(4)),
+        };
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            json!({ "context": "edit", "attributes": { "showLabel": true }, "post_id": 4 })
+        );
+    }
+}
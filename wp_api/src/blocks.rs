@@ -0,0 +1,547 @@
+//! Parses & serializes post content in the WordPress block grammar.
+//!
+//! [`parse_blocks`] is a port of `@wordpress/block-serialization-default-parser`, and produces the
+//! same tree: HTML that is outside of any block becomes a "freeform" block without a
+//! `block_name`.
+//!
+//! Serializing a parsed document with [`serialize_blocks`] gives back the original document byte
+//! for byte. Each block keeps the raw text of its delimiters & attributes, which is written back
+//! as is, even if it isn't in the canonical form that WordPress writes or its attributes aren't
+//! valid JSON. Only the delimiters of a block whose `block_name` or `attrs` were changed are
+//! written the way WordPress' `serialize_blocks` writes them.
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::JsonValue;
+
+const DEFAULT_BLOCK_NAMESPACE: &str = "core/";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record)]
+pub struct Block {
+    /// The fully qualified name of the block, i.e. `core/paragraph`, or `None` for freeform HTML.
+    #[serde(rename = "blockName")]
+    pub block_name: Option<String>,
+    /// The attributes of the block as a JSON object, or `null` if they are not valid JSON.
+    pub attrs: JsonValue,
+    #[serde(rename = "innerBlocks")]
+    pub inner_blocks: Vec<Block>,
+    /// The HTML of the block, without its inner blocks.
+    #[serde(rename = "innerHTML")]
+    pub inner_html: String,
+    /// The HTML chunks of the block, with `None` in place of each of the inner blocks.
+    #[serde(rename = "innerContent")]
+    pub inner_content: Vec<Option<String>>,
+    /// The opener, or the void delimiter, of a parsed block as it's written in the document.
+    #[serde(skip)]
+    #[uniffi(default = None)]
+    pub raw_opener: Option<String>,
+    /// The closer of a parsed block as it's written in the document, or `None` if the block is
+    /// void or its closer is missing.
+    #[serde(skip)]
+    #[uniffi(default = None)]
+    pub raw_closer: Option<String>,
+    /// The attributes of a parsed block as they are written in the document, including ones that
+    /// aren't valid JSON.
+    #[serde(skip)]
+    #[uniffi(default = None)]
+    pub raw_attrs: Option<String>,
+}
+
+impl Block {
+    fn new(block_name: Option<String>, attrs: Value) -> Self {
+        Self {
+            block_name,
+            attrs: JsonValue(attrs),
+            inner_blocks: Vec::new(),
+            inner_html: String::new(),
+            inner_content: Vec::new(),
+            raw_opener: None,
+            raw_closer: None,
+            raw_attrs: None,
+        }
+    }
+
+    fn from_delimiter(delimiter: Delimiter, raw_opener: &str) -> Self {
+        Self {
+            raw_opener: Some(raw_opener.to_string()),
+            raw_attrs: delimiter.raw_attrs,
+            ..Self::new(Some(delimiter.block_name), delimiter.attrs)
+        }
+    }
+
+    fn freeform(html: &str) -> Self {
+        Self {
+            inner_html: html.to_string(),
+            inner_content: vec![Some(html.to_string())],
+            ..Self::new(None, Value::Object(Default::default()))
+        }
+    }
+}
+
+#[uniffi::export]
+pub fn parse_blocks(document: &str) -> Vec<Block> {
+    BlockParser::new(document).parse()
+}
+
+#[uniffi::export]
+pub fn serialize_blocks(blocks: &[Block]) -> String {
+    blocks.iter().map(serialize_block).collect()
+}
+
+#[uniffi::export]
+pub fn serialize_block(block: &Block) -> String {
+    let mut inner_blocks = block.inner_blocks.iter();
+    let content = block
+        .inner_content
+        .iter()
+        .map(|chunk| match chunk {
+            Some(html) => html.clone(),
+            None => inner_blocks.next().map(serialize_block).unwrap_or_default(),
+        })
+        .collect::<String>();
+    let Some(block_name) = &block.block_name else {
+        return content;
+    };
+    // The delimiter the block was parsed from, which is written back as is unless the block was
+    // changed
+    let parsed = block
+        .raw_opener
+        .as_ref()
+        .and_then(|raw_opener| Some((raw_opener, match_delimiter(raw_opener, 0)?)));
+    let is_name_unchanged = parsed
+        .as_ref()
+        .is_some_and(|(_, delimiter)| delimiter.block_name == *block_name);
+    let is_attrs_unchanged = parsed
+        .as_ref()
+        .is_some_and(|(_, delimiter)| delimiter.attrs == block.attrs.0);
+    if let Some((raw_opener, delimiter)) = &parsed {
+        // A void block that was given content needs a closer
+        let needs_closer = delimiter.kind == DelimiterKind::Void && !content.is_empty();
+        if is_name_unchanged && is_attrs_unchanged && !needs_closer {
+            return format!(
+                "{}{}{}",
+                raw_opener,
+                content,
+                block.raw_closer.as_deref().unwrap_or_default()
+            );
+        }
+    }
+    let name = block_name
+        .strip_prefix(DEFAULT_BLOCK_NAMESPACE)
+        .unwrap_or(block_name);
+    let attrs = match (&block.raw_attrs, &block.attrs.0) {
+        (Some(raw_attrs), _) if is_attrs_unchanged => format!("{} ", raw_attrs),
+        (_, Value::Object(attrs)) if !attrs.is_empty() => {
+            format!("{} ", serialize_block_attributes(&block.attrs.0))
+        }
+        _ => String::new(),
+    };
+    if content.is_empty() {
+        return format!("<!-- wp:{} {}/-->", name, attrs);
+    }
+    let closer = match &block.raw_closer {
+        Some(raw_closer) if is_name_unchanged => raw_closer.clone(),
+        _ => format!("<!-- /wp:{} -->", name),
+    };
+    format!("<!-- wp:{} {}-->{}{}", name, attrs, content, closer)
+}
+
+// Escapes the characters that could end the HTML comment or be mistaken for markup, just like
+// `serialize_block_attributes` in WordPress
+fn serialize_block_attributes(attrs: &Value) -> String {
+    attrs
+        .to_string()
+        .replace("--", "\\u002d\\u002d")
+        .replace('<', "\\u003c")
+        .replace('>', "\\u003e")
+        .replace('&', "\\u0026")
+        .replace("\\\"", "\\u0022")
+}
+
+#[derive(Debug)]
+struct Delimiter {
+    kind: DelimiterKind,
+    block_name: String,
+    attrs: Value,
+    raw_attrs: Option<String>,
+    start: usize,
+    length: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum DelimiterKind {
+    Opener,
+    Closer,
+    Void,
+}
+
+#[derive(Debug)]
+struct Frame {
+    block: Block,
+    token_start: usize,
+    token_length: usize,
+    prev_offset: usize,
+    leading_html_start: Option<usize>,
+}
+
+struct BlockParser<'a> {
+    document: &'a str,
+    offset: usize,
+    search_offset: usize,
+    output: Vec<Block>,
+    stack: Vec<Frame>,
+}
+
+impl<'a> BlockParser<'a> {
+    fn new(document: &'a str) -> Self {
+        Self {
+            document,
+            offset: 0,
+            search_offset: 0,
+            output: Vec::new(),
+            stack: Vec::new(),
+        }
+    }
+
+    fn parse(mut self) -> Vec<Block> {
+        while self.proceed() {}
+        self.output
+    }
+
+    fn proceed(&mut self) -> bool {
+        let Some(delimiter) = self.next_delimiter() else {
+            match self.stack.len() {
+                0 => self.add_freeform(),
+                _ => {
+                    while !self.stack.is_empty() {
+                        self.add_block_from_stack(None);
+                    }
+                }
+            }
+            return false;
+        };
+        let start = delimiter.start;
+        let length = delimiter.length;
+        let end = start + length;
+        let leading_html_start = (start > self.offset).then_some(self.offset);
+        match delimiter.kind {
+            DelimiterKind::Void => {
+                let block = Block::from_delimiter(delimiter, &self.document[start..end]);
+                if self.stack.is_empty() {
+                    if let Some(leading_html_start) = leading_html_start {
+                        self.output
+                            .push(Block::freeform(&self.document[leading_html_start..start]));
+                    }
+                    self.output.push(block);
+                } else {
+                    self.add_inner_block(block, start, length, None);
+                }
+            }
+            DelimiterKind::Opener => {
+                self.stack.push(Frame {
+                    block: Block::from_delimiter(delimiter, &self.document[start..end]),
+                    token_start: start,
+                    token_length: length,
+                    prev_offset: end,
+                    leading_html_start,
+                });
+            }
+            DelimiterKind::Closer => match self.stack.len() {
+                // A closer without an opener ends the parsing
+                0 => {
+                    self.add_freeform();
+                    return false;
+                }
+                1 => {
+                    self.set_raw_closer(start, end);
+                    self.add_block_from_stack(Some(start));
+                }
+                _ => {
+                    self.set_raw_closer(start, end);
+                    let mut frame = self.stack.pop().expect("The stack is not empty");
+                    let html = &self.document[frame.prev_offset..start];
+                    frame.block.inner_html.push_str(html);
+                    frame.block.inner_content.push(Some(html.to_string()));
+                    self.add_inner_block(
+                        frame.block,
+                        frame.token_start,
+                        frame.token_length,
+                        Some(end),
+                    );
+                }
+            },
+        }
+        self.offset = end;
+        true
+    }
+
+    fn set_raw_closer(&mut self, start: usize, end: usize) {
+        let frame = self.stack.last_mut().expect("Closers have an opener");
+        frame.block.raw_closer = Some(self.document[start..end].to_string());
+    }
+
+    fn add_freeform(&mut self) {
+        if self.offset < self.document.len() {
+            self.output
+                .push(Block::freeform(&self.document[self.offset..]));
+        }
+    }
+
+    fn add_inner_block(
+        &mut self,
+        block: Block,
+        token_start: usize,
+        token_length: usize,
+        last_offset: Option<usize>,
+    ) {
+        let parent = self.stack.last_mut().expect("Inner blocks have a parent");
+        parent.block.inner_blocks.push(block);
+        let html = &self.document[parent.prev_offset..token_start];
+        if !html.is_empty() {
+            parent.block.inner_html.push_str(html);
+            parent.block.inner_content.push(Some(html.to_string()));
+        }
+        parent.block.inner_content.push(None);
+        parent.prev_offset = last_offset.unwrap_or(token_start + token_length);
+    }
+
+    fn add_block_from_stack(&mut self, end_offset: Option<usize>) {
+        let Frame {
+            mut block,
+            token_start,
+            prev_offset,
+            leading_html_start,
+            ..
+        } = self.stack.pop().expect("The stack is not empty");
+        let html = &self.document[prev_offset..end_offset.unwrap_or(self.document.len())];
+        if !html.is_empty() {
+            block.inner_html.push_str(html);
+            block.inner_content.push(Some(html.to_string()));
+        }
+        if let Some(leading_html_start) = leading_html_start {
+            self.output.push(Block::freeform(
+                &self.document[leading_html_start..token_start],
+            ));
+        }
+        self.output.push(block);
+    }
+
+    fn next_delimiter(&mut self) -> Option<Delimiter> {
+        while let Some(index) = self.document[self.search_offset..].find("<!--") {
+            let start = self.search_offset + index;
+            if let Some(delimiter) = match_delimiter(self.document, start) {
+                self.search_offset = start + delimiter.length;
+                return Some(delimiter);
+            }
+            self.search_offset = start + 1;
+        }
+        None
+    }
+}
+
+// Matches a block comment delimiter at `start`, the same way as the tokenizer regex of the
+// default parser, which uses lookarounds that the `regex` crate doesn't support:
+// <!--\s+(\/)?wp:([a-z][a-z0-9_-]*\/)?([a-z][a-z0-9_-]*)\s+({...}\s+)?(\/)?-->
+fn match_delimiter(document: &str, start: usize) -> Option<Delimiter> {
+    let rest = strip_whitespace(document[start..].strip_prefix("<!--")?)?;
+    let (is_closer, rest) = match rest.strip_prefix('/') {
+        Some(rest) => (true, rest),
+        None => (false, rest),
+    };
+    let (name, rest) = split_name(rest.strip_prefix("wp:")?)?;
+    let (block_name, rest) = match rest.strip_prefix('/') {
+        Some(rest) => {
+            let (name_without_namespace, rest) = split_name(rest)?;
+            (format!("{}/{}", name, name_without_namespace), rest)
+        }
+        None => (format!("{}{}", DEFAULT_BLOCK_NAMESPACE, name), rest),
+    };
+    let rest = strip_whitespace(rest)?;
+    let (attrs, raw_attrs, rest) = if rest.starts_with('{') {
+        let (attrs, rest) = split_attrs(rest)?;
+        // Invalid attributes are parsed as `null`
+        let parsed_attrs = serde_json::from_str(attrs).unwrap_or(Value::Null);
+        (parsed_attrs, Some(attrs.to_string()), rest)
+    } else {
+        (Value::Object(Default::default()), None, rest)
+    };
+    let (is_void, rest) = match rest.strip_prefix('/') {
+        Some(rest) => (true, rest),
+        None => (false, rest),
+    };
+    let rest = rest.strip_prefix("-->")?;
+    let kind = match (is_void, is_closer) {
+        (true, _) => DelimiterKind::Void,
+        (false, true) => DelimiterKind::Closer,
+        (false, false) => DelimiterKind::Opener,
+    };
+    Some(Delimiter {
+        kind,
+        block_name,
+        attrs,
+        raw_attrs,
+        start,
+        length: document.len() - start - rest.len(),
+    })
+}
+
+// Strips at least one whitespace character
+fn strip_whitespace(s: &str) -> Option<&str> {
+    let trimmed = s.trim_start();
+    (trimmed.len() < s.len()).then_some(trimmed)
+}
+
+// Splits a name matching `[a-z][a-z0-9_-]*` from the start of `s`
+fn split_name(s: &str) -> Option<(&str, &str)> {
+    if !s.starts_with(|c: char| c.is_ascii_lowercase()) {
+        return None;
+    }
+    let end = s
+        .find(|c: char| !(c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-'))
+        .unwrap_or(s.len());
+    Some(s.split_at(end))
+}
+
+// Splits the attributes from the start of `s`. They end at the first `}` that is followed by the
+// end of the delimiter, so they may contain `}` & even `-->`.
+fn split_attrs(s: &str) -> Option<(&str, &str)> {
+    s.match_indices('}').find_map(|(index, _)| {
+        let rest = strip_whitespace(&s[index + 1..])?;
+        rest.strip_prefix('/')
+            .unwrap_or(rest)
+            .starts_with("-->")
+            .then_some((&s[..=index], rest))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+    use serde_json::json;
+
+    #[rstest]
+    #[case("", json!([]))]
+    #[case(
+        "<p>Hello</p>",
+        json!([
+            { "blockName": null, "attrs": {}, "innerBlocks": [], "innerHTML": "<p>Hello</p>", "innerContent": ["<p>Hello</p>"] }
+        ])
+    )]
+    #[case(
+        "<!-- wp:paragraph {\"align\":\"center\"} -->\n<p>Hi</p>\n<!-- /wp:paragraph -->\n\n<!-- wp:separator /-->",
+        json!([
+            { "blockName": "core/paragraph", "attrs": { "align": "center" }, "innerBlocks": [], "innerHTML": "\n<p>Hi</p>\n", "innerContent": ["\n<p>Hi</p>\n"] },
+            { "blockName": null, "attrs": {}, "innerBlocks": [], "innerHTML": "\n\n", "innerContent": ["\n\n"] },
+            { "blockName": "core/separator", "attrs": {}, "innerBlocks": [], "innerHTML": "", "innerContent": [] }
+        ])
+    )]
+    #[case(
+        "<!-- wp:columns --><div><!-- wp:column --><p>A</p><!-- /wp:column --></div><!-- /wp:columns -->",
+        json!([
+            {
+                "blockName": "core/columns",
+                "attrs": {},
+                "innerBlocks": [
+                    { "blockName": "core/column", "attrs": {}, "innerBlocks": [], "innerHTML": "<p>A</p>", "innerContent": ["<p>A</p>"] }
+                ],
+                "innerHTML": "<div></div>",
+                "innerContent": ["<div>", null, "</div>"]
+            }
+        ])
+    )]
+    #[case(
+        "<!-- wp:my-plugin/thing {\"a\": } /-->",
+        json!([
+            { "blockName": "my-plugin/thing", "attrs": null, "innerBlocks": [], "innerHTML": "", "innerContent": [] }
+        ])
+    )]
+    #[case(
+        "<!-- wp:quote --><p>Q</p>",
+        json!([
+            { "blockName": "core/quote", "attrs": {}, "innerBlocks": [], "innerHTML": "<p>Q</p>", "innerContent": ["<p>Q</p>"] }
+        ])
+    )]
+    #[case(
+        "<p>A</p><!-- /wp:quote --><p>B</p>",
+        json!([
+            { "blockName": null, "attrs": {}, "innerBlocks": [], "innerHTML": "<p>A</p><!-- /wp:quote --><p>B</p>", "innerContent": ["<p>A</p><!-- /wp:quote --><p>B</p>"] }
+        ])
+    )]
+    #[case(
+        "<!-- not a block --><!--wp:paragraph -->",
+        json!([
+            { "blockName": null, "attrs": {}, "innerBlocks": [], "innerHTML": "<!-- not a block --><!--wp:paragraph -->", "innerContent": ["<!-- not a block --><!--wp:paragraph -->"] }
+        ])
+    )]
+    fn test_parse_blocks(#[case] document: &str, #[case] expected: Value) {
+        assert_eq!(
+            serde_json::to_value(parse_blocks(document)).unwrap(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("<p>Just HTML</p>")]
+    #[case("<!-- wp:paragraph -->\n<p>Hi</p>\n<!-- /wp:paragraph -->")]
+    #[case("<!-- wp:image {\"id\":5,\"align\":\"left\"} /-->\n\n<!-- wp:my-plugin/thing /-->")]
+    #[case("<!-- wp:group {\"tagName\":\"section\"} -->\n<section><!-- wp:paragraph -->\n<p>A</p>\n<!-- /wp:paragraph -->\n\n<!-- wp:quote -->\n<blockquote><!-- wp:paragraph -->\n<p>B</p>\n<!-- /wp:paragraph --></blockquote>\n<!-- /wp:quote --></section>\n<!-- /wp:group -->")]
+    #[case("<!-- wp:paragraph {\"content\":\"\\u003cb\\u003e\\u002d\\u002d\\u0026\\u0022\"} -->\n<p>C</p>\n<!-- /wp:paragraph -->")]
+    #[case("<!-- wp:my-plugin/thing {\"a\": } /-->")]
+    #[case("<!-- wp:core/separator /-->")]
+    #[case("<!--  wp:image\n{\"id\": 5,  \"align\": \"left\"}  /-->")]
+    #[case("<!-- wp:quote --><p>Q</p><!--   /wp:paragraph  -->")]
+    #[case("<!-- wp:quote --><p>Q</p>")]
+    #[case("<!-- wp:group --><div><!-- wp:core/paragraph {\"a\":1 } --><p>A</p><!-- /wp:core/paragraph --></div>")]
+    fn test_serialize_parsed_blocks(#[case] document: &str) {
+        assert_eq!(serialize_blocks(&parse_blocks(document)), document);
+    }
+
+    #[rstest]
+    #[case(
+        "<!-- wp:my-plugin/thing {\"a\": } /-->",
+        "<!-- wp:my-plugin/other {\"a\": } /-->",
+        |block: &mut Block| block.block_name = Some("my-plugin/other".to_string())
+    )]
+    #[case(
+        "<!--  wp:image\n{\"id\": 5,  \"align\": \"left\"}  /-->",
+        "<!-- wp:image {\"id\":6,\"align\":\"left\"} /-->",
+        |block: &mut Block| block.attrs.0["id"] = json!(6)
+    )]
+    #[case(
+        "<!-- wp:core/quote --><p>Q</p><!--   /wp:core/quote  -->",
+        "<!-- wp:quote {\"x\":1} --><p>Q</p><!--   /wp:core/quote  -->",
+        |block: &mut Block| block.attrs.0 = json!({ "x": 1 })
+    )]
+    #[case(
+        "<!-- wp:paragraph {\"align\": \"center\"} -->\n<p>Hi</p>\n<!-- /wp:paragraph -->",
+        "<!-- wp:heading {\"align\": \"center\"} -->\n<p>Hi</p>\n<!-- /wp:heading -->",
+        |block: &mut Block| block.block_name = Some("core/heading".to_string())
+    )]
+    #[case(
+        "<!-- wp:separator /-->",
+        "<!-- wp:separator --><hr/><!-- /wp:separator -->",
+        |block: &mut Block| block.inner_content = vec![Some("<hr/>".to_string())]
+    )]
+    fn test_serialize_changed_block(
+        #[case] document: &str,
+        #[case] expected: &str,
+        #[case] change: fn(&mut Block),
+    ) {
+        let mut blocks = parse_blocks(document);
+        change(&mut blocks[0]);
+        assert_eq!(serialize_blocks(&blocks), expected);
+    }
+
+    #[rstest]
+    fn test_serialize_block_escapes_attributes() {
+        let block = Block::new(
+            Some("core/paragraph".to_string()),
+            json!({ "content": "<b>--&\"</b>" }),
+        );
+        assert_eq!(
+            serialize_block(&block),
+            r#"<!-- wp:paragraph {"content":"\u003cb\u003e\u002d\u002d\u0026\u0022\u003c/b\u003e"} /-->"#
+        );
+    }
+}
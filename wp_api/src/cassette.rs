@@ -0,0 +1,414 @@
+//! A cassette style [RequestExecutor] for running tests without a WordPress site.
+//!
+//! In record mode, requests are executed by the wrapped executor and every request & response
+//! pair is written to a JSON file. In replay mode, the responses are read back from that file,
+//! so the same test can run without any network access.
+//!
+//! Authentication headers & cookies are scrubbed before they are written to the file, so
+//! cassettes captured from a real site can be shared.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use crate::request::{
+    RequestExecutor, RequestMethod, WpNetworkHeaderMap, WpNetworkRequest, WpNetworkResponse,
+};
+use crate::RequestExecutionError;
+
+pub const SCRUBBED_HEADER_VALUE: &str = "[scrubbed]";
+const SCRUBBED_HEADER_NAMES: [&str; 4] = ["authorization", "cookie", "set-cookie", "x-wp-nonce"];
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum CassetteError {
+    #[error("Failed to access cassette '{}': {}", path, reason)]
+    Io { path: String, reason: String },
+    #[error("Failed to parse cassette '{}': {}", path, reason)]
+    Parsing { path: String, reason: String },
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<CassetteInteraction>,
+}
+
+impl Cassette {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, CassetteError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|e| CassetteError::Io {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        })?;
+        serde_json::from_str(&contents).map_err(|e| CassetteError::Parsing {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        })
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), CassetteError> {
+        let path = path.as_ref();
+        let io_error = |e: std::io::Error| CassetteError::Io {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        let contents =
+            serde_json::to_string_pretty(self).expect("Cassette is always serializable to JSON");
+        std::fs::write(path, contents).map_err(io_error)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CassetteInteraction {
+    pub request: CassetteRequest,
+    pub response: CassetteResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CassetteRequest {
+    pub method: RequestMethod,
+    pub url: String,
+    pub headers: BTreeMap<String, Vec<String>>,
+    pub body: Option<String>,
+}
+
+impl CassetteRequest {
+    // Headers are not taken into account, because they are scrubbed
+    fn matches(&self, request: &WpNetworkRequest) -> bool {
+        self.method == request.method
+            && self.url == request.url.0
+            && self.body == request.body_as_string()
+    }
+}
+
+impl From<&WpNetworkRequest> for CassetteRequest {
+    fn from(request: &WpNetworkRequest) -> Self {
+        Self {
+            method: request.method.clone(),
+            url: request.url.0.clone(),
+            headers: scrubbed_headers(request.header_map.to_map()),
+            body: request.body_as_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CassetteResponse {
+    Response {
+        status_code: u16,
+        headers: BTreeMap<String, Vec<String>>,
+        body: String,
+    },
+    Failure {
+        status_code: Option<u16>,
+        reason: String,
+    },
+}
+
+impl CassetteResponse {
+    fn to_result(&self) -> Result<WpNetworkResponse, RequestExecutionError> {
+        match self {
+            Self::Response {
+                status_code,
+                headers,
+                body,
+            } => Ok(WpNetworkResponse {
+                body: body.as_bytes().to_vec(),
+                status_code: *status_code,
+                header_map: WpNetworkHeaderMap::from_multi_map(
+                    headers.clone().into_iter().collect(),
+                )
+                .unwrap_or_default()
+                .into(),
+            }),
+            Self::Failure {
+                status_code,
+                reason,
+            } => Err(RequestExecutionError::RequestExecutionFailed {
+                status_code: *status_code,
+                reason: reason.clone(),
+            }),
+        }
+    }
+}
+
+impl From<&Result<WpNetworkResponse, RequestExecutionError>> for CassetteResponse {
+    fn from(result: &Result<WpNetworkResponse, RequestExecutionError>) -> Self {
+        match result {
+            Ok(response) => Self::Response {
+                status_code: response.status_code,
+                headers: scrubbed_headers(response.header_map.to_map()),
+                body: String::from_utf8_lossy(&response.body).to_string(),
+            },
+            Err(RequestExecutionError::RequestExecutionFailed {
+                status_code,
+                reason,
+            }) => Self::Failure {
+                status_code: *status_code,
+                reason: reason.clone(),
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+enum CassetteMode {
+    Record {
+        request_executor: Arc<dyn RequestExecutor>,
+    },
+    // Tracks which interactions are already replayed, so repeated requests get their responses
+    // in the recorded order.
+    Replay {
+        replayed: Mutex<Vec<bool>>,
+    },
+}
+
+#[derive(Debug)]
+pub struct CassetteRequestExecutor {
+    path: PathBuf,
+    mode: CassetteMode,
+    cassette: Mutex<Cassette>,
+}
+
+impl CassetteRequestExecutor {
+    // Executes requests with the given executor and saves them to the cassette at the given path
+    // after each request. An existing cassette will be overwritten.
+    pub fn record(path: impl Into<PathBuf>, request_executor: Arc<dyn RequestExecutor>) -> Self {
+        Self {
+            path: path.into(),
+            mode: CassetteMode::Record { request_executor },
+            cassette: Mutex::new(Cassette::default()),
+        }
+    }
+
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self, CassetteError> {
+        let path = path.into();
+        let cassette = Cassette::load(&path)?;
+        Ok(Self {
+            path,
+            mode: CassetteMode::Replay {
+                replayed: Mutex::new(vec![false; cassette.interactions.len()]),
+            },
+            cassette: Mutex::new(cassette),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestExecutor for CassetteRequestExecutor {
+    async fn execute(
+        &self,
+        request: Arc<WpNetworkRequest>,
+    ) -> Result<WpNetworkResponse, RequestExecutionError> {
+        match &self.mode {
+            CassetteMode::Record { request_executor } => {
+                let result = request_executor.execute(request.clone()).await;
+                let mut cassette = self.cassette.lock().expect("Cassette lock is not poisoned");
+                cassette.interactions.push(CassetteInteraction {
+                    request: request.as_ref().into(),
+                    response: (&result).into(),
+                });
+                cassette.save(&self.path).map_err(|e| {
+                    RequestExecutionError::RequestExecutionFailed {
+                        status_code: None,
+                        reason: e.to_string(),
+                    }
+                })?;
+                result
+            }
+            CassetteMode::Replay { replayed } => {
+                let cassette = self.cassette.lock().expect("Cassette lock is not poisoned");
+                let mut replayed = replayed.lock().expect("Cassette lock is not poisoned");
+                let index = cassette
+                    .interactions
+                    .iter()
+                    .enumerate()
+                    .position(|(index, interaction)| {
+                        !replayed[index] && interaction.request.matches(&request)
+                    })
+                    .ok_or_else(|| RequestExecutionError::RequestExecutionFailed {
+                        status_code: None,
+                        reason: format!(
+                            "No recorded interaction in '{}' for {:?}",
+                            self.path.display(),
+                            request
+                        ),
+                    })?;
+                replayed[index] = true;
+                cassette.interactions[index].response.to_result()
+            }
+        }
+    }
+}
+
+fn scrubbed_headers(headers: HashMap<String, Vec<String>>) -> BTreeMap<String, Vec<String>> {
+    headers
+        .into_iter()
+        .map(|(name, values)| {
+            let name = name.to_lowercase();
+            let values = if SCRUBBED_HEADER_NAMES.contains(&name.as_str()) {
+                values
+                    .iter()
+                    .map(|_| SCRUBBED_HEADER_VALUE.to_string())
+                    .collect()
+            } else {
+                values
+            };
+            (name, values)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::{endpoint::WpEndpointUrl, WpNetworkRequestBody};
+    use futures::executor::block_on;
+    use http::{HeaderMap, HeaderValue};
+    use rstest::*;
+
+    #[derive(Debug)]
+    struct StaticRequestExecutor;
+
+    #[async_trait::async_trait]
+    impl RequestExecutor for StaticRequestExecutor {
+        async fn execute(
+            &self,
+            request: Arc<WpNetworkRequest>,
+        ) -> Result<WpNetworkResponse, RequestExecutionError> {
+            let mut header_map = HeaderMap::new();
+            header_map.insert(
+                http::header::SET_COOKIE,
+                HeaderValue::from_static("foo=bar"),
+            );
+            Ok(WpNetworkResponse {
+                body: format!("{{\"url\":\"{}\"}}", request.url.0).into_bytes(),
+                status_code: 200,
+                header_map: WpNetworkHeaderMap::new(header_map).into(),
+            })
+        }
+    }
+
+    #[fixture]
+    fn cassette_path() -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "wp_api_cassette_{}.json",
+            crate::WpUuid::new().uuid_string()
+        ))
+    }
+
+    #[rstest]
+    fn test_record_and_replay(cassette_path: PathBuf) {
+        let recorder =
+            CassetteRequestExecutor::record(&cassette_path, Arc::new(StaticRequestExecutor));
+        let recorded_response =
+            block_on(recorder.execute(request("https://example.com/wp-json/wp/v2/users").into()))
+                .unwrap();
+
+        let player = CassetteRequestExecutor::replay(&cassette_path).unwrap();
+        let replayed_response =
+            block_on(player.execute(request("https://example.com/wp-json/wp/v2/users").into()))
+                .unwrap();
+        assert_eq!(replayed_response.body, recorded_response.body);
+        assert_eq!(replayed_response.status_code, 200);
+
+        // Every interaction is only replayed once
+        assert!(block_on(
+            player.execute(request("https://example.com/wp-json/wp/v2/users").into())
+        )
+        .is_err());
+        std::fs::remove_file(cassette_path).unwrap();
+    }
+
+    #[rstest]
+    fn test_recorded_headers_are_scrubbed(cassette_path: PathBuf) {
+        let recorder =
+            CassetteRequestExecutor::record(&cassette_path, Arc::new(StaticRequestExecutor));
+        block_on(recorder.execute(request("https://example.com/wp-json/wp/v2/users").into()))
+            .unwrap();
+
+        let interaction = Cassette::load(&cassette_path)
+            .unwrap()
+            .interactions
+            .remove(0);
+        assert_eq!(
+            interaction.request.headers.get("authorization"),
+            Some(&vec![SCRUBBED_HEADER_VALUE.to_string()])
+        );
+        assert_eq!(
+            interaction.request.headers.get("accept"),
+            Some(&vec!["application/json".to_string()])
+        );
+        if let CassetteResponse::Response { headers, .. } = interaction.response {
+            assert_eq!(
+                headers.get("set-cookie"),
+                Some(&vec![SCRUBBED_HEADER_VALUE.to_string()])
+            );
+        } else {
+            panic!("Expecting a recorded response");
+        }
+        std::fs::remove_file(cassette_path).unwrap();
+    }
+
+    #[rstest]
+    #[case(
+        "https://example.com/wp-json/wp/v2/users?page=2",
+        "https://example.com/wp-json/wp/v2/users?page=2",
+        true
+    )]
+    #[case(
+        "https://example.com/wp-json/wp/v2/users?page=2",
+        "https://example.com/wp-json/wp/v2/users?page=3",
+        false
+    )]
+    #[case(
+        "https://example.com/wp-json/wp/v2/users",
+        "https://example.com/wp-json/wp/v2/posts",
+        false
+    )]
+    #[case(
+        "https://example.com/wp-json/wp/v2/users",
+        "http://example.com/wp-json/wp/v2/users",
+        false
+    )]
+    fn test_request_matches(#[case] recorded_url: &str, #[case] url: &str, #[case] expected: bool) {
+        let recorded_request = CassetteRequest::from(&request(recorded_url));
+        assert_eq!(recorded_request.matches(&request(url)), expected);
+    }
+
+    #[rstest]
+    fn test_replay_missing_cassette(cassette_path: PathBuf) {
+        assert!(matches!(
+            CassetteRequestExecutor::replay(cassette_path),
+            Err(CassetteError::Io { .. })
+        ));
+    }
+
+    fn request(url: &str) -> WpNetworkRequest {
+        let mut header_map = HeaderMap::new();
+        header_map.insert(
+            http::header::ACCEPT,
+            HeaderValue::from_static("application/json"),
+        );
+        header_map.insert(
+            http::header::AUTHORIZATION,
+            HeaderValue::from_static("Basic dGVzdDp0ZXN0"),
+        );
+        WpNetworkRequest {
+            method: RequestMethod::GET,
+            url: WpEndpointUrl(url.to_string()),
+            header_map: WpNetworkHeaderMap::new(header_map).into(),
+            body: None::<Arc<WpNetworkRequestBody>>,
+            timeout_ms: None,
+        }
+    }
+}
@@ -0,0 +1,589 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use wp_contextual::WpContextual;
+use wp_derive_request_builder::WpQueryParams;
+
+use crate::{
+    query_params::{impl_query_param_value_for_newtype, impl_query_param_value_via_as_str},
+    request::{MultipartForm, WpMultipartFile},
+    WpApiParamOrder,
+};
+
+#[derive(Debug, Default, PartialEq, Eq, uniffi::Record, WpQueryParams)]
+pub struct FontFamilyListParams {
+    /// Current page of the collection.
+    /// Default: `1`
+    #[uniffi(default = None)]
+    pub page: Option<u32>,
+    /// Maximum number of items to be returned in result set.
+    /// Default: `10`
+    #[uniffi(default = None)]
+    pub per_page: Option<u32>,
+    /// Ensure result set excludes specific IDs.
+    #[uniffi(default = [])]
+    pub exclude: Vec<FontFamilyId>,
+    /// Limit result set to specific IDs.
+    #[uniffi(default = [])]
+    pub include: Vec<FontFamilyId>,
+    /// Order sort attribute ascending or descending.
+    /// Default: desc
+    /// One of: asc, desc
+    #[uniffi(default = None)]
+    pub order: Option<WpApiParamOrder>,
+    /// Sort collection by font family attribute.
+    /// Default: id
+    #[uniffi(default = None)]
+    pub orderby: Option<WpApiParamFontsOrderBy>,
+    /// Limit result set to font families with one or more specific slugs.
+    #[uniffi(default = [])]
+    pub slug: Vec<String>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, uniffi::Record, WpQueryParams)]
+pub struct FontFaceListParams {
+    /// Current page of the collection.
+    /// Default: `1`
+    #[uniffi(default = None)]
+    pub page: Option<u32>,
+    /// Maximum number of items to be returned in result set.
+    /// Default: `10`
+    #[uniffi(default = None)]
+    pub per_page: Option<u32>,
+    /// Ensure result set excludes specific IDs.
+    #[uniffi(default = [])]
+    pub exclude: Vec<FontFaceId>,
+    /// Limit result set to specific IDs.
+    #[uniffi(default = [])]
+    pub include: Vec<FontFaceId>,
+    /// Order sort attribute ascending or descending.
+    /// Default: desc
+    /// One of: asc, desc
+    #[uniffi(default = None)]
+    pub order: Option<WpApiParamOrder>,
+    /// Sort collection by font face attribute.
+    /// Default: id
+    #[uniffi(default = None)]
+    pub orderby: Option<WpApiParamFontsOrderBy>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum WpApiParamFontsOrderBy {
+    #[default]
+    Id,
+    Include,
+}
+
+impl WpApiParamFontsOrderBy {
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Id => "id",
+            Self::Include => "include",
+        }
+    }
+}
+
+impl_query_param_value_via_as_str!(WpApiParamFontsOrderBy, [Id, Include]);
+
+#[derive(Debug, Serialize, uniffi::Record)]
+pub struct FontFamilyCreateParams {
+    /// The version of the theme.json schema the settings are in.
+    /// Default: `3`
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme_json_version: Option<u32>,
+    // WordPress expects the settings as a JSON encoded string
+    #[serde(serialize_with = "serialize_as_json_string")]
+    pub font_family_settings: FontFamilySettings,
+}
+
+impl FontFamilyCreateParams {
+    pub fn new(font_family_settings: FontFamilySettings) -> Self {
+        Self {
+            theme_json_version: None,
+            font_family_settings,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct FontFamilyUpdateParams {
+    /// The version of the theme.json schema the settings are in.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme_json_version: Option<u32>,
+    #[uniffi(default = None)]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_as_json_string"
+    )]
+    pub font_family_settings: Option<FontFamilySettings>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
+pub struct FontFamilyDeleteResponse {
+    pub deleted: bool,
+    pub previous: FontFamilyWithEditContext,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseFontFamily {
+    #[WpContext(edit, embed, view)]
+    pub id: Option<FontFamilyId>,
+    #[WpContext(edit, embed, view)]
+    pub theme_json_version: Option<u32>,
+    #[WpContext(edit, embed, view)]
+    pub font_faces: Option<Vec<FontFaceId>>,
+    #[WpContext(edit, embed, view)]
+    pub font_family_settings: Option<FontFamilySettings>,
+}
+
+/// The theme.json settings of a font family.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+#[serde(rename_all = "camelCase")]
+pub struct FontFamilySettings {
+    pub name: String,
+    pub slug: String,
+    /// The CSS `font-family` value, i.e. `"Open Sans", sans-serif`.
+    pub font_family: String,
+    /// The URL of a preview image of the font family.
+    #[uniffi(default = None)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preview: Option<String>,
+}
+
+uniffi::custom_newtype!(FontFamilyId, i32);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FontFamilyId(pub i32);
+
+impl Display for FontFamilyId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Serialize, uniffi::Record)]
+pub struct FontFaceCreateParams {
+    /// The version of the theme.json schema the settings are in.
+    /// Default: `3`
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme_json_version: Option<u32>,
+    // WordPress expects the settings as a JSON encoded string
+    #[serde(serialize_with = "serialize_as_json_string")]
+    pub font_face_settings: FontFaceSettings,
+}
+
+impl FontFaceCreateParams {
+    pub fn new(font_face_settings: FontFaceSettings) -> Self {
+        Self {
+            theme_json_version: None,
+            font_face_settings,
+        }
+    }
+}
+
+/// Creates a font face & uploads its font files as a `multipart/form-data` request.
+#[derive(Debug, uniffi::Record)]
+pub struct FontFaceCreateWithFilesParams {
+    /// The version of the theme.json schema the settings are in.
+    /// Default: `3`
+    #[uniffi(default = None)]
+    pub theme_json_version: Option<u32>,
+    /// Each `src` of the settings should be the `field_name` of one of the `files`.
+    pub font_face_settings: FontFaceSettings,
+    pub files: Vec<WpMultipartFile>,
+}
+
+impl FontFaceCreateWithFilesParams {
+    pub fn new(font_face_settings: FontFaceSettings, files: Vec<WpMultipartFile>) -> Self {
+        Self {
+            theme_json_version: None,
+            font_face_settings,
+            files,
+        }
+    }
+
+    pub(crate) fn multipart_form(&self) -> MultipartForm {
+        let mut form = MultipartForm::new();
+        if let Some(theme_json_version) = self.theme_json_version {
+            form = form.text("theme_json_version", &theme_json_version.to_string());
+        }
+        // WordPress expects the settings as a JSON encoded string
+        form = form.text(
+            "font_face_settings",
+            &serde_json::to_string(&self.font_face_settings)
+                .expect("Font face settings can always be serialized"),
+        );
+        self.files.iter().fold(form, |form, file| form.file(file))
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
+pub struct FontFaceDeleteResponse {
+    pub deleted: bool,
+    pub previous: FontFaceWithEditContext,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseFontFace {
+    #[WpContext(edit, embed, view)]
+    pub id: Option<FontFaceId>,
+    #[WpContext(edit, embed, view)]
+    pub theme_json_version: Option<u32>,
+    #[WpContext(edit, embed, view)]
+    pub parent: Option<FontFamilyId>,
+    #[WpContext(edit, embed, view)]
+    pub font_face_settings: Option<FontFaceSettings>,
+}
+
+/// The theme.json settings of a font face, which mirror the CSS `@font-face` descriptors.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+#[serde(rename_all = "camelCase")]
+pub struct FontFaceSettings {
+    /// The CSS `font-family` value, i.e. `"Open Sans", sans-serif`.
+    pub font_family: String,
+    /// The URLs of the font files. When uploading the font files, these are the names of the form
+    /// fields the files are uploaded in instead.
+    #[serde(deserialize_with = "deserialize_string_or_vec")]
+    pub src: Vec<String>,
+    /// i.e. `normal` or `italic`
+    #[uniffi(default = None)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_style: Option<String>,
+    /// i.e. `400` or `100 900` for variable fonts.
+    #[uniffi(default = None)]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_option_string_or_number"
+    )]
+    pub font_weight: Option<String>,
+    #[uniffi(default = None)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_display: Option<String>,
+    #[uniffi(default = None)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_stretch: Option<String>,
+    #[uniffi(default = None)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ascent_override: Option<String>,
+    #[uniffi(default = None)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub descent_override: Option<String>,
+    #[uniffi(default = None)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_variant: Option<String>,
+    #[uniffi(default = None)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_feature_settings: Option<String>,
+    #[uniffi(default = None)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_variation_settings: Option<String>,
+    #[uniffi(default = None)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line_gap_override: Option<String>,
+    #[uniffi(default = None)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_adjust: Option<String>,
+    #[uniffi(default = None)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unicode_range: Option<String>,
+    /// The URL of a preview image of the font face.
+    #[uniffi(default = None)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preview: Option<String>,
+}
+
+impl FontFaceSettings {
+    pub fn new(font_family: String, src: Vec<String>) -> Self {
+        Self {
+            font_family,
+            src,
+            font_style: None,
+            font_weight: None,
+            font_display: None,
+            font_stretch: None,
+            ascent_override: None,
+            descent_override: None,
+            font_variant: None,
+            font_feature_settings: None,
+            font_variation_settings: None,
+            line_gap_override: None,
+            size_adjust: None,
+            unicode_range: None,
+            preview: None,
+        }
+    }
+}
+
+uniffi::custom_newtype!(FontFaceId, i32);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FontFaceId(pub i32);
+
+impl Display for FontFaceId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl_query_param_value_for_newtype!(FontFamilyId, FontFaceId);
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseFontCollection {
+    #[WpContext(edit, embed, view)]
+    pub slug: Option<FontCollectionSlug>,
+    #[WpContext(edit, embed, view)]
+    pub name: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub description: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub font_families: Option<Vec<FontCollectionFontFamily>>,
+    #[WpContext(edit, embed, view)]
+    pub categories: Option<Vec<FontCollectionCategory>>,
+}
+
+/// The slug of a font collection, i.e. `google-fonts`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, uniffi::Record)]
+#[serde(transparent)]
+pub struct FontCollectionSlug {
+    pub slug: String,
+}
+
+impl FontCollectionSlug {
+    pub fn new(slug: String) -> Self {
+        Self { slug }
+    }
+}
+
+impl From<&str> for FontCollectionSlug {
+    fn from(value: &str) -> Self {
+        Self {
+            slug: value.to_string(),
+        }
+    }
+}
+
+impl Display for FontCollectionSlug {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.slug)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+pub struct FontCollectionFontFamily {
+    pub font_family_settings: FontCollectionFontFamilySettings,
+    /// The slugs of the collection's categories the font family belongs to.
+    #[serde(default)]
+    pub categories: Vec<String>,
+}
+
+/// The settings of a font family in a font collection, which include its font faces.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+#[serde(rename_all = "camelCase")]
+pub struct FontCollectionFontFamilySettings {
+    pub name: String,
+    pub slug: String,
+    pub font_family: String,
+    #[serde(default)]
+    pub preview: Option<String>,
+    #[serde(default)]
+    pub font_face: Vec<FontFaceSettings>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+pub struct FontCollectionCategory {
+    pub name: String,
+    pub slug: String,
+}
+
+/// The params to install a font family from a font collection, by creating the font family &
+/// then each of its font faces.
+#[derive(Debug, uniffi::Record)]
+pub struct FontCollectionFontFamilyInstallParams {
+    pub font_family: FontFamilyCreateParams,
+    pub font_faces: Vec<FontFaceCreateParams>,
+}
+
+/// Returns the params to install a font family from a font collection, i.e. Google Fonts.
+///
+/// The font faces refer to the font files by the collection's URLs. To host the font files on the
+/// site instead, download them & replace each `src` with the name of the form field the file is
+/// uploaded in through `FontFaceCreateWithFilesParams`.
+#[uniffi::export]
+pub fn font_collection_font_family_install_params(
+    font_family: &FontCollectionFontFamily,
+) -> FontCollectionFontFamilyInstallParams {
+    let settings = &font_family.font_family_settings;
+    FontCollectionFontFamilyInstallParams {
+        font_family: FontFamilyCreateParams::new(FontFamilySettings {
+            name: settings.name.clone(),
+            slug: settings.slug.clone(),
+            font_family: settings.font_family.clone(),
+            preview: settings.preview.clone(),
+        }),
+        font_faces: settings
+            .font_face
+            .iter()
+            .cloned()
+            .map(FontFaceCreateParams::new)
+            .collect(),
+    }
+}
+
+fn serialize_as_json_string<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    let json = serde_json::to_string(value).map_err(serde::ser::Error::custom)?;
+    serializer.serialize_str(&json)
+}
+
+// `src` is either a single URL or a list of them
+fn deserialize_string_or_vec<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrVec {
+        String(String),
+        Vec(Vec<String>),
+    }
+    Ok(match StringOrVec::deserialize(deserializer)? {
+        StringOrVec::String(s) => vec![s],
+        StringOrVec::Vec(v) => v,
+    })
+}
+
+// `fontWeight` is either a number, i.e. `400`, or a string, i.e. `"100 900"`
+fn deserialize_option_string_or_number<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrNumber {
+        String(String),
+        Number(serde_json::Number),
+    }
+    Ok(
+        Option::<StringOrNumber>::deserialize(deserializer)?.map(|v| match v {
+            StringOrNumber::String(s) => s,
+            StringOrNumber::Number(n) => n.to_string(),
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, unit_test_common::assert_expected_query_pairs};
+    use rstest::*;
+    use serde_json::json;
+
+    #[rstest]
+    #[case(FontFamilyListParams::default(), &[])]
+    #[case(generate!(FontFamilyListParams, (include, vec![FontFamilyId(1), FontFamilyId(2)])), &[("include", "1,2")])]
+    #[case(generate!(FontFamilyListParams, (orderby, Some(WpApiParamFontsOrderBy::Include))), &[("orderby", "include")])]
+    #[case(generate!(FontFamilyListParams, (slug, vec!["open-sans".to_string()])), &[("slug", "open-sans")])]
+    #[trace]
+    fn test_font_family_list_params(
+        #[case] params: FontFamilyListParams,
+        #[case] expected_pairs: &[(&str, &str)],
+    ) {
+        assert_expected_query_pairs(params.query_pairs(), expected_pairs);
+    }
+
+    #[test]
+    fn font_family_create_params_encode_settings_as_json_string() {
+        let params = FontFamilyCreateParams::new(FontFamilySettings {
+            name: "Open Sans".to_string(),
+            slug: "open-sans".to_string(),
+            font_family: "\"Open Sans\", sans-serif".to_string(),
+            preview: None,
+        });
+        let value = serde_json::to_value(&params).unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(
+                value["font_family_settings"].as_str().unwrap()
+            )
+            .unwrap(),
+            json!({
+                "name": "Open Sans",
+                "slug": "open-sans",
+                "fontFamily": "\"Open Sans\", sans-serif"
+            })
+        );
+    }
+
+    #[test]
+    fn font_family_update_params_only_include_given_fields() {
+        let params = FontFamilyUpdateParams {
+            theme_json_version: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            json!({ "theme_json_version": 3 })
+        );
+    }
+
+    #[rstest]
+    #[case(json!("https://example.com/a.woff2"), vec!["https://example.com/a.woff2"], json!(400), Some("400"))]
+    #[case(json!(["file-0", "file-1"]), vec!["file-0", "file-1"], json!("100 900"), Some("100 900"))]
+    #[case(json!([]), vec![], json!(null), None)]
+    fn deserialize_font_face_settings(
+        #[case] src: serde_json::Value,
+        #[case] expected_src: Vec<&str>,
+        #[case] font_weight: serde_json::Value,
+        #[case] expected_font_weight: Option<&str>,
+    ) {
+        let settings: FontFaceSettings = serde_json::from_value(json!({
+            "fontFamily": "Open Sans",
+            "src": src,
+            "fontWeight": font_weight,
+            "fontStyle": "normal"
+        }))
+        .unwrap();
+        assert_eq!(settings.src, expected_src);
+        assert_eq!(settings.font_weight.as_deref(), expected_font_weight);
+        assert_eq!(settings.font_style.as_deref(), Some("normal"));
+    }
+
+    #[test]
+    fn install_params_from_font_collection() {
+        let font_family: FontCollectionFontFamily = serde_json::from_value(json!({
+            "font_family_settings": {
+                "name": "Open Sans",
+                "slug": "open-sans",
+                "fontFamily": "'Open Sans', sans-serif",
+                "preview": "https://example.com/open-sans.svg",
+                "fontFace": [
+                    { "fontFamily": "Open Sans", "fontStyle": "normal", "fontWeight": "400", "src": "https://example.com/400.woff2" },
+                    { "fontFamily": "Open Sans", "fontStyle": "italic", "fontWeight": "400", "src": "https://example.com/400-italic.woff2" }
+                ]
+            },
+            "categories": ["sans-serif"]
+        }))
+        .unwrap();
+        let install_params = font_collection_font_family_install_params(&font_family);
+        assert_eq!(
+            install_params.font_family.font_family_settings.slug,
+            "open-sans"
+        );
+        assert_eq!(
+            install_params.font_family.font_family_settings.preview,
+            Some("https://example.com/open-sans.svg".to_string())
+        );
+        assert_eq!(
+            install_params
+                .font_faces
+                .iter()
+                .map(|f| f.font_face_settings.font_style.as_deref())
+                .collect::<Vec<_>>(),
+            vec![Some("normal"), Some("italic")]
+        );
+    }
+}
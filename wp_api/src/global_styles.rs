@@ -0,0 +1,340 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use wp_contextual::WpContextual;
+
+use crate::{query_params::impl_query_param_value_for_newtype, JsonValue};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseGlobalStyles {
+    #[WpContext(edit, embed, view)]
+    pub id: Option<GlobalStylesId>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualField]
+    pub title: Option<SparseGlobalStylesTitle>,
+    /// The `settings` tree of theme.json, i.e. `{ "color": { "palette": [...] } }`.
+    #[WpContext(edit, view)]
+    pub settings: Option<JsonValue>,
+    /// The `styles` tree of theme.json, i.e. `{ "color": { "text": "var:preset|color|primary" } }`.
+    #[WpContext(edit, view)]
+    pub styles: Option<JsonValue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseGlobalStylesTitle {
+    #[WpContext(edit, view)]
+    pub raw: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub rendered: Option<String>,
+}
+
+uniffi::custom_newtype!(GlobalStylesId, i32);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GlobalStylesId(pub i32);
+
+impl std::fmt::Display for GlobalStylesId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl_query_param_value_for_newtype!(GlobalStylesId);
+
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct GlobalStylesUpdateParams {
+    /// Global styles.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub styles: Option<JsonValue>,
+    /// Global settings.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<JsonValue>,
+    /// Title of the global styles variation.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+/// The global styles of a theme, as defined by its theme.json.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record)]
+pub struct ThemeGlobalStyles {
+    pub settings: JsonValue,
+    pub styles: JsonValue,
+}
+
+/// A style variation of a theme, from one of the JSON files in its `styles` folder.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record)]
+pub struct ThemeGlobalStylesVariation {
+    pub version: Option<u32>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub settings: Option<JsonValue>,
+    pub styles: Option<JsonValue>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum GlobalStylesPresetType {
+    Color,
+    Gradient,
+    FontSize,
+    FontFamily,
+    Spacing,
+    Shadow,
+}
+
+impl GlobalStylesPresetType {
+    fn css_var_infix(&self) -> &str {
+        match self {
+            Self::Color => "color",
+            Self::Gradient => "gradient",
+            Self::FontSize => "font-size",
+            Self::FontFamily => "font-family",
+            Self::Spacing => "spacing",
+            Self::Shadow => "shadow",
+        }
+    }
+
+    // The path of the presets in the `settings` tree & the key of the preset's value.
+    fn settings_path(&self) -> ([&str; 2], &str) {
+        match self {
+            Self::Color => (["color", "palette"], "color"),
+            Self::Gradient => (["color", "gradients"], "gradient"),
+            Self::FontSize => (["typography", "fontSizes"], "size"),
+            Self::FontFamily => (["typography", "fontFamilies"], "fontFamily"),
+            Self::Spacing => (["spacing", "spacingSizes"], "size"),
+            Self::Shadow => (["shadow", "presets"], "shadow"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct GlobalStylesPreset {
+    pub slug: String,
+    pub name: Option<String>,
+    pub value: String,
+    /// The CSS custom property WordPress defines for the preset, i.e.
+    /// `--wp--preset--color--primary`.
+    pub css_custom_property_name: String,
+}
+
+/// Returns the CSS custom property WordPress defines for a preset, i.e.
+/// `--wp--preset--font-size--x-large`.
+#[uniffi::export]
+pub fn preset_css_custom_property_name(preset_type: GlobalStylesPresetType, slug: &str) -> String {
+    format!(
+        "--wp--preset--{}--{}",
+        preset_type.css_var_infix(),
+        to_kebab_case(slug)
+    )
+}
+
+/// Returns the presets of the given type from a `settings` tree, with the CSS custom property
+/// WordPress defines for each of them.
+///
+/// The presets can either be a list, as in a theme.json file, or grouped by their origin
+/// (`default`, `theme` & `custom`), as in the settings returned by the API. In the latter case,
+/// a preset overrides any preset with the same slug from an earlier origin.
+#[uniffi::export]
+pub fn global_styles_presets(
+    settings: &JsonValue,
+    preset_type: GlobalStylesPresetType,
+) -> Vec<GlobalStylesPreset> {
+    let ([section, key], value_key) = preset_type.settings_path();
+    let presets = match settings.0.get(section).and_then(|s| s.get(key)) {
+        Some(Value::Array(presets)) => presets.iter().collect::<Vec<_>>(),
+        Some(Value::Object(presets_by_origin)) => ["default", "theme", "custom"]
+            .iter()
+            .filter_map(|origin| presets_by_origin.get(*origin))
+            .filter_map(Value::as_array)
+            .flatten()
+            .collect(),
+        _ => Vec::new(),
+    };
+    let mut result: Vec<GlobalStylesPreset> = Vec::new();
+    for preset in presets {
+        let Some(slug) = preset.get("slug").and_then(Value::as_str) else {
+            continue;
+        };
+        let value = match preset.get(value_key) {
+            Some(Value::String(value)) => value.clone(),
+            Some(Value::Number(value)) => value.to_string(),
+            _ => continue,
+        };
+        let preset = GlobalStylesPreset {
+            slug: slug.to_string(),
+            name: preset
+                .get("name")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            value,
+            css_custom_property_name: preset_css_custom_property_name(preset_type, slug),
+        };
+        match result.iter_mut().find(|p| p.slug == preset.slug) {
+            Some(existing) => *existing = preset,
+            None => result.push(preset),
+        }
+    }
+    result
+}
+
+/// Resolves a preset reference of a `styles` tree, such as `var:preset|color|primary`, to the
+/// CSS variable it stands for: `var(--wp--preset--color--primary)`. Any other value is returned
+/// as is.
+#[uniffi::export]
+pub fn resolve_global_styles_value(value: &str) -> String {
+    match value.strip_prefix("var:") {
+        Some(reference) => format!(
+            "var(--wp--{})",
+            reference
+                .split('|')
+                .map(to_kebab_case)
+                .collect::<Vec<_>>()
+                .join("--")
+        ),
+        None => value.to_string(),
+    }
+}
+
+// Mirrors `_wp_to_kebab_case`, which WordPress uses for the slugs in CSS custom properties:
+// words are split on non-alphanumeric characters, case changes & between letters and digits.
+fn to_kebab_case(value: &str) -> String {
+    let chars = value.chars().collect::<Vec<_>>();
+    let mut words: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if let Some(prev) = current.chars().last() {
+            let next_is_lowercase = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_numeric() != c.is_numeric()
+                || (prev.is_lowercase() && c.is_uppercase())
+                || (prev.is_uppercase() && c.is_uppercase() && next_is_lowercase)
+            {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+        current.push(c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words.join("-").to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+    use serde_json::json;
+
+    #[rstest]
+    #[case("primary", "primary")]
+    #[case("x-large", "x-large")]
+    #[case("xLarge", "x-large")]
+    #[case("Base 2", "base-2")]
+    #[case("heading1", "heading-1")]
+    #[case("2xl", "2-xl")]
+    #[case("HTMLColor", "html-color")]
+    #[case("vivid_red", "vivid-red")]
+    fn test_to_kebab_case(#[case] value: &str, #[case] expected: &str) {
+        assert_eq!(to_kebab_case(value), expected);
+    }
+
+    #[rstest]
+    #[case(
+        GlobalStylesPresetType::Color,
+        "primary",
+        "--wp--preset--color--primary"
+    )]
+    #[case(
+        GlobalStylesPresetType::FontSize,
+        "xLarge",
+        "--wp--preset--font-size--x-large"
+    )]
+    #[case(
+        GlobalStylesPresetType::FontFamily,
+        "body",
+        "--wp--preset--font-family--body"
+    )]
+    #[case(GlobalStylesPresetType::Spacing, "50", "--wp--preset--spacing--50")]
+    fn test_preset_css_custom_property_name(
+        #[case] preset_type: GlobalStylesPresetType,
+        #[case] slug: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(preset_css_custom_property_name(preset_type, slug), expected);
+    }
+
+    #[rstest]
+    #[case("var:preset|color|primary", "var(--wp--preset--color--primary)")]
+    #[case("var:preset|font-size|xLarge", "var(--wp--preset--font-size--x-large)")]
+    #[case("var:custom|line-height|body", "var(--wp--custom--line-height--body)")]
+    #[case("#ffffff", "#ffffff")]
+    fn test_resolve_global_styles_value(#[case] value: &str, #[case] expected: &str) {
+        assert_eq!(resolve_global_styles_value(value), expected);
+    }
+
+    #[test]
+    fn global_styles_presets_from_theme_json() {
+        let settings = JsonValue(json!({
+            "color": { "palette": [
+                { "slug": "base", "name": "Base", "color": "#ffffff" },
+                { "slug": "contrast", "name": "Contrast", "color": "#111111" }
+            ] },
+            "typography": { "fontSizes": [{ "slug": "small", "size": "0.9rem" }] }
+        }));
+        assert_eq!(
+            global_styles_presets(&settings, GlobalStylesPresetType::Color),
+            vec![
+                GlobalStylesPreset {
+                    slug: "base".to_string(),
+                    name: Some("Base".to_string()),
+                    value: "#ffffff".to_string(),
+                    css_custom_property_name: "--wp--preset--color--base".to_string(),
+                },
+                GlobalStylesPreset {
+                    slug: "contrast".to_string(),
+                    name: Some("Contrast".to_string()),
+                    value: "#111111".to_string(),
+                    css_custom_property_name: "--wp--preset--color--contrast".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            global_styles_presets(&settings, GlobalStylesPresetType::FontSize),
+            vec![GlobalStylesPreset {
+                slug: "small".to_string(),
+                name: None,
+                value: "0.9rem".to_string(),
+                css_custom_property_name: "--wp--preset--font-size--small".to_string(),
+            }]
+        );
+        assert!(global_styles_presets(&settings, GlobalStylesPresetType::Gradient).is_empty());
+    }
+
+    #[test]
+    fn global_styles_presets_grouped_by_origin() {
+        let settings = JsonValue(json!({
+            "color": { "palette": {
+                "custom": [{ "slug": "base", "color": "#eeeeee" }],
+                "default": [{ "slug": "black", "color": "#000000" }],
+                "theme": [{ "slug": "base", "color": "#ffffff" }]
+            } }
+        }));
+        let presets = global_styles_presets(&settings, GlobalStylesPresetType::Color)
+            .into_iter()
+            .map(|p| (p.slug, p.value))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            presets,
+            vec![
+                ("black".to_string(), "#000000".to_string()),
+                ("base".to_string(), "#eeeeee".to_string()),
+            ]
+        );
+    }
+}
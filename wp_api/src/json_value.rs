@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+uniffi::custom_type!(JsonValue, String);
+
+/// An arbitrary JSON value for the parts of a response that don't have a fixed shape, such as
+/// block attributes or JSON Schemas. It's passed through uniffi as a JSON string.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct JsonValue(pub Value);
+
+impl crate::UniffiCustomTypeConverter for JsonValue {
+    type Builtin = String;
+
+    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self> {
+        Ok(Self(serde_json::from_str(&val)?))
+    }
+
+    fn from_custom(obj: Self) -> Self::Builtin {
+        obj.0.to_string()
+    }
+}
@@ -2,6 +2,7 @@
 
 pub use api_client::{WpApiClient, WpApiRequestBuilder};
 pub use api_error::{RequestExecutionError, WpApiError, WpErrorCode};
+pub use json_value::JsonValue;
 pub use parsed_url::{ParseUrlError, ParsedUrl};
 use plugins::*;
 use users::*;
@@ -9,18 +10,41 @@ pub use uuid::{WpUuid, WpUuidParseError};
 
 mod api_client; // re-exported relevant types
 mod api_error; // re-exported relevant types
+mod json_value; // re-exported relevant types
 mod parsed_url; // re-exported relevant types
 mod uuid; // re-exported relevant types
 
 pub mod application_passwords;
+pub mod block_patterns;
+pub mod block_types;
+pub mod blocks;
+#[cfg(feature = "cassette")]
+pub mod cassette;
+pub mod fonts;
+pub mod global_styles;
 pub mod login;
+pub mod menus;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod navigation;
+pub mod oembed;
 pub mod plugins;
 pub mod post_types;
 pub mod posts;
+pub mod query_params;
 pub mod request;
+pub mod search;
 pub mod site_settings;
+pub mod statuses;
+#[cfg(feature = "sync")]
+pub mod sync;
+pub mod templates;
+pub mod themes;
 pub mod users;
+pub mod widgets;
+pub mod wp_blocks;
 pub mod wp_site_health_tests;
+pub mod xmlrpc;
 
 #[cfg(test)]
 mod unit_test_common;
@@ -82,6 +106,21 @@ impl WpApiParamOrder {
     }
 }
 
+query_params::impl_query_param_value_via_as_str!(WpApiParamOrder, [Asc, Desc]);
+
+/// Permanently deletes an object. It's used by the endpoints whose objects can't be moved to the
+/// trash, or that have a separate request for trashing them.
+#[derive(Debug, Default, uniffi::Record)]
+pub struct ForceDeleteParams {}
+
+impl ForceDeleteParams {
+    pub fn query_pairs(&self) -> impl IntoIterator<Item = (&str, String)> {
+        // Without `force`, WordPress either moves the object to the trash or returns an error if
+        // the object doesn't support trashing
+        [("force", true.to_string())].into_iter()
+    }
+}
+
 trait SparseField {
     fn as_str(&self) -> &str;
 }
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::str;
 use std::sync::Arc;
 
@@ -5,7 +6,7 @@ use crate::request::endpoint::WpEndpointUrl;
 use crate::request::{
     RequestExecutor, RequestMethod, WpNetworkHeaderMap, WpNetworkRequest, WpNetworkResponse,
 };
-use crate::ParsedUrl;
+use crate::{xmlrpc, ParsedUrl};
 
 use super::url_discovery::{
     self, FetchApiDetailsError, FetchApiRootUrlError, StateInitial, UrlDiscoveryAttemptError,
@@ -82,11 +83,39 @@ impl WpLoginClient {
                 api_root_url: s.2,
                 attempts,
             })
+        } else if let Some(xmlrpc_url) = self.find_xmlrpc_url(&attempts).await {
+            Err(UrlDiscoveryError::XmlRpcAvailable {
+                xmlrpc_url: Arc::new(xmlrpc_url),
+                attempts,
+            })
         } else {
             Err(UrlDiscoveryError::UrlDiscoveryFailed { attempts })
         }
     }
 
+    // Sites with a blocked REST API may still serve XML-RPC, so before giving up, we check
+    // whether any of the attempted sites has a working XML-RPC endpoint.
+    async fn find_xmlrpc_url(
+        &self,
+        attempts: &HashMap<String, UrlDiscoveryState>,
+    ) -> Option<ParsedUrl> {
+        let mut candidates = attempts
+            .values()
+            .filter_map(|a| match a {
+                UrlDiscoveryState::Success(_) => None,
+                UrlDiscoveryState::Failure(e) => e.xmlrpc_url_candidate(),
+            })
+            .collect::<Vec<_>>();
+        candidates.sort();
+        candidates.dedup();
+        for candidate in candidates {
+            if xmlrpc::is_xmlrpc_available(self.request_executor.as_ref(), &candidate).await {
+                return Some(candidate);
+            }
+        }
+        None
+    }
+
     async fn attempt_api_discovery(
         &self,
         site_url: &str,
@@ -132,6 +161,7 @@ impl WpLoginClient {
             url: WpEndpointUrl(parsed_site_url.url()),
             header_map: WpNetworkHeaderMap::default().into(),
             body: None,
+            timeout_ms: None,
         };
         self.request_executor
             .execute(api_root_request.into())
@@ -150,6 +180,7 @@ impl WpLoginClient {
                     url: WpEndpointUrl(api_root_url.url()),
                     header_map: WpNetworkHeaderMap::default().into(),
                     body: None,
+                    timeout_ms: None,
                 }
                 .into(),
             )
@@ -8,6 +8,8 @@ use crate::{
 use super::WpApiDetails;
 
 const API_ROOT_LINK_HEADER: &str = "https://api.w.org/";
+const PINGBACK_HEADER: &str = "X-Pingback";
+const XMLRPC_FILE_NAME: &str = "xmlrpc.php";
 
 pub fn construct_attempts(input_site_url: String) -> Vec<String> {
     let mut attempts = vec![input_site_url.clone()];
@@ -63,6 +65,44 @@ impl UrlDiscoveryAttemptError {
             UrlDiscoveryAttemptError::FetchApiDetailsFailed { site_url, .. } => site_url.url(),
         }
     }
+
+    // Returns the url that should be probed for XML-RPC when the REST API couldn't be found.
+    //
+    // WordPress advertises the XML-RPC endpoint through the `X-Pingback` header, even when the
+    // `Link` header for the REST API is removed. If the header isn't there, we fall back to the
+    // default `xmlrpc.php` location.
+    pub(super) fn xmlrpc_url_candidate(&self) -> Option<ParsedUrl> {
+        match self {
+            UrlDiscoveryAttemptError::FailedToParseSiteUrl { .. } => None,
+            UrlDiscoveryAttemptError::FetchApiRootUrlFailed { site_url, error } => {
+                let pingback_url = match error {
+                    FetchApiRootUrlError::ApiRootLinkHeaderNotFound { header_map, .. } => {
+                        header_map
+                            .as_header_map()
+                            .get(PINGBACK_HEADER)
+                            .and_then(|v| v.to_str().ok())
+                            .and_then(|v| ParsedUrl::parse(v).ok())
+                    }
+                    FetchApiRootUrlError::RequestExecutionFailed { .. } => None,
+                };
+                pingback_url.or_else(|| default_xmlrpc_url(site_url))
+            }
+            UrlDiscoveryAttemptError::FetchApiDetailsFailed { site_url, .. } => {
+                default_xmlrpc_url(site_url)
+            }
+        }
+    }
+}
+
+fn default_xmlrpc_url(site_url: &ParsedUrl) -> Option<ParsedUrl> {
+    let mut url = site_url.inner.clone();
+    url.set_query(None);
+    url.set_fragment(None);
+    url.path_segments_mut()
+        .ok()?
+        .pop_if_empty()
+        .push(XMLRPC_FILE_NAME);
+    Some(ParsedUrl::new(url))
 }
 
 #[derive(Debug, uniffi::Record)]
@@ -79,6 +119,14 @@ pub enum UrlDiscoveryError {
     UrlDiscoveryFailed {
         attempts: HashMap<String, UrlDiscoveryState>,
     },
+    /// The REST API couldn't be discovered, but the site has a working XML-RPC endpoint.
+    ///
+    /// The site can still be used through [`crate::xmlrpc::WpXmlRpcClient`].
+    #[error("Url discovery failed, but XML-RPC is available at '{}'", xmlrpc_url.url())]
+    XmlRpcAvailable {
+        xmlrpc_url: Arc<ParsedUrl>,
+        attempts: HashMap<String, UrlDiscoveryState>,
+    },
 }
 
 #[derive(Debug)]
@@ -274,4 +322,40 @@ mod tests {
         expected_attempts.sort();
         assert_eq!(found_attempts, expected_attempts)
     }
+
+    #[rstest]
+    #[case("https://example.com", "https://example.com/xmlrpc.php")]
+    #[case("https://example.com/", "https://example.com/xmlrpc.php")]
+    #[case("https://example.com/blog/", "https://example.com/blog/xmlrpc.php")]
+    #[case(
+        "https://example.com/blog?foo=bar",
+        "https://example.com/blog/xmlrpc.php"
+    )]
+    fn test_default_xmlrpc_url(#[case] site_url: &str, #[case] expected_xmlrpc_url: &str) {
+        let site_url = ParsedUrl::parse(site_url).unwrap();
+        assert_eq!(
+            default_xmlrpc_url(&site_url).unwrap().url(),
+            expected_xmlrpc_url
+        );
+    }
+
+    #[rstest]
+    fn test_xmlrpc_url_candidate_prefers_pingback_header() {
+        let mut header_map = http::HeaderMap::new();
+        header_map.insert(
+            PINGBACK_HEADER,
+            http::HeaderValue::from_static("https://example.com/custom/xmlrpc.php"),
+        );
+        let error = UrlDiscoveryAttemptError::FetchApiRootUrlFailed {
+            site_url: ParsedUrl::parse("https://example.com").unwrap().into(),
+            error: FetchApiRootUrlError::ApiRootLinkHeaderNotFound {
+                header_map: WpNetworkHeaderMap::new(header_map).into(),
+                status_code: 200,
+            },
+        };
+        assert_eq!(
+            error.xmlrpc_url_candidate().unwrap().url(),
+            "https://example.com/custom/xmlrpc.php"
+        );
+    }
 }
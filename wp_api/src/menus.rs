@@ -0,0 +1,795 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use serde::{Deserialize, Serialize};
+use wp_contextual::WpContextual;
+use wp_derive_request_builder::WpQueryParams;
+
+use crate::{
+    posts::PostStatus, query_params::impl_query_param_value_for_newtype, JsonValue, WpApiParamOrder,
+};
+
+#[derive(Debug, Default, PartialEq, Eq, uniffi::Record, WpQueryParams)]
+pub struct MenuListParams {
+    /// Current page of the collection.
+    /// Default: `1`
+    #[uniffi(default = None)]
+    pub page: Option<u32>,
+    /// Maximum number of items to be returned in result set.
+    /// Default: `10`
+    #[uniffi(default = None)]
+    pub per_page: Option<u32>,
+    /// Limit results to those matching a string.
+    #[uniffi(default = None)]
+    pub search: Option<String>,
+    /// Ensure result set excludes specific IDs.
+    #[uniffi(default = [])]
+    pub exclude: Vec<MenuId>,
+    /// Limit result set to specific IDs.
+    #[uniffi(default = [])]
+    pub include: Vec<MenuId>,
+    /// Order sort attribute ascending or descending.
+    /// Default: asc
+    /// One of: asc, desc
+    #[uniffi(default = None)]
+    pub order: Option<WpApiParamOrder>,
+    /// Whether to hide menus that don't have any items.
+    #[uniffi(default = None)]
+    pub hide_empty: Option<bool>,
+    /// Limit result set to menus with one or more specific slugs.
+    #[uniffi(default = [])]
+    pub slug: Vec<String>,
+}
+
+#[derive(Debug, Serialize, uniffi::Record)]
+pub struct MenuCreateParams {
+    /// HTML title for the menu.
+    pub name: String,
+    /// HTML description of the menu.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// An alphanumeric identifier for the menu unique to its type.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    /// The locations assigned to the menu.
+    #[uniffi(default = [])]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub locations: Vec<MenuLocationName>,
+    /// Whether to automatically add top level pages to this menu.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_add: Option<bool>,
+}
+
+impl MenuCreateParams {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            description: None,
+            slug: None,
+            locations: Vec::new(),
+            auto_add: None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct MenuUpdateParams {
+    /// HTML title for the menu.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// HTML description of the menu.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// An alphanumeric identifier for the menu unique to its type.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    /// The locations assigned to the menu. An empty list removes the menu from all locations.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locations: Option<Vec<MenuLocationName>>,
+    /// Whether to automatically add top level pages to this menu.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_add: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
+pub struct MenuDeleteResponse {
+    pub deleted: bool,
+    pub previous: MenuWithEditContext,
+}
+
+// Classic menus are terms of the `nav_menu` taxonomy.
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseMenu {
+    #[WpContext(edit, embed, view)]
+    pub id: Option<MenuId>,
+    #[WpContext(edit, view)]
+    pub description: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub name: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub slug: Option<String>,
+    #[WpContext(edit, view)]
+    pub meta: Option<JsonValue>,
+    #[WpContext(edit, view)]
+    pub locations: Option<Vec<MenuLocationName>>,
+    #[WpContext(edit, view)]
+    pub auto_add: Option<bool>,
+}
+
+uniffi::custom_newtype!(MenuId, i32);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MenuId(pub i32);
+
+impl std::fmt::Display for MenuId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, uniffi::Record, WpQueryParams)]
+pub struct MenuItemListParams {
+    /// Current page of the collection.
+    /// Default: `1`
+    #[uniffi(default = None)]
+    pub page: Option<u32>,
+    /// Maximum number of items to be returned in result set.
+    /// Default: `100`
+    #[uniffi(default = None)]
+    pub per_page: Option<u32>,
+    /// Limit results to those matching a string.
+    #[uniffi(default = None)]
+    pub search: Option<String>,
+    /// Ensure result set excludes specific IDs.
+    #[uniffi(default = [])]
+    pub exclude: Vec<MenuItemId>,
+    /// Limit result set to specific IDs.
+    #[uniffi(default = [])]
+    pub include: Vec<MenuItemId>,
+    /// Order sort attribute ascending or descending.
+    /// Default: asc
+    /// One of: asc, desc
+    #[uniffi(default = None)]
+    pub order: Option<WpApiParamOrder>,
+    /// Limit result set to items assigned to one or more menus.
+    #[uniffi(default = [])]
+    pub menus: Vec<MenuId>,
+    /// Limit result set to items with a specific `menu_order`.
+    #[uniffi(default = None)]
+    pub menu_order: Option<u32>,
+}
+
+#[derive(Debug, Serialize, uniffi::Record)]
+pub struct MenuItemCreateParams {
+    /// The menu the item belongs to.
+    pub menus: MenuId,
+    /// The title for the menu item.
+    pub title: String,
+    /// The family of objects originally represented, such as "post_type" or "taxonomy".
+    #[uniffi(default = None)]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub item_type: Option<MenuItemType>,
+    /// A named status for the menu item.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<PostStatus>,
+    /// The ID of the parent menu item, or `0` for a top level item.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<MenuItemId>,
+    /// Text for the title attribute of the link element for this menu item.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attr_title: Option<String>,
+    /// Class names for the link element of this menu item.
+    #[uniffi(default = [])]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub classes: Vec<String>,
+    /// The description of this menu item.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The position of the menu item among its siblings.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub menu_order: Option<u32>,
+    /// The type of object originally represented, such as "category", "post", or "attachment".
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object: Option<String>,
+    /// The database ID of the original object this menu item represents, for example the ID
+    /// for posts or the term_id for categories.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<i32>,
+    /// The target attribute of the link element for this menu item.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<MenuItemTarget>,
+    /// The URL to which this menu item points.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The XFN relationships expressed in the link of this menu item.
+    #[uniffi(default = [])]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub xfn: Vec<String>,
+}
+
+impl MenuItemCreateParams {
+    pub fn new(menus: MenuId, title: String) -> Self {
+        Self {
+            menus,
+            title,
+            item_type: None,
+            status: None,
+            parent: None,
+            attr_title: None,
+            classes: Vec::new(),
+            description: None,
+            menu_order: None,
+            object: None,
+            object_id: None,
+            target: None,
+            url: None,
+            xfn: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct MenuItemUpdateParams {
+    /// The menu the item belongs to.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub menus: Option<MenuId>,
+    /// The title for the menu item.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The family of objects originally represented, such as "post_type" or "taxonomy".
+    #[uniffi(default = None)]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub item_type: Option<MenuItemType>,
+    /// A named status for the menu item.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<PostStatus>,
+    /// The ID of the parent menu item, or `0` for a top level item.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<MenuItemId>,
+    /// Text for the title attribute of the link element for this menu item.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attr_title: Option<String>,
+    /// Class names for the link element of this menu item.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classes: Option<Vec<String>>,
+    /// The description of this menu item.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The position of the menu item among its siblings.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub menu_order: Option<u32>,
+    /// The type of object originally represented, such as "category", "post", or "attachment".
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object: Option<String>,
+    /// The database ID of the original object this menu item represents, for example the ID
+    /// for posts or the term_id for categories.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<i32>,
+    /// The target attribute of the link element for this menu item.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<MenuItemTarget>,
+    /// The URL to which this menu item points.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The XFN relationships expressed in the link of this menu item.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub xfn: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
+pub struct MenuItemDeleteResponse {
+    pub deleted: bool,
+    pub previous: MenuItemWithEditContext,
+}
+
+// Menu items are posts of the `nav_menu_item` type.
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseMenuItem {
+    #[WpContext(edit, embed, view)]
+    pub id: Option<MenuItemId>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualField]
+    pub title: Option<crate::posts::SparsePostTitle>,
+    #[WpContext(edit, embed, view)]
+    pub status: Option<PostStatus>,
+    #[WpContext(edit, embed, view)]
+    pub url: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub attr_title: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub description: Option<String>,
+    #[serde(rename = "type")]
+    #[WpContext(edit, embed, view)]
+    pub item_type: Option<MenuItemType>,
+    #[WpContext(edit, embed, view)]
+    pub type_label: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub object: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub object_id: Option<i32>,
+    /// The ID of the parent menu item, or `0` for a top level item.
+    #[WpContext(edit, embed, view)]
+    pub parent: Option<MenuItemId>,
+    #[WpContext(edit, embed, view)]
+    pub menu_order: Option<u32>,
+    #[WpContext(edit, embed, view)]
+    pub target: Option<MenuItemTarget>,
+    #[WpContext(edit, embed, view)]
+    pub classes: Option<Vec<String>>,
+    #[WpContext(edit, embed, view)]
+    pub xfn: Option<Vec<String>>,
+    /// Whether the object the menu item represents no longer exists, i.e. a deleted post.
+    #[WpContext(edit, embed, view)]
+    pub invalid: Option<bool>,
+    #[WpContext(edit, view)]
+    pub menus: Option<MenuId>,
+    #[WpContext(edit, view)]
+    pub meta: Option<JsonValue>,
+}
+
+uniffi::custom_newtype!(MenuItemId, i32);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct MenuItemId(pub i32);
+
+impl MenuItemId {
+    /// The `parent` of a top level menu item.
+    pub const ROOT: Self = Self(0);
+}
+
+impl std::fmt::Display for MenuItemId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl_query_param_value_for_newtype!(MenuId, MenuItemId);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Enum)]
+#[serde(rename_all = "snake_case")]
+pub enum MenuItemType {
+    Taxonomy,
+    PostType,
+    PostTypeArchive,
+    Custom,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Enum)]
+pub enum MenuItemTarget {
+    /// Opens the link in the same tab.
+    #[serde(rename = "")]
+    SameTab,
+    /// Opens the link in a new tab.
+    #[serde(rename = "_blank")]
+    NewTab,
+}
+
+// The locations are keyed by their name, same as `SparsePostTypesResponse`.
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+#[serde(transparent)]
+pub struct SparseMenuLocationsResponse {
+    #[serde(flatten)]
+    #[WpContext(edit, embed, view)]
+    #[WpContextualField]
+    pub menu_locations: Option<HashMap<MenuLocationName, SparseMenuLocation>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseMenuLocation {
+    #[WpContext(edit, embed, view)]
+    pub name: Option<MenuLocationName>,
+    #[WpContext(edit, embed, view)]
+    pub description: Option<String>,
+    /// The ID of the assigned menu, or `0` if there isn't one.
+    #[WpContext(edit, embed, view)]
+    pub menu: Option<MenuId>,
+}
+
+/// The name of a menu location registered by the theme, i.e. `primary`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, uniffi::Record)]
+#[serde(transparent)]
+pub struct MenuLocationName {
+    pub name: String,
+}
+
+impl MenuLocationName {
+    pub fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+impl From<&str> for MenuLocationName {
+    fn from(value: &str) -> Self {
+        Self {
+            name: value.to_string(),
+        }
+    }
+}
+
+impl Display for MenuLocationName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Debug, uniffi::Record)]
+pub struct MenuItemNode {
+    pub item: MenuItemWithEditContext,
+    pub children: Vec<MenuItemNode>,
+}
+
+/// Builds the tree of a menu from its items, using their `parent`, with the children of each
+/// item sorted by their `menu_order`.
+///
+/// Items whose parent isn't one of the given items are placed at the top level, so a partial
+/// list of items still results in a tree.
+#[uniffi::export]
+pub fn build_menu_item_tree(menu_items: Vec<MenuItemWithEditContext>) -> Vec<MenuItemNode> {
+    let ids = menu_items.iter().map(|i| i.id).collect::<HashSet<_>>();
+    let mut children_by_parent: HashMap<MenuItemId, Vec<MenuItemWithEditContext>> = HashMap::new();
+    for item in menu_items {
+        let parent = if item.parent != item.id && ids.contains(&item.parent) {
+            item.parent
+        } else {
+            MenuItemId::ROOT
+        };
+        children_by_parent.entry(parent).or_default().push(item);
+    }
+    for children in children_by_parent.values_mut() {
+        children.sort_by_key(|i| (i.menu_order, i.id));
+    }
+
+    let mut tree = menu_item_nodes(MenuItemId::ROOT, &mut children_by_parent);
+    // Items that are their own ancestors can't be reached from the top level
+    while let Some(parent) = children_by_parent.keys().min().copied() {
+        tree.extend(menu_item_nodes(parent, &mut children_by_parent));
+    }
+    tree
+}
+
+fn menu_item_nodes(
+    parent: MenuItemId,
+    children_by_parent: &mut HashMap<MenuItemId, Vec<MenuItemWithEditContext>>,
+) -> Vec<MenuItemNode> {
+    children_by_parent
+        .remove(&parent)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|item| {
+            let children = menu_item_nodes(item.id, children_by_parent);
+            MenuItemNode { item, children }
+        })
+        .collect()
+}
+
+/// The desired position of a menu item & its children, for `menu_item_reorder_updates`.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct MenuItemOrder {
+    pub id: MenuItemId,
+    pub children: Vec<MenuItemOrder>,
+}
+
+impl From<&MenuItemNode> for MenuItemOrder {
+    fn from(node: &MenuItemNode) -> Self {
+        Self {
+            id: node.item.id,
+            children: node.children.iter().map(Self::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct MenuItemReorderUpdate {
+    pub id: MenuItemId,
+    pub parent: MenuItemId,
+    pub menu_order: u32,
+}
+
+/// Returns the updates that move the menu items into the desired order.
+///
+/// WordPress only uses `menu_order` to sort the siblings, so the items that keep their parent
+/// & are already in the right order relative to each other keep their `menu_order`, and only
+/// the moved items are updated. If there is no room for a moved item between the `menu_order`
+/// of its new siblings, its siblings are renumbered.
+#[uniffi::export]
+pub fn menu_item_reorder_updates(
+    menu_items: &[MenuItemWithEditContext],
+    desired_order: &[MenuItemOrder],
+) -> Vec<MenuItemReorderUpdate> {
+    let current = menu_items
+        .iter()
+        .map(|i| (i.id, (i.parent, i.menu_order)))
+        .collect::<HashMap<_, _>>();
+    let mut updates = Vec::new();
+    sibling_reorder_updates(MenuItemId::ROOT, desired_order, &current, &mut updates);
+    updates
+}
+
+fn sibling_reorder_updates(
+    parent: MenuItemId,
+    siblings: &[MenuItemOrder],
+    current: &HashMap<MenuItemId, (MenuItemId, u32)>,
+    updates: &mut Vec<MenuItemReorderUpdate>,
+) {
+    // The `menu_order` of the siblings that are already under this parent
+    let current_orders = siblings
+        .iter()
+        .map(|s| {
+            current
+                .get(&s.id)
+                .filter(|(p, _)| *p == parent)
+                .map(|(_, order)| *order)
+        })
+        .collect::<Vec<_>>();
+    let menu_orders = keep_increasing_menu_orders(&current_orders)
+        .unwrap_or_else(|| (1..).take(siblings.len()).collect());
+    for (sibling, menu_order) in siblings.iter().zip(menu_orders) {
+        if current.get(&sibling.id) != Some(&(parent, menu_order)) {
+            updates.push(MenuItemReorderUpdate {
+                id: sibling.id,
+                parent,
+                menu_order,
+            });
+        }
+    }
+    for sibling in siblings {
+        sibling_reorder_updates(sibling.id, &sibling.children, current, updates);
+    }
+}
+
+// Keeps the longest increasing subsequence of the current `menu_order`s & fits the others in
+// between them, or returns `None` if they don't fit.
+fn keep_increasing_menu_orders(current_orders: &[Option<u32>]) -> Option<Vec<u32>> {
+    let n = current_orders.len();
+    let mut lengths = vec![0; n];
+    let mut previous = vec![None; n];
+    for i in 0..n {
+        let Some(order) = current_orders[i] else {
+            continue;
+        };
+        lengths[i] = 1;
+        for j in 0..i {
+            if current_orders[j].is_some_and(|o| o < order) && lengths[j] + 1 > lengths[i] {
+                lengths[i] = lengths[j] + 1;
+                previous[i] = Some(j);
+            }
+        }
+    }
+    let mut kept = vec![false; n];
+    let mut last = (0..n)
+        .filter(|i| lengths[*i] > 0)
+        .max_by_key(|i| (lengths[*i], n - i));
+    while let Some(i) = last {
+        kept[i] = true;
+        last = previous[i];
+    }
+
+    let mut menu_orders = Vec::with_capacity(n);
+    let mut lower = 0;
+    for i in 0..n {
+        let menu_order = match current_orders[i] {
+            Some(order) if kept[i] => order,
+            _ => {
+                let upper = (i + 1..n)
+                    .find(|j| kept[*j])
+                    .and_then(|j| current_orders[j]);
+                let menu_order = lower + 1;
+                if upper.is_some_and(|upper| menu_order >= upper) {
+                    return None;
+                }
+                menu_order
+            }
+        };
+        menu_orders.push(menu_order);
+        lower = menu_order;
+    }
+    Some(menu_orders)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, unit_test_common::assert_expected_query_pairs};
+    use rstest::*;
+    use serde_json::json;
+
+    #[rstest]
+    #[case(MenuListParams::default(), &[])]
+    #[case(generate!(MenuListParams, (hide_empty, Some(true))), &[("hide_empty", "true")])]
+    #[case(generate!(MenuListParams, (include, vec![MenuId(1), MenuId(2)])), &[("include", "1,2")])]
+    #[trace]
+    fn test_menu_list_params(
+        #[case] params: MenuListParams,
+        #[case] expected_pairs: &[(&str, &str)],
+    ) {
+        assert_expected_query_pairs(params.query_pairs(), expected_pairs);
+    }
+
+    #[rstest]
+    #[case(MenuItemListParams::default(), &[])]
+    #[case(generate!(MenuItemListParams, (menus, vec![MenuId(3)])), &[("menus", "3")])]
+    #[case(generate!(MenuItemListParams, (menu_order, Some(2)), (per_page, Some(100))), &[("per_page", "100"), ("menu_order", "2")])]
+    #[trace]
+    fn test_menu_item_list_params(
+        #[case] params: MenuItemListParams,
+        #[case] expected_pairs: &[(&str, &str)],
+    ) {
+        assert_expected_query_pairs(params.query_pairs(), expected_pairs);
+    }
+
+    fn menu_item(id: i32, parent: i32, menu_order: u32) -> MenuItemWithEditContext {
+        serde_json::from_value(json!({
+            "id": id,
+            "title": { "raw": format!("Item {}", id), "rendered": format!("Item {}", id) },
+            "status": "publish",
+            "url": "https://example.com",
+            "attr_title": "",
+            "description": "",
+            "type": "custom",
+            "type_label": "Custom Link",
+            "object": "custom",
+            "object_id": id,
+            "parent": parent,
+            "menu_order": menu_order,
+            "target": "",
+            "classes": [""],
+            "xfn": [""],
+            "invalid": false,
+            "menus": 2,
+            "meta": [],
+        }))
+        .unwrap()
+    }
+
+    fn tree_ids(nodes: &[MenuItemNode]) -> Vec<(i32, Vec<i32>)> {
+        nodes
+            .iter()
+            .map(|n| {
+                (
+                    n.item.id.0,
+                    n.children.iter().map(|c| c.item.id.0).collect(),
+                )
+            })
+            .collect()
+    }
+
+    fn order(id: i32, children: Vec<MenuItemOrder>) -> MenuItemOrder {
+        MenuItemOrder {
+            id: MenuItemId(id),
+            children,
+        }
+    }
+
+    fn update(id: i32, parent: i32, menu_order: u32) -> MenuItemReorderUpdate {
+        MenuItemReorderUpdate {
+            id: MenuItemId(id),
+            parent: MenuItemId(parent),
+            menu_order,
+        }
+    }
+
+    #[test]
+    fn build_menu_item_tree_nests_and_sorts_items() {
+        let tree = build_menu_item_tree(vec![
+            menu_item(4, 1, 4),
+            menu_item(1, 0, 1),
+            menu_item(3, 1, 3),
+            menu_item(2, 0, 2),
+        ]);
+        assert_eq!(tree_ids(&tree), vec![(1, vec![3, 4]), (2, vec![])]);
+    }
+
+    #[test]
+    fn build_menu_item_tree_places_orphans_at_the_top_level() {
+        let tree = build_menu_item_tree(vec![menu_item(1, 0, 2), menu_item(2, 9, 1)]);
+        assert_eq!(tree_ids(&tree), vec![(2, vec![]), (1, vec![])]);
+    }
+
+    #[test]
+    fn build_menu_item_tree_includes_items_in_a_cycle() {
+        let tree = build_menu_item_tree(vec![menu_item(1, 2, 1), menu_item(2, 1, 2)]);
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree[0].children.len(), 1);
+    }
+
+    #[test]
+    fn menu_item_order_from_tree() {
+        let tree = build_menu_item_tree(vec![menu_item(1, 0, 1), menu_item(2, 1, 2)]);
+        assert_eq!(
+            tree.iter().map(MenuItemOrder::from).collect::<Vec<_>>(),
+            vec![order(1, vec![order(2, vec![])])]
+        );
+    }
+
+    #[test]
+    fn reorder_without_changes_has_no_updates() {
+        let items = vec![menu_item(1, 0, 1), menu_item(2, 0, 2), menu_item(3, 2, 3)];
+        let desired = vec![order(1, vec![]), order(2, vec![order(3, vec![])])];
+        assert!(menu_item_reorder_updates(&items, &desired).is_empty());
+    }
+
+    #[test]
+    fn reorder_only_updates_the_moved_item() {
+        let items = vec![
+            menu_item(1, 0, 1),
+            menu_item(2, 0, 2),
+            menu_item(3, 0, 3),
+            menu_item(4, 0, 4),
+        ];
+        // Moving the first item to the end keeps the others as they are
+        let desired = vec![
+            order(2, vec![]),
+            order(3, vec![]),
+            order(4, vec![]),
+            order(1, vec![]),
+        ];
+        assert_eq!(
+            menu_item_reorder_updates(&items, &desired),
+            vec![update(1, 0, 5)]
+        );
+    }
+
+    #[test]
+    fn reorder_updates_an_item_moved_to_another_parent() {
+        let items = vec![menu_item(1, 0, 1), menu_item(2, 0, 2), menu_item(3, 0, 3)];
+        let desired = vec![order(1, vec![order(3, vec![])]), order(2, vec![])];
+        assert_eq!(
+            menu_item_reorder_updates(&items, &desired),
+            vec![update(3, 1, 1)]
+        );
+    }
+
+    #[test]
+    fn reorder_renumbers_siblings_without_room_for_the_moved_item() {
+        let items = vec![menu_item(1, 0, 1), menu_item(2, 0, 2), menu_item(3, 1, 1)];
+        let desired = vec![order(1, vec![]), order(3, vec![]), order(2, vec![])];
+        assert_eq!(
+            menu_item_reorder_updates(&items, &desired),
+            vec![update(3, 0, 2), update(2, 0, 3)]
+        );
+    }
+
+    #[test]
+    fn reorder_fits_the_moved_item_between_its_siblings() {
+        let items = vec![
+            menu_item(1, 0, 10),
+            menu_item(2, 0, 20),
+            menu_item(3, 0, 30),
+        ];
+        let desired = vec![order(1, vec![]), order(3, vec![]), order(2, vec![])];
+        assert_eq!(
+            menu_item_reorder_updates(&items, &desired),
+            vec![update(2, 0, 31)]
+        );
+    }
+}
@@ -0,0 +1,488 @@
+//! Mocks for unit testing code that uses [WpApiClient] without a live WordPress site.
+//!
+//! Every endpoint has a generated mock, i.e. `UsersRequestMock`, with the same functions as its
+//! request executor. Instead of executing the request, these functions return a [MockedRequest]
+//! which can be used to stub the response and to check whether the request was made.
+//!
+//! ```ignore
+//! let mock = WpApiMock::new(site_url, WpAuthentication::None);
+//! let retrieve_me = mock.users().retrieve_me_with_edit_context();
+//! retrieve_me.returns(&user)?;
+//!
+//! let user = mock.client().users().retrieve_me_with_edit_context().await?;
+//! assert_eq!(retrieve_me.request_count(), 1);
+//! ```
+
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+
+use crate::api_client::WpApiClient;
+use crate::request::{
+    endpoint::{
+        application_passwords_endpoint::ApplicationPasswordsRequestMock,
+        block_patterns_endpoint::BlockPatternsRequestMock,
+        block_renderer_endpoint::BlockRendererRequestMock,
+        block_types_endpoint::BlockTypesRequestMock,
+        font_collections_endpoint::FontCollectionsRequestMock,
+        font_faces_endpoint::FontFacesRequestMock, font_families_endpoint::FontFamiliesRequestMock,
+        global_styles_endpoint::GlobalStylesRequestMock, menu_items_endpoint::MenuItemsRequestMock,
+        menu_locations_endpoint::MenuLocationsRequestMock, menus_endpoint::MenusRequestMock,
+        navigation_endpoint::NavigationRequestMock, oembed_endpoint::OEmbedRequestMock,
+        plugins_endpoint::PluginsRequestMock, post_types_endpoint::PostTypesRequestMock,
+        posts_endpoint::PostsRequestMock, search_endpoint::SearchRequestMock,
+        sidebars_endpoint::SidebarsRequestMock, site_settings_endpoint::SiteSettingsRequestMock,
+        statuses_endpoint::StatusesRequestMock, template_parts_endpoint::TemplatePartsRequestMock,
+        templates_endpoint::TemplatesRequestMock, themes_endpoint::ThemesRequestMock,
+        users_endpoint::UsersRequestMock, widget_types_endpoint::WidgetTypesRequestMock,
+        widgets_endpoint::WidgetsRequestMock, wp_blocks_endpoint::WpBlocksRequestMock,
+        wp_site_health_tests_endpoint::WpSiteHealthTestsRequestMock, ApiBaseUrl,
+    },
+    RequestExecutor, RequestMethod, WpNetworkHeaderMap, WpNetworkRequest, WpNetworkResponse,
+};
+use crate::{ParsedUrl, RequestExecutionError, WpApiError, WpAuthentication};
+
+#[derive(Debug)]
+pub struct WpApiMock {
+    site_url: Arc<ParsedUrl>,
+    authentication: WpAuthentication,
+    mock_request_executor: Arc<MockRequestExecutor>,
+    application_passwords: ApplicationPasswordsRequestMock,
+    block_patterns: BlockPatternsRequestMock,
+    block_renderer: BlockRendererRequestMock,
+    block_types: BlockTypesRequestMock,
+    font_collections: FontCollectionsRequestMock,
+    font_faces: FontFacesRequestMock,
+    font_families: FontFamiliesRequestMock,
+    global_styles: GlobalStylesRequestMock,
+    menu_items: MenuItemsRequestMock,
+    menu_locations: MenuLocationsRequestMock,
+    menus: MenusRequestMock,
+    navigation: NavigationRequestMock,
+    oembed: OEmbedRequestMock,
+    plugins: PluginsRequestMock,
+    post_types: PostTypesRequestMock,
+    posts: PostsRequestMock,
+    search: SearchRequestMock,
+    sidebars: SidebarsRequestMock,
+    site_settings: SiteSettingsRequestMock,
+    statuses: StatusesRequestMock,
+    template_parts: TemplatePartsRequestMock,
+    templates: TemplatesRequestMock,
+    themes: ThemesRequestMock,
+    users: UsersRequestMock,
+    widget_types: WidgetTypesRequestMock,
+    widgets: WidgetsRequestMock,
+    wp_site_health_tests: WpSiteHealthTestsRequestMock,
+    wp_blocks: WpBlocksRequestMock,
+}
+
+impl WpApiMock {
+    pub fn new(site_url: Arc<ParsedUrl>, authentication: WpAuthentication) -> Self {
+        let api_base_url: Arc<ApiBaseUrl> = Arc::new(site_url.inner.clone().into());
+        let mock_request_executor = Arc::new(MockRequestExecutor::default());
+        macro_helper::wp_api_mock!(
+            site_url,
+            authentication,
+            api_base_url,
+            mock_request_executor;
+            application_passwords,
+            block_patterns,
+            block_renderer,
+            block_types,
+            font_collections,
+            font_faces,
+            font_families,
+            global_styles,
+            menu_items,
+            menu_locations,
+            menus,
+            navigation,
+            oembed: OEmbed,
+            plugins,
+            post_types,
+            posts,
+            site_settings,
+            statuses,
+            template_parts,
+            templates,
+            themes,
+            users,
+            search,
+            sidebars,
+            widget_types,
+            widgets,
+            wp_site_health_tests,
+            wp_blocks
+        )
+    }
+
+    // Returns a client that'll execute its requests using the stubbed responses
+    pub fn client(&self) -> WpApiClient {
+        WpApiClient::new(
+            self.site_url.clone(),
+            self.authentication.clone(),
+            self.mock_request_executor.clone(),
+        )
+    }
+
+    pub fn request_executor(&self) -> Arc<MockRequestExecutor> {
+        self.mock_request_executor.clone()
+    }
+}
+
+macro_helper::generate_endpoint_impl!(application_passwords);
+macro_helper::generate_endpoint_impl!(block_patterns);
+macro_helper::generate_endpoint_impl!(block_renderer);
+macro_helper::generate_endpoint_impl!(block_types);
+macro_helper::generate_endpoint_impl!(font_collections);
+macro_helper::generate_endpoint_impl!(font_faces);
+macro_helper::generate_endpoint_impl!(font_families);
+macro_helper::generate_endpoint_impl!(global_styles);
+macro_helper::generate_endpoint_impl!(menu_items);
+macro_helper::generate_endpoint_impl!(menu_locations);
+macro_helper::generate_endpoint_impl!(menus);
+macro_helper::generate_endpoint_impl!(navigation);
+macro_helper::generate_endpoint_impl!(oembed: OEmbed);
+macro_helper::generate_endpoint_impl!(plugins);
+macro_helper::generate_endpoint_impl!(post_types);
+macro_helper::generate_endpoint_impl!(posts);
+macro_helper::generate_endpoint_impl!(search);
+macro_helper::generate_endpoint_impl!(sidebars);
+macro_helper::generate_endpoint_impl!(site_settings);
+macro_helper::generate_endpoint_impl!(statuses);
+macro_helper::generate_endpoint_impl!(template_parts);
+macro_helper::generate_endpoint_impl!(templates);
+macro_helper::generate_endpoint_impl!(themes);
+macro_helper::generate_endpoint_impl!(users);
+macro_helper::generate_endpoint_impl!(widget_types);
+macro_helper::generate_endpoint_impl!(widgets);
+macro_helper::generate_endpoint_impl!(wp_site_health_tests);
+macro_helper::generate_endpoint_impl!(wp_blocks);
+
+// A [RequestExecutor] that records every request and responds to them with a stub matching the
+// request's method and url. If there are multiple matching stubs, the latest one is used.
+#[derive(Debug, Default)]
+pub struct MockRequestExecutor {
+    stubs: Mutex<Vec<MockStub>>,
+    requests: Mutex<Vec<Arc<WpNetworkRequest>>>,
+}
+
+impl MockRequestExecutor {
+    pub fn stub(&self, method: RequestMethod, url: String, response: MockResponse) {
+        self.stubs
+            .lock()
+            .expect("Mock stubs lock shouldn't be poisoned")
+            .push(MockStub {
+                method,
+                url,
+                response,
+            });
+    }
+
+    pub fn requests(&self) -> Vec<Arc<WpNetworkRequest>> {
+        self.requests
+            .lock()
+            .expect("Mock requests lock shouldn't be poisoned")
+            .clone()
+    }
+
+    pub fn request_count(&self, method: &RequestMethod, url: &str) -> usize {
+        self.requests
+            .lock()
+            .expect("Mock requests lock shouldn't be poisoned")
+            .iter()
+            .filter(|r| &r.method == method && r.url.0 == url)
+            .count()
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestExecutor for MockRequestExecutor {
+    async fn execute(
+        &self,
+        request: Arc<WpNetworkRequest>,
+    ) -> Result<WpNetworkResponse, RequestExecutionError> {
+        self.requests
+            .lock()
+            .expect("Mock requests lock shouldn't be poisoned")
+            .push(request.clone());
+        self.stubs
+            .lock()
+            .expect("Mock stubs lock shouldn't be poisoned")
+            .iter()
+            .rev()
+            .find(|s| s.method == request.method && s.url == request.url.0)
+            .map(|s| s.response.to_result())
+            .unwrap_or_else(|| {
+                Err(RequestExecutionError::RequestExecutionFailed {
+                    status_code: None,
+                    reason: format!("No mock response for {:?}", request),
+                })
+            })
+    }
+}
+
+#[derive(Debug)]
+struct MockStub {
+    method: RequestMethod,
+    url: String,
+    response: MockResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MockResponse {
+    Response { status_code: u16, body: Vec<u8> },
+    Failure(RequestExecutionError),
+}
+
+impl MockResponse {
+    fn to_result(&self) -> Result<WpNetworkResponse, RequestExecutionError> {
+        match self {
+            Self::Response { status_code, body } => Ok(WpNetworkResponse {
+                body: body.clone(),
+                status_code: *status_code,
+                header_map: WpNetworkHeaderMap::default().into(),
+            }),
+            Self::Failure(e) => Err(e.clone()),
+        }
+    }
+}
+
+// Builds a response that'll be parsed back to the same error by the client.
+//
+// `ResponseParsingError` & `SiteUrlParsingError` can't be reproduced from a response, so they
+// are returned as a response that fails to parse and as a request execution failure respectively.
+impl From<WpApiError> for MockResponse {
+    fn from(error: WpApiError) -> Self {
+        match error {
+            WpApiError::InvalidHttpStatusCode { status_code } => Self::Response {
+                status_code,
+                body: Vec::new(),
+            },
+            WpApiError::RequestExecutionFailed {
+                status_code,
+                reason,
+            } => Self::Failure(RequestExecutionError::RequestExecutionFailed {
+                status_code,
+                reason,
+            }),
+            WpApiError::ResponseParsingError { response, .. } => Self::Response {
+                status_code: 200,
+                body: response.into_bytes(),
+            },
+            WpApiError::SiteUrlParsingError { reason } => {
+                Self::Failure(RequestExecutionError::RequestExecutionFailed {
+                    status_code: None,
+                    reason,
+                })
+            }
+            WpApiError::UnknownError {
+                status_code,
+                response,
+            } => Self::Response {
+                status_code,
+                body: response.into_bytes(),
+            },
+            WpApiError::WpError {
+                error_code,
+                error_message,
+                status_code,
+                ..
+            } => Self::Response {
+                status_code,
+                body: serde_json::json!({
+                    "code": error_code,
+                    "message": error_message,
+                    "data": { "status": status_code },
+                })
+                .to_string()
+                .into_bytes(),
+            },
+        }
+    }
+}
+
+// A request built by a generated endpoint mock, i.e. `UsersRequestMock`. `T` is the output type
+// of the request, so that stubbed values are type checked.
+#[derive(Debug)]
+pub struct MockedRequest<T> {
+    mock_request_executor: Arc<MockRequestExecutor>,
+    method: RequestMethod,
+    url: String,
+    _output: PhantomData<T>,
+}
+
+impl<T> MockedRequest<T> {
+    pub fn new(mock_request_executor: Arc<MockRequestExecutor>, request: WpNetworkRequest) -> Self {
+        Self {
+            mock_request_executor,
+            method: request.method,
+            url: request.url.0,
+            _output: PhantomData,
+        }
+    }
+
+    pub fn fails(&self, error: WpApiError) {
+        self.responds_with(error.into());
+    }
+
+    // Stubs the response with a raw body, i.e. a JSON fixture captured from a real site
+    pub fn returns_fixture(&self, status_code: u16, body: impl Into<Vec<u8>>) {
+        self.responds_with(MockResponse::Response {
+            status_code,
+            body: body.into(),
+        });
+    }
+
+    pub fn responds_with(&self, response: MockResponse) {
+        self.mock_request_executor
+            .stub(self.method.clone(), self.url.clone(), response);
+    }
+
+    pub fn request_count(&self) -> usize {
+        self.mock_request_executor
+            .request_count(&self.method, &self.url)
+    }
+}
+
+impl<T: Serialize> MockedRequest<T> {
+    pub fn returns(&self, value: &T) -> Result<(), serde_json::Error> {
+        self.returns_fixture(200, serde_json::to_vec(value)?);
+        Ok(())
+    }
+}
+
+mod macro_helper {
+    // The type of an endpoint is named after its camel cased `$ident`, unless a `$type_prefix` is
+    // given for names `paste` can't derive, i.e. `oembed: OEmbed`
+    macro_rules! endpoint_type {
+        ($ident:ident, $suffix:ident) => {
+            paste::paste! { [<$ident:camel $suffix>] }
+        };
+        ($ident:ident: $type_prefix:ident, $suffix:ident) => {
+            paste::paste! { [<$type_prefix $suffix>] }
+        };
+    }
+
+    macro_rules! generate_endpoint_impl {
+        ($ident:ident $(: $type_prefix:ident)?) => {
+            impl WpApiMock {
+                pub fn $ident(&self) -> &macro_helper::endpoint_type!($ident $(: $type_prefix)?, RequestMock) {
+                    &self.$ident
+                }
+            }
+        };
+    }
+
+    macro_rules! wp_api_mock {
+        ($site_url:ident, $authentication:ident, $api_base_url:ident, $mock_request_executor:ident; $($element:ident $(: $type_prefix:ident)?),*) => {
+            Self {
+                $($element: <macro_helper::endpoint_type!($element $(: $type_prefix)?, RequestMock)>::new(
+                    $api_base_url.clone(),
+                    $authentication.clone(),
+                    $mock_request_executor.clone(),
+                ),)*
+                $site_url,
+                $authentication,
+                $mock_request_executor,
+            }
+        };
+    }
+
+    pub(super) use endpoint_type;
+    pub(super) use generate_endpoint_impl;
+    pub(super) use wp_api_mock;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::users::{UserDeleteParams, UserId, UserListParams, UserWithEmbedContext};
+    use crate::WpErrorCode;
+    use futures::executor::block_on;
+    use rstest::*;
+
+    #[fixture]
+    fn mock() -> WpApiMock {
+        WpApiMock::new(
+            ParsedUrl::parse("https://example.com").unwrap().into(),
+            WpAuthentication::None,
+        )
+    }
+
+    #[rstest]
+    fn test_returns_stubbed_value(mock: WpApiMock) {
+        let retrieve_me = mock.users().retrieve_me_with_embed_context();
+        retrieve_me.returns(&user_with_embed_context()).unwrap();
+
+        let user = block_on(mock.client().users().retrieve_me_with_embed_context()).unwrap();
+        assert_eq!(user.id, UserId(1));
+        assert_eq!(user.name, "foo");
+        assert_eq!(retrieve_me.request_count(), 1);
+    }
+
+    #[rstest]
+    fn test_returns_stubbed_error(mock: WpApiMock) {
+        let user_id = UserId(1);
+        let params = UserDeleteParams::new(UserId(2));
+        mock.users()
+            .delete(&user_id, &params)
+            .fails(WpApiError::WpError {
+                error_code: WpErrorCode::UserCannotDelete,
+                error_message: "Sorry, you are not allowed to delete this user.".to_string(),
+                status_code: 401,
+                response: String::new(),
+            });
+
+        let error = block_on(mock.client().users().delete(&user_id, &params)).unwrap_err();
+        assert!(matches!(
+            error,
+            WpApiError::WpError {
+                error_code: WpErrorCode::UserCannotDelete,
+                status_code: 401,
+                ..
+            }
+        ));
+    }
+
+    #[rstest]
+    fn test_stub_matches_method_and_url(mock: WpApiMock) {
+        mock.users()
+            .list_with_edit_context(&UserListParams::default())
+            .returns_fixture(200, "[]");
+        let params = UserListParams {
+            page: Some(2),
+            ..Default::default()
+        };
+
+        let client = mock.client();
+        assert!(block_on(client.users().list_with_edit_context(&params)).is_err());
+        assert!(block_on(
+            client
+                .users()
+                .list_with_edit_context(&UserListParams::default())
+        )
+        .unwrap()
+        .is_empty());
+        assert!(block_on(client.users().list_with_view_context(&params)).is_err());
+        assert_eq!(mock.request_executor().requests().len(), 3);
+        assert_eq!(
+            mock.users().list_with_edit_context(&params).request_count(),
+            1
+        );
+    }
+
+    fn user_with_embed_context() -> UserWithEmbedContext {
+        UserWithEmbedContext {
+            id: UserId(1),
+            name: "foo".to_string(),
+            url: String::new(),
+            description: String::new(),
+            link: "https://example.com/author/foo".to_string(),
+            slug: "foo".to_string(),
+            avatar_urls: None,
+        }
+    }
+}
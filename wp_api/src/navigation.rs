@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use wp_contextual::WpContextual;
+use wp_derive_request_builder::WpQueryParams;
+
+use crate::{
+    posts::{PostId, PostStatus, WpApiParamPostsOrderBy},
+    WpApiParamOrder,
+};
+
+#[derive(Debug, Default, PartialEq, Eq, uniffi::Record, WpQueryParams)]
+pub struct NavigationListParams {
+    /// Current page of the collection.
+    /// Default: `1`
+    #[uniffi(default = None)]
+    pub page: Option<u32>,
+    /// Maximum number of items to be returned in result set.
+    /// Default: `10`
+    #[uniffi(default = None)]
+    pub per_page: Option<u32>,
+    /// Limit results to those matching a string.
+    #[uniffi(default = None)]
+    pub search: Option<String>,
+    /// Ensure result set excludes specific IDs.
+    #[uniffi(default = [])]
+    pub exclude: Vec<PostId>,
+    /// Limit result set to specific IDs.
+    #[uniffi(default = [])]
+    pub include: Vec<PostId>,
+    /// Order sort attribute ascending or descending.
+    /// Default: desc
+    /// One of: asc, desc
+    #[uniffi(default = None)]
+    pub order: Option<WpApiParamOrder>,
+    /// Sort collection by post attribute.
+    /// Default: date
+    #[uniffi(default = None)]
+    pub orderby: Option<WpApiParamPostsOrderBy>,
+    /// Limit result set to navigations with one or more specific slugs.
+    #[uniffi(default = [])]
+    pub slug: Vec<String>,
+    /// Limit result set to navigations assigned one or more statuses.
+    /// Default: publish
+    #[uniffi(default = [])]
+    pub status: Vec<PostStatus>,
+}
+
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct NavigationCreateParams {
+    /// The title for the navigation.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The navigation blocks, in the block grammar.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// A named status for the navigation.
+    /// One of: publish, future, draft, pending, private
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<PostStatus>,
+    /// An alphanumeric identifier for the navigation unique to its type.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct NavigationUpdateParams {
+    /// The title for the navigation.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The navigation blocks, in the block grammar.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// A named status for the navigation.
+    /// One of: publish, future, draft, pending, private
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<PostStatus>,
+    /// An alphanumeric identifier for the navigation unique to its type.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
+pub struct NavigationDeleteResponse {
+    pub deleted: bool,
+    pub previous: NavigationWithEditContext,
+}
+
+// Block-based navigation menus are posts of the `wp_navigation` type, which store the
+// navigation blocks as their content.
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseNavigation {
+    #[WpContext(edit, embed, view)]
+    pub id: Option<PostId>,
+    #[WpContext(edit, view)]
+    pub date: Option<String>,
+    #[WpContext(edit, view)]
+    pub date_gmt: Option<String>,
+    #[WpContext(edit, view)]
+    #[WpContextualField]
+    pub guid: Option<crate::posts::SparsePostGuid>,
+    #[WpContext(edit, embed, view)]
+    pub link: Option<String>,
+    #[WpContext(edit, view)]
+    pub modified: Option<String>,
+    #[WpContext(edit, view)]
+    pub modified_gmt: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub slug: Option<String>,
+    #[WpContext(edit, view)]
+    pub status: Option<PostStatus>,
+    #[serde(rename = "type")]
+    #[WpContext(edit, embed, view)]
+    pub post_type: Option<String>,
+    #[WpContext(edit)]
+    pub password: Option<String>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualField]
+    pub title: Option<crate::posts::SparsePostTitle>,
+    #[WpContext(edit, view)]
+    #[WpContextualField]
+    pub content: Option<crate::posts::SparsePostContent>,
+    #[WpContext(edit, view)]
+    pub template: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, unit_test_common::assert_expected_query_pairs};
+    use rstest::*;
+
+    #[rstest]
+    #[case(NavigationListParams::default(), &[])]
+    #[case(generate!(NavigationListParams, (search, Some("foo".to_string()))), &[("search", "foo")])]
+    #[case(generate!(NavigationListParams, (include, vec![PostId(1), PostId(2)])), &[("include", "1,2")])]
+    #[case(generate!(NavigationListParams, (status, vec![PostStatus::Publish, PostStatus::Draft])), &[("status", "publish,draft")])]
+    #[trace]
+    fn test_navigation_list_params(
+        #[case] params: NavigationListParams,
+        #[case] expected_pairs: &[(&str, &str)],
+    ) {
+        assert_expected_query_pairs(params.query_pairs(), expected_pairs);
+    }
+}
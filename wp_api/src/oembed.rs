@@ -0,0 +1,280 @@
+use serde::{Deserialize, Serialize};
+use wp_serde_helper::deserialize_option_u32_or_string;
+
+/// Returns the oEmbed data of a post on the site, from its URL.
+#[derive(Debug, PartialEq, Eq, uniffi::Record)]
+pub struct OEmbedParams {
+    /// The URL of the post to embed.
+    pub url: String,
+    /// The maximum width of the embed frame in pixels.
+    /// Default: `600`
+    #[uniffi(default = None)]
+    pub maxwidth: Option<u32>,
+}
+
+impl OEmbedParams {
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            maxwidth: None,
+        }
+    }
+
+    // `url` is required, which `WpQueryParams` doesn't support
+    pub fn query_pairs(&self) -> impl IntoIterator<Item = (&str, String)> {
+        [
+            Some(("url", self.url.clone())),
+            self.maxwidth.map(|v| ("maxwidth", v.to_string())),
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+/// Returns the oEmbed data of any URL through the site, such as a YouTube video. The site caches
+/// the responses, & only users who can edit posts can make proxied requests.
+#[derive(Debug, PartialEq, Eq, uniffi::Record)]
+pub struct OEmbedProxyParams {
+    /// The URL to embed.
+    pub url: String,
+    /// The maximum width of the embed frame in pixels.
+    #[uniffi(default = None)]
+    pub maxwidth: Option<u32>,
+    /// The maximum height of the embed frame in pixels.
+    #[uniffi(default = None)]
+    pub maxheight: Option<u32>,
+    /// Whether to perform an oEmbed discovery request for URLs of unsanctioned providers.
+    /// Default: `true`
+    #[uniffi(default = None)]
+    pub discover: Option<bool>,
+}
+
+impl OEmbedProxyParams {
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            maxwidth: None,
+            maxheight: None,
+            discover: None,
+        }
+    }
+
+    // `url` is required, which `WpQueryParams` doesn't support
+    pub fn query_pairs(&self) -> impl IntoIterator<Item = (&str, String)> {
+        [
+            Some(("url", self.url.clone())),
+            self.maxwidth.map(|v| ("maxwidth", v.to_string())),
+            self.maxheight.map(|v| ("maxheight", v.to_string())),
+            self.discover.map(|v| ("discover", v.to_string())),
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+/// An oEmbed response, as described in https://oembed.com.
+///
+/// Proxied responses come from third-party providers as they are, so the numeric fields are
+/// parsed leniently.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Enum)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OEmbedResponse {
+    Photo(OEmbedPhoto),
+    Video(OEmbedVideo),
+    Link(OEmbedLink),
+    Rich(OEmbedRich),
+}
+
+impl OEmbedResponse {
+    pub fn details(&self) -> &OEmbedDetails {
+        match self {
+            Self::Photo(photo) => &photo.details,
+            Self::Video(video) => &video.details,
+            Self::Link(link) => &link.details,
+            Self::Rich(rich) => &rich.details,
+        }
+    }
+
+    /// The HTML to embed, for `video` & `rich` responses.
+    pub fn html(&self) -> Option<&str> {
+        match self {
+            Self::Video(video) => Some(video.html.as_str()),
+            Self::Rich(rich) => Some(rich.html.as_str()),
+            Self::Photo(_) | Self::Link(_) => None,
+        }
+    }
+}
+
+/// The fields that are shared by all types of oEmbed responses.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+pub struct OEmbedDetails {
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub author_name: Option<String>,
+    #[serde(default)]
+    pub author_url: Option<String>,
+    #[serde(default)]
+    pub provider_name: Option<String>,
+    #[serde(default)]
+    pub provider_url: Option<String>,
+    /// The suggested cache lifetime of the response, in seconds.
+    #[serde(default, deserialize_with = "deserialize_option_u32_or_string")]
+    pub cache_age: Option<u32>,
+    #[serde(default)]
+    pub thumbnail_url: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_option_u32_or_string")]
+    pub thumbnail_width: Option<u32>,
+    #[serde(default, deserialize_with = "deserialize_option_u32_or_string")]
+    pub thumbnail_height: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+pub struct OEmbedPhoto {
+    #[serde(flatten)]
+    pub details: OEmbedDetails,
+    /// The URL of the image.
+    pub url: String,
+    #[serde(default, deserialize_with = "deserialize_option_u32_or_string")]
+    pub width: Option<u32>,
+    #[serde(default, deserialize_with = "deserialize_option_u32_or_string")]
+    pub height: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+pub struct OEmbedVideo {
+    #[serde(flatten)]
+    pub details: OEmbedDetails,
+    /// The HTML of the video player.
+    pub html: String,
+    #[serde(default, deserialize_with = "deserialize_option_u32_or_string")]
+    pub width: Option<u32>,
+    #[serde(default, deserialize_with = "deserialize_option_u32_or_string")]
+    pub height: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+pub struct OEmbedLink {
+    #[serde(flatten)]
+    pub details: OEmbedDetails,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+pub struct OEmbedRich {
+    #[serde(flatten)]
+    pub details: OEmbedDetails,
+    /// The HTML to display the resource.
+    pub html: String,
+    #[serde(default, deserialize_with = "deserialize_option_u32_or_string")]
+    pub width: Option<u32>,
+    #[serde(default, deserialize_with = "deserialize_option_u32_or_string")]
+    pub height: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_test_common::assert_expected_query_pairs;
+    use rstest::*;
+    use serde_json::json;
+
+    #[rstest]
+    #[case(OEmbedParams::new("https://example.com/hello-world".to_string()), &[("url", "https://example.com/hello-world")])]
+    #[case(OEmbedParams { maxwidth: Some(400), ..OEmbedParams::new("https://example.com/hello-world".to_string()) }, &[("url", "https://example.com/hello-world"), ("maxwidth", "400")])]
+    #[trace]
+    fn test_oembed_params(#[case] params: OEmbedParams, #[case] expected_pairs: &[(&str, &str)]) {
+        assert_expected_query_pairs(params.query_pairs(), expected_pairs);
+    }
+
+    #[rstest]
+    #[case(OEmbedProxyParams::new("https://youtu.be/foo".to_string()), &[("url", "https://youtu.be/foo")])]
+    #[case(
+        OEmbedProxyParams { maxwidth: Some(400), maxheight: Some(300), discover: Some(false), ..OEmbedProxyParams::new("https://youtu.be/foo".to_string()) },
+        &[("url", "https://youtu.be/foo"), ("maxwidth", "400"), ("maxheight", "300"), ("discover", "false")]
+    )]
+    #[trace]
+    fn test_oembed_proxy_params(
+        #[case] params: OEmbedProxyParams,
+        #[case] expected_pairs: &[(&str, &str)],
+    ) {
+        assert_expected_query_pairs(params.query_pairs(), expected_pairs);
+    }
+
+    #[test]
+    fn deserialize_rich_response_of_a_post() {
+        let response: OEmbedResponse = serde_json::from_value(json!({
+            "version": "1.0",
+            "provider_name": "Test Site",
+            "provider_url": "https://example.com",
+            "author_name": "test@example.com",
+            "author_url": "https://example.com/author/test",
+            "title": "Hello world!",
+            "type": "rich",
+            "width": 600,
+            "height": 338,
+            "html": "<blockquote class=\"wp-embedded-content\">Hello world!</blockquote>"
+        }))
+        .unwrap();
+        assert_eq!(response.details().title.as_deref(), Some("Hello world!"));
+        assert_eq!(
+            response.html(),
+            Some("<blockquote class=\"wp-embedded-content\">Hello world!</blockquote>")
+        );
+        let OEmbedResponse::Rich(rich) = response else {
+            panic!("Expected a rich response");
+        };
+        assert_eq!(rich.width, Some(600));
+        assert_eq!(rich.height, Some(338));
+    }
+
+    #[test]
+    fn deserialize_photo_response_with_string_dimensions() {
+        let response: OEmbedResponse = serde_json::from_value(json!({
+            "type": "photo",
+            "version": "1.0",
+            "url": "https://example.com/photo.jpg",
+            "width": "1024",
+            "height": "768",
+            "cache_age": 3600
+        }))
+        .unwrap();
+        assert_eq!(
+            response,
+            OEmbedResponse::Photo(OEmbedPhoto {
+                details: OEmbedDetails {
+                    cache_age: Some(3600),
+                    ..Default::default()
+                },
+                url: "https://example.com/photo.jpg".to_string(),
+                width: Some(1024),
+                height: Some(768),
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_video_response_without_height() {
+        let response: OEmbedResponse = serde_json::from_value(json!({
+            "type": "video",
+            "version": 1.0,
+            "html": "<iframe></iframe>",
+            "width": 560,
+            "height": null
+        }))
+        .unwrap();
+        let OEmbedResponse::Video(video) = response else {
+            panic!("Expected a video response");
+        };
+        assert_eq!(video.width, Some(560));
+        assert_eq!(video.height, None);
+    }
+
+    #[test]
+    fn deserialize_link_response() {
+        let response: OEmbedResponse =
+            serde_json::from_value(json!({ "type": "link", "version": "1.0", "title": "Foo" }))
+                .unwrap();
+        assert_eq!(response.details().title.as_deref(), Some("Foo"));
+        assert_eq!(response.html(), None);
+    }
+}
@@ -1,9 +1,12 @@
-use std::fmt::Display;
+use std::{cmp::Ordering, collections::HashSet, fmt::Debug, fmt::Display};
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use wp_contextual::WpContextual;
+use wp_derive_request_builder::WpQueryParams;
 
-#[derive(Debug, Default, uniffi::Record)]
+use crate::{query_params::impl_query_param_value_via_as_str, WpApiError};
+
+#[derive(Debug, Default, PartialEq, Eq, uniffi::Record, WpQueryParams)]
 pub struct PluginListParams {
     /// Limit results to those matching a string.
     #[uniffi(default = None)]
@@ -13,18 +16,6 @@ pub struct PluginListParams {
     pub status: Option<PluginStatus>,
 }
 
-impl PluginListParams {
-    pub fn query_pairs(&self) -> impl IntoIterator<Item = (&str, String)> {
-        [
-            ("search", self.search.clone()),
-            ("status", self.status.map(|x| x.as_str().to_string())),
-        ]
-        .into_iter()
-        // Remove `None` values
-        .filter_map(|(k, opt_v)| opt_v.map(|v| (k, v)))
-    }
-}
-
 #[derive(Debug, Serialize, uniffi::Record)]
 pub struct PluginCreateParams {
     /// WordPress.org plugin directory slug.
@@ -69,6 +60,12 @@ pub struct SparsePlugin {
     pub requires_php: Option<String>,
     #[WpContext(edit, view)]
     pub textdomain: Option<String>,
+    /// The plugins that must be installed & active before this plugin can be activated, from
+    /// the plugin's `Requires Plugins` header. Missing if the site doesn't return it.
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    #[serde(default, deserialize_with = "deserialize_requires_plugins")]
+    pub requires_plugins: Option<Vec<PluginWpOrgDirectorySlug>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, uniffi::Record)]
@@ -77,7 +74,9 @@ pub struct PluginDeleteResponse {
     pub previous: PluginWithEditContext,
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, uniffi::Record)]
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, uniffi::Record,
+)]
 #[serde(transparent)]
 pub struct PluginSlug {
     pub slug: String,
@@ -87,6 +86,16 @@ impl PluginSlug {
     pub fn new(slug: String) -> Self {
         Self { slug }
     }
+
+    /// The slug other plugins use to require this plugin, which is the plugin's directory, i.e.
+    /// `hello-dolly` for `hello-dolly/hello`, or its file name for single file plugins.
+    pub fn wp_org_directory_slug(&self) -> PluginWpOrgDirectorySlug {
+        let slug = self
+            .slug
+            .split_once('/')
+            .map_or(self.slug.as_str(), |(directory, _)| directory);
+        PluginWpOrgDirectorySlug::from(slug.trim_end_matches(".php"))
+    }
 }
 
 impl From<&str> for PluginSlug {
@@ -103,12 +112,18 @@ impl Display for PluginSlug {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, uniffi::Record)]
 #[serde(transparent)]
 pub struct PluginWpOrgDirectorySlug {
     pub slug: String,
 }
 
+impl PluginWpOrgDirectorySlug {
+    pub fn new(slug: String) -> Self {
+        Self { slug }
+    }
+}
+
 impl From<&str> for PluginWpOrgDirectorySlug {
     fn from(value: &str) -> Self {
         Self {
@@ -117,6 +132,40 @@ impl From<&str> for PluginWpOrgDirectorySlug {
     }
 }
 
+impl Display for PluginWpOrgDirectorySlug {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.slug)
+    }
+}
+
+// `requires_plugins` is either a list of slugs or the comma separated `Requires Plugins` header
+fn deserialize_requires_plugins<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<PluginWpOrgDirectorySlug>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RequiresPlugins {
+        Header(String),
+        List(Vec<String>),
+    }
+    let slugs = match Option::<RequiresPlugins>::deserialize(deserializer)? {
+        Some(RequiresPlugins::Header(header)) => header.split(',').map(str::to_string).collect(),
+        Some(RequiresPlugins::List(slugs)) => slugs,
+        None => return Ok(None),
+    };
+    Ok(Some(
+        slugs
+            .iter()
+            .map(|slug| slug.trim())
+            .filter(|slug| !slug.is_empty())
+            .map(PluginWpOrgDirectorySlug::from)
+            .collect(),
+    ))
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, uniffi::Enum)]
 pub enum PluginStatus {
     #[serde(rename = "active")]
@@ -135,19 +184,294 @@ impl PluginStatus {
             Self::NetworkActive => "network-active",
         }
     }
+
+    fn is_active(&self) -> bool {
+        matches!(self, Self::Active | Self::NetworkActive)
+    }
 }
 
+impl_query_param_value_via_as_str!(PluginStatus, [Active, Inactive, NetworkActive]);
+
 #[derive(Debug, Serialize, Deserialize, uniffi::Record)]
 pub struct PluginDescription {
     pub raw: String,
     pub rendered: String,
 }
 
+/// Returns the plugins `plugin` requires that aren't installed & active, in which case WordPress
+/// refuses to activate it with `WpErrorCode::WpCorePluginMissingDependencies`.
+#[uniffi::export]
+pub fn missing_plugin_dependencies(
+    plugin: &PluginWithEditContext,
+    installed_plugins: &[PluginWithEditContext],
+) -> Vec<PluginWpOrgDirectorySlug> {
+    let active_plugins = installed_plugins
+        .iter()
+        .filter(|p| p.status.is_active())
+        .map(|p| p.plugin.wp_org_directory_slug())
+        .collect::<HashSet<_>>();
+    plugin
+        .requires_plugins
+        .iter()
+        .flatten()
+        .filter(|dependency| !active_plugins.contains(dependency))
+        .cloned()
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error, uniffi::Error)]
+pub enum PluginActionError {
+    /// The client-side counterpart of `WpErrorCode::WpCorePluginMissingDependencies`, which is
+    /// returned before sending the activation request. If the server refuses the activation with
+    /// that error code instead, `missing_dependencies` only has the dependencies the client knew
+    /// to be missing.
+    #[error(
+        "Plugin '{}' requires plugins that aren't installed & active: {:?}",
+        plugin,
+        missing_dependencies
+    )]
+    MissingDependencies {
+        plugin: PluginSlug,
+        missing_dependencies: Vec<PluginWpOrgDirectorySlug>,
+    },
+    #[error("{}", error)]
+    RequestFailed { error: WpApiError },
+}
+
+impl From<WpApiError> for PluginActionError {
+    fn from(error: WpApiError) -> Self {
+        Self::RequestFailed { error }
+    }
+}
+
+// `PluginWithEditContext` is much larger than `PluginActionError`, but boxing it isn't supported
+// by uniffi
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, uniffi::Enum)]
+pub enum PluginBulkActionResult {
+    /// The plugin after the action, or before it was deleted.
+    Succeeded { plugin: PluginWithEditContext },
+    Failed {
+        plugin: PluginSlug,
+        error: PluginActionError,
+    },
+}
+
+/// Orders the plugins to activate so that each plugin is activated after the dependencies that
+/// are activated along with it. Plugins that depend on each other keep their order.
+pub(crate) fn plugin_activation_order(
+    plugins: Vec<PluginSlug>,
+    installed_plugins: &[PluginWithEditContext],
+) -> Vec<PluginSlug> {
+    let requires_plugins = |slug: &PluginSlug| {
+        installed_plugins
+            .iter()
+            .find(|p| p.plugin == *slug)
+            .and_then(|p| p.requires_plugins.clone())
+            .unwrap_or_default()
+    };
+    let mut remaining = plugins;
+    let mut ordered: Vec<PluginSlug> = Vec::with_capacity(remaining.len());
+    while !remaining.is_empty() {
+        // A plugin is ready once none of the remaining plugins is one of its dependencies
+        let ready_index = remaining
+            .iter()
+            .position(|slug| {
+                let dependencies = requires_plugins(slug);
+                !remaining.iter().any(|other| {
+                    other != slug && dependencies.contains(&other.wp_org_directory_slug())
+                })
+            })
+            // Circular dependencies can't be activated in any order, so they're left to fail
+            .unwrap_or(0);
+        ordered.push(remaining.remove(ready_index));
+    }
+    ordered
+}
+
+/// Provides the latest versions of plugins, i.e. from the WordPress.org update check API or a
+/// plugin vendor's own API, as the REST API doesn't include available updates.
+#[uniffi::export(with_foreign)]
+#[async_trait::async_trait]
+pub trait PluginUpdateSource: Send + Sync + Debug {
+    /// Returns the latest versions of the given plugins. Plugins the source doesn't know about
+    /// can be left out.
+    async fn latest_versions(
+        &self,
+        plugins: Vec<PluginSlug>,
+    ) -> Result<Vec<PluginLatestVersion>, PluginUpdateSourceError>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct PluginLatestVersion {
+    pub plugin: PluginSlug,
+    pub version: String,
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error, uniffi::Error)]
+pub enum PluginUpdateSourceError {
+    #[error("Plugin update source failed: {}", reason)]
+    SourceFailed { reason: String },
+}
+
+impl From<uniffi::UnexpectedUniFFICallbackError> for PluginUpdateSourceError {
+    fn from(value: uniffi::UnexpectedUniFFICallbackError) -> Self {
+        Self::SourceFailed {
+            reason: value.reason,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error, uniffi::Error)]
+pub enum PluginUpdateCheckError {
+    #[error("{}", error)]
+    RequestFailed { error: WpApiError },
+    #[error("Plugin update source failed: {}", reason)]
+    SourceFailed { reason: String },
+}
+
+impl From<WpApiError> for PluginUpdateCheckError {
+    fn from(error: WpApiError) -> Self {
+        Self::RequestFailed { error }
+    }
+}
+
+impl From<PluginUpdateSourceError> for PluginUpdateCheckError {
+    fn from(value: PluginUpdateSourceError) -> Self {
+        match value {
+            PluginUpdateSourceError::SourceFailed { reason } => Self::SourceFailed { reason },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct PluginUpdate {
+    pub plugin: PluginSlug,
+    pub current_version: String,
+    pub new_version: String,
+}
+
+/// Returns the installed plugins that have a newer version than the installed one.
+#[uniffi::export]
+pub fn available_plugin_updates(
+    installed_plugins: &[PluginWithEditContext],
+    latest_versions: &[PluginLatestVersion],
+) -> Vec<PluginUpdate> {
+    installed_plugins
+        .iter()
+        .filter_map(|installed| {
+            let latest = latest_versions
+                .iter()
+                .find(|latest| latest.plugin == installed.plugin)?;
+            (compare_plugin_versions(&latest.version, &installed.version) == Ordering::Greater)
+                .then(|| PluginUpdate {
+                    plugin: installed.plugin.clone(),
+                    current_version: installed.version.clone(),
+                    new_version: latest.version.clone(),
+                })
+        })
+        .collect()
+}
+
+// Compares versions such as `1.2.10` & `1.3-beta1` the same way PHP's `version_compare` does,
+// which is what WordPress uses to decide whether a plugin update is available.
+//
+// The versions are split into parts at `.`, `-`, `_` & `+`, and between digits & non-digits. Numbers
+// are compared numerically, and other parts by their special form:
+// `dev` < `alpha` = `a` < `beta` = `b` < `RC` = `rc` < number < `pl` = `p`.
+fn compare_plugin_versions(lhs: &str, rhs: &str) -> Ordering {
+    compare_version_parts(&version_parts(lhs), &version_parts(rhs))
+}
+
+fn version_parts(version: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut part = String::new();
+    for c in version.trim().chars() {
+        let is_separator = matches!(c, '.' | '-' | '_' | '+');
+        let is_digit_boundary = part
+            .chars()
+            .last()
+            .is_some_and(|last| last.is_ascii_digit() != c.is_ascii_digit());
+        if (is_separator || is_digit_boundary) && !part.is_empty() {
+            parts.push(std::mem::take(&mut part));
+        }
+        if !is_separator {
+            part.push(c);
+        }
+    }
+    if !part.is_empty() {
+        parts.push(part);
+    }
+    parts
+}
+
+fn compare_version_parts(lhs: &[String], rhs: &[String]) -> Ordering {
+    if let Some(ordering) = lhs
+        .iter()
+        .zip(rhs)
+        .map(|(lhs, rhs)| compare_version_part(lhs, rhs))
+        .find(|ordering| ordering.is_ne())
+    {
+        return ordering;
+    }
+    // When one of the versions has more parts, the extra parts are compared against a number, so
+    // `1.0.1` is newer than `1.0`, while `1.0-beta` is older.
+    let number = ["#N#".to_string()];
+    let len = lhs.len().min(rhs.len());
+    match (lhs.get(len), rhs.get(len)) {
+        (Some(lhs_part), None) if is_version_number(lhs_part) => Ordering::Greater,
+        (Some(_), None) => compare_version_parts(&lhs[len..], &number),
+        (None, Some(rhs_part)) if is_version_number(rhs_part) => Ordering::Less,
+        (None, Some(_)) => compare_version_parts(&number, &rhs[len..]),
+        _ => Ordering::Equal,
+    }
+}
+
+fn compare_version_part(lhs: &str, rhs: &str) -> Ordering {
+    match (is_version_number(lhs), is_version_number(rhs)) {
+        (true, true) => {
+            // Compared without parsing, so long numbers don't overflow
+            let lhs = lhs.trim_start_matches('0');
+            let rhs = rhs.trim_start_matches('0');
+            lhs.len().cmp(&rhs.len()).then_with(|| lhs.cmp(rhs))
+        }
+        (true, false) => special_version_form_order("#").cmp(&special_version_form_order(rhs)),
+        (false, true) => special_version_form_order(lhs).cmp(&special_version_form_order("#")),
+        (false, false) => special_version_form_order(lhs).cmp(&special_version_form_order(rhs)),
+    }
+}
+
+fn is_version_number(part: &str) -> bool {
+    part.chars().all(|c| c.is_ascii_digit())
+}
+
+// Like PHP, a part matches a special form if it starts with it, and parts that don't match any of
+// them are older than `dev`.
+fn special_version_form_order(part: &str) -> i8 {
+    const SPECIAL_FORMS: [(&str, i8); 10] = [
+        ("dev", 0),
+        ("alpha", 1),
+        ("a", 1),
+        ("beta", 2),
+        ("b", 2),
+        ("RC", 3),
+        ("rc", 3),
+        ("#", 4),
+        ("pl", 5),
+        ("p", 5),
+    ];
+    SPECIAL_FORMS
+        .iter()
+        .find(|(form, _)| part.starts_with(form))
+        .map_or(-6, |(_, order)| *order)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{generate, unit_test_common::assert_expected_query_pairs};
     use rstest::*;
+    use serde_json::json;
 
     #[rstest]
     #[case(PluginListParams::default(), &[])]
@@ -161,4 +485,189 @@ mod tests {
     ) {
         assert_expected_query_pairs(params.query_pairs(), expected_pairs);
     }
+
+    #[rstest]
+    #[case(json!(["woocommerce", "jetpack"]), Some(vec!["woocommerce", "jetpack"]))]
+    #[case(json!("woocommerce, jetpack,"), Some(vec!["woocommerce", "jetpack"]))]
+    #[case(json!(""), Some(vec![]))]
+    #[case(json!(null), None)]
+    fn deserialize_requires_plugins(
+        #[case] requires_plugins: serde_json::Value,
+        #[case] expected: Option<Vec<&str>>,
+    ) {
+        let mut json = plugin_json("foo/foo", "inactive", "1.0");
+        json["requires_plugins"] = requires_plugins;
+        let plugin: PluginWithEditContext = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            plugin.requires_plugins,
+            expected.map(|slugs| slugs
+                .into_iter()
+                .map(PluginWpOrgDirectorySlug::from)
+                .collect())
+        );
+    }
+
+    #[test]
+    fn deserialize_plugin_without_requires_plugins() {
+        let mut json = plugin_json("foo/foo", "inactive", "1.0");
+        json.as_object_mut().unwrap().remove("requires_plugins");
+        let plugin: PluginWithEditContext = serde_json::from_value(json).unwrap();
+        assert_eq!(plugin.requires_plugins, None);
+    }
+
+    #[rstest]
+    #[case("hello-dolly/hello", "hello-dolly")]
+    #[case("hello", "hello")]
+    #[case("hello.php", "hello")]
+    fn test_wp_org_directory_slug(#[case] plugin_slug: &str, #[case] expected: &str) {
+        assert_eq!(
+            PluginSlug::from(plugin_slug).wp_org_directory_slug(),
+            PluginWpOrgDirectorySlug::from(expected)
+        );
+    }
+
+    #[test]
+    fn test_missing_plugin_dependencies() {
+        let installed_plugins = vec![
+            plugin("woocommerce/woocommerce", "active", &[]),
+            plugin("jetpack/jetpack", "inactive", &[]),
+            plugin(
+                "extension/extension",
+                "inactive",
+                &["woocommerce", "jetpack", "akismet"],
+            ),
+        ];
+        assert_eq!(
+            missing_plugin_dependencies(&installed_plugins[2], &installed_plugins),
+            vec![
+                PluginWpOrgDirectorySlug::from("jetpack"),
+                PluginWpOrgDirectorySlug::from("akismet")
+            ]
+        );
+        assert!(missing_plugin_dependencies(&installed_plugins[0], &installed_plugins).is_empty());
+    }
+
+    #[test]
+    fn test_plugin_activation_order() {
+        let installed_plugins = vec![
+            plugin("woocommerce/woocommerce", "inactive", &[]),
+            plugin("extension/extension", "inactive", &["woocommerce"]),
+            plugin("extension-addon/addon", "inactive", &["extension"]),
+            plugin("hello-dolly/hello", "inactive", &[]),
+        ];
+        let order = plugin_activation_order(
+            vec![
+                "extension-addon/addon".into(),
+                "hello-dolly/hello".into(),
+                "extension/extension".into(),
+                "woocommerce/woocommerce".into(),
+            ],
+            &installed_plugins,
+        );
+        assert_eq!(
+            order,
+            vec![
+                PluginSlug::from("hello-dolly/hello"),
+                PluginSlug::from("woocommerce/woocommerce"),
+                PluginSlug::from("extension/extension"),
+                PluginSlug::from("extension-addon/addon"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plugin_activation_order_with_circular_dependencies() {
+        let installed_plugins = vec![
+            plugin("foo/foo", "inactive", &["bar"]),
+            plugin("bar/bar", "inactive", &["foo"]),
+        ];
+        let plugins = vec![PluginSlug::from("foo/foo"), PluginSlug::from("bar/bar")];
+        assert_eq!(
+            plugin_activation_order(plugins.clone(), &installed_plugins),
+            plugins
+        );
+    }
+
+    #[rstest]
+    #[case("1.0", "1.0", Ordering::Equal)]
+    #[case("1.0", "1.0.0", Ordering::Less)]
+    #[case("1.0.1", "1.0", Ordering::Greater)]
+    #[case("1.2.10", "1.2.9", Ordering::Greater)]
+    #[case("2.0", "10.0", Ordering::Less)]
+    #[case("1.3-beta1", "1.3", Ordering::Less)]
+    #[case("1.3-beta2", "1.3-beta1", Ordering::Greater)]
+    #[case("1.3-beta1", "1.2", Ordering::Greater)]
+    #[case("1.3-beta10", "1.3-beta9", Ordering::Greater)]
+    #[case("1.3-RC1", "1.3-beta1", Ordering::Greater)]
+    #[case("1.3-rc1", "1.3-RC1", Ordering::Equal)]
+    #[case("1.3rc1", "1.3-RC1", Ordering::Equal)]
+    #[case("1.3-a1", "1.3-alpha1", Ordering::Equal)]
+    #[case("1.3-b1", "1.3-alpha2", Ordering::Greater)]
+    #[case("1.3-dev", "1.3-alpha", Ordering::Less)]
+    #[case("1.3-pl1", "1.3", Ordering::Greater)]
+    #[case("1.3-foo", "1.3-dev", Ordering::Less)]
+    #[case("1.3_1", "1.3+1", Ordering::Equal)]
+    fn test_compare_plugin_versions(
+        #[case] lhs: &str,
+        #[case] rhs: &str,
+        #[case] expected: Ordering,
+    ) {
+        assert_eq!(compare_plugin_versions(lhs, rhs), expected);
+    }
+
+    #[test]
+    fn test_available_plugin_updates() {
+        let installed_plugins = vec![
+            serde_json::from_value(plugin_json(
+                "classic-editor/classic-editor",
+                "active",
+                "1.6.3",
+            ))
+            .unwrap(),
+            serde_json::from_value(plugin_json("hello-dolly/hello", "inactive", "1.7.2")).unwrap(),
+            serde_json::from_value(plugin_json("custom/custom", "inactive", "2.0")).unwrap(),
+        ];
+        let latest_versions = vec![
+            PluginLatestVersion {
+                plugin: "classic-editor/classic-editor".into(),
+                version: "1.6.4".to_string(),
+            },
+            PluginLatestVersion {
+                plugin: "hello-dolly/hello".into(),
+                version: "1.7.2".to_string(),
+            },
+        ];
+        assert_eq!(
+            available_plugin_updates(&installed_plugins, &latest_versions),
+            vec![PluginUpdate {
+                plugin: "classic-editor/classic-editor".into(),
+                current_version: "1.6.3".to_string(),
+                new_version: "1.6.4".to_string(),
+            }]
+        );
+    }
+
+    fn plugin(slug: &str, status: &str, requires_plugins: &[&str]) -> PluginWithEditContext {
+        let mut json = plugin_json(slug, status, "1.0");
+        json["requires_plugins"] = json!(requires_plugins);
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn plugin_json(slug: &str, status: &str, version: &str) -> serde_json::Value {
+        json!({
+            "plugin": slug,
+            "status": status,
+            "name": slug,
+            "plugin_uri": "",
+            "author": "",
+            "author_uri": "",
+            "description": { "raw": "", "rendered": "" },
+            "version": version,
+            "network_only": false,
+            "requires_wp": "",
+            "requires_php": "",
+            "textdomain": slug,
+            "requires_plugins": []
+        })
+    }
 }
@@ -3,6 +3,8 @@ use std::{collections::HashMap, fmt::Display};
 use serde::{Deserialize, Serialize};
 use wp_contextual::WpContextual;
 
+use crate::query_params::QueryParamValue;
+
 #[derive(
     Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, uniffi::Enum,
 )]
@@ -41,6 +43,16 @@ impl Display for PostType {
     }
 }
 
+impl QueryParamValue for PostType {
+    fn to_query_value(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+
+    fn from_query_value(value: &str) -> Option<Self> {
+        serde_json::from_value(serde_json::Value::String(value.to_string())).ok()
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
 #[serde(transparent)]
 pub struct SparsePostTypesResponse {
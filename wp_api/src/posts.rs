@@ -1,7 +1,12 @@
 use serde::{Deserialize, Serialize};
 use wp_contextual::WpContextual;
 
-use crate::{UserId, WpApiParamOrder};
+use wp_derive_request_builder::WpQueryParams;
+
+use crate::{
+    query_params::{impl_query_param_value_for_newtype, impl_query_param_value_via_as_str},
+    UserId, WpApiError, WpApiParamOrder,
+};
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
 pub enum WpApiParamPostsOrderBy {
@@ -35,6 +40,22 @@ impl WpApiParamPostsOrderBy {
     }
 }
 
+impl_query_param_value_via_as_str!(
+    WpApiParamPostsOrderBy,
+    [
+        Author,
+        Date,
+        Id,
+        Include,
+        IncludeSlugs,
+        Modified,
+        Parent,
+        Relevance,
+        Slug,
+        Title
+    ]
+);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
 pub enum WpApiParamPostsTaxRelation {
     And,
@@ -50,6 +71,8 @@ impl WpApiParamPostsTaxRelation {
     }
 }
 
+impl_query_param_value_via_as_str!(WpApiParamPostsTaxRelation, [And, Or]);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
 pub enum WpApiParamPostsSearchColumn {
     PostContent,
@@ -67,7 +90,12 @@ impl WpApiParamPostsSearchColumn {
     }
 }
 
-#[derive(Debug, Default, uniffi::Record)]
+impl_query_param_value_via_as_str!(
+    WpApiParamPostsSearchColumn,
+    [PostContent, PostExcerpt, PostTitle]
+);
+
+#[derive(Debug, Default, PartialEq, Eq, uniffi::Record, WpQueryParams)]
 pub struct PostListParams {
     /// Current page of the collection.
     /// Default: `1`
@@ -148,142 +176,80 @@ pub struct PostListParams {
     pub sticky: Option<bool>,
 }
 
-impl PostListParams {
-    pub fn query_pairs(&self) -> impl IntoIterator<Item = (&str, String)> {
-        [
-            ("page", self.page.map(|x| x.to_string())),
-            ("per_page", self.per_page.map(|x| x.to_string())),
-            ("search", self.search.clone()),
-            ("after", self.after.clone()),
-            ("modified_after", self.modified_after.clone()),
-            (
-                "author",
-                (!self.author.is_empty()).then_some(
-                    self.author
-                        .iter()
-                        .map(|x| x.to_string())
-                        .collect::<Vec<String>>()
-                        .join(","),
-                ),
-            ),
-            (
-                "author_exclude",
-                (!self.author_exclude.is_empty()).then_some(
-                    self.author_exclude
-                        .iter()
-                        .map(|x| x.to_string())
-                        .collect::<Vec<String>>()
-                        .join(","),
-                ),
-            ),
-            ("before", self.before.clone()),
-            ("modified_before", self.modified_before.clone()),
-            (
-                "exclude",
-                (!self.exclude.is_empty()).then_some(
-                    self.exclude
-                        .iter()
-                        .map(|x| x.to_string())
-                        .collect::<Vec<String>>()
-                        .join(","),
-                ),
-            ),
-            (
-                "include",
-                (!self.include.is_empty()).then_some(
-                    self.include
-                        .iter()
-                        .map(|x| x.to_string())
-                        .collect::<Vec<String>>()
-                        .join(","),
-                ),
-            ),
-            ("offset", self.offset.map(|x| x.to_string())),
-            ("order", self.order.as_ref().map(|x| x.as_str().to_string())),
-            (
-                "orderby",
-                self.orderby.as_ref().map(|x| x.as_str().to_string()),
-            ),
-            (
-                "search_columns",
-                (!self.search_columns.is_empty()).then_some(
-                    self.search_columns
-                        .iter()
-                        .map(|x| x.as_str().to_string())
-                        .collect::<Vec<String>>()
-                        .join(","),
-                ),
-            ),
-            (
-                "slug",
-                (!self.slug.is_empty()).then_some(
-                    self.slug
-                        .iter()
-                        .map(|x| x.to_string())
-                        .collect::<Vec<String>>()
-                        .join(","),
-                ),
-            ),
-            (
-                "status",
-                (!self.status.is_empty()).then_some(
-                    self.status
-                        .iter()
-                        .map(|x| x.as_str().to_string())
-                        .collect::<Vec<String>>()
-                        .join(","),
-                ),
-            ),
-            (
-                "tax_relation",
-                self.tax_relation.as_ref().map(|x| x.as_str().to_string()),
-            ),
-            (
-                "categories",
-                (!self.categories.is_empty()).then_some(
-                    self.categories
-                        .iter()
-                        .map(|x| x.0.to_string())
-                        .collect::<Vec<String>>()
-                        .join(","),
-                ),
-            ),
-            (
-                "categories_exclude",
-                (!self.categories_exclude.is_empty()).then_some(
-                    self.categories_exclude
-                        .iter()
-                        .map(|x| x.0.to_string())
-                        .collect::<Vec<String>>()
-                        .join(","),
-                ),
-            ),
-            (
-                "tags",
-                (!self.tags.is_empty()).then_some(
-                    self.tags
-                        .iter()
-                        .map(|x| x.0.to_string())
-                        .collect::<Vec<String>>()
-                        .join(","),
-                ),
-            ),
-            (
-                "tags_exclude",
-                (!self.tags_exclude.is_empty()).then_some(
-                    self.tags_exclude
-                        .iter()
-                        .map(|x| x.0.to_string())
-                        .collect::<Vec<String>>()
-                        .join(","),
-                ),
-            ),
-            ("sticky", self.sticky.map(|x| x.to_string())),
-        ]
-        .into_iter()
-        // Remove `None` values
-        .filter_map(|(k, opt_v)| opt_v.map(|v| (k, v)))
-    }
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct PostUpdateParams {
+    /// The date the post was published, in the site's timezone.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    /// The date the post was published, as GMT.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_gmt: Option<String>,
+    /// An alphanumeric identifier for the post unique to its type.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    /// A named status for the post.
+    /// One of: publish, future, draft, pending, private
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<PostStatus>,
+    /// A password to protect access to the content and excerpt.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    /// The title for the post.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The content for the post.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// The ID for the author of the post.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<UserId>,
+    /// The excerpt for the post.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excerpt: Option<String>,
+    /// The ID of the featured media for the post.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub featured_media: Option<i64>,
+    /// Whether or not comments are open on the post.
+    /// One of: open, closed
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment_status: Option<PostCommentStatus>,
+    /// Whether or not the post can be pinged.
+    /// One of: open, closed
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ping_status: Option<PostPingStatus>,
+    /// The format for the post.
+    /// One of: standard, aside, chat, gallery, link, image, quote, status, video, audio
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<PostFormat>,
+    /// Whether or not the post should be treated as sticky.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sticky: Option<bool>,
+    /// The theme file to use to display the post.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    /// The terms assigned to the post in the category taxonomy.
+    #[uniffi(default = [])]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub categories: Vec<CategoryId>,
+    /// The terms assigned to the post in the post_tag taxonomy.
+    #[uniffi(default = [])]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<TagId>,
 }
 
 uniffi::custom_newtype!(PostId, i32);
@@ -304,6 +270,8 @@ impl std::fmt::Display for PostId {
     }
 }
 
+impl_query_param_value_for_newtype!(PostId, TagId, CategoryId);
+
 #[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
 pub struct SparsePost {
     #[WpContext(edit, embed, view)]
@@ -313,7 +281,8 @@ pub struct SparsePost {
     #[WpContext(edit, view)]
     pub date_gmt: Option<String>,
     #[WpContext(edit, view)]
-    pub guid: Option<PostGuid>,
+    #[WpContextualField]
+    pub guid: Option<SparsePostGuid>,
     #[WpContext(edit, embed, view)]
     pub link: Option<String>,
     #[WpContext(edit, view)]
@@ -334,13 +303,16 @@ pub struct SparsePost {
     #[WpContext(edit)]
     pub generated_slug: Option<String>,
     #[WpContext(edit, embed, view)]
-    pub title: Option<PostTitle>,
+    #[WpContextualField]
+    pub title: Option<SparsePostTitle>,
     #[WpContext(edit, view)]
-    pub content: Option<PostContent>,
+    #[WpContextualField]
+    pub content: Option<SparsePostContent>,
     #[WpContext(edit, embed, view)]
     pub author: Option<UserId>,
     #[WpContext(edit, embed, view)]
-    pub excerpt: Option<PostExcerpt>,
+    #[WpContextualField]
+    pub excerpt: Option<SparsePostExcerpt>,
     #[WpContext(edit, embed, view)]
     pub featured_media: Option<i64>,
     #[WpContext(edit, view)]
@@ -361,26 +333,110 @@ pub struct SparsePost {
     pub tags: Option<Vec<TagId>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
-pub struct PostGuid {
-    pub rendered: String,
+// `PostWithEditContext` is much larger than `WpApiError`, but boxing it isn't supported by uniffi
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum PostUpdateError {
+    #[error(
+        "Post was modified on the server at '{}', after the client's version from '{}'",
+        server_version.modified_gmt,
+        client_modified_gmt
+    )]
+    Conflict {
+        client_modified_gmt: String,
+        server_version: PostWithEditContext,
+    },
+    #[error("{}", error)]
+    RequestFailed { error: WpApiError },
 }
 
-#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
-pub struct PostTitle {
-    pub rendered: String,
+impl From<WpApiError> for PostUpdateError {
+    fn from(error: WpApiError) -> Self {
+        Self::RequestFailed { error }
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
-pub struct PostContent {
-    pub rendered: String,
-    pub protected: bool,
+/// The result of [`merge_sparse_posts`].
+#[derive(Debug, uniffi::Record)]
+pub struct SparsePostMerge {
+    /// The merged post. Conflicting fields keep their local value.
+    pub merged: SparsePost,
+    /// The names of the fields that were changed to different values both locally & remotely.
+    pub conflicting_fields: Vec<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
-pub struct PostExcerpt {
-    pub rendered: String,
-    pub protected: bool,
+/// Merges the `local` & `remote` versions of a post field by field, using `base` as the version
+/// they were both derived from.
+///
+/// A field that was only changed on one side takes the changed value, so only the fields that
+/// were changed on both sides need to be resolved by the user.
+#[uniffi::export]
+pub fn merge_sparse_posts(
+    base: SparsePost,
+    local: SparsePost,
+    remote: SparsePost,
+) -> SparsePostMerge {
+    let to_map = |post: &SparsePost| match serde_json::to_value(post) {
+        Ok(serde_json::Value::Object(map)) => map,
+        _ => unreachable!("`SparsePost` is serialized as a JSON object"),
+    };
+    let (base, mut local, remote) = (to_map(&base), to_map(&local), to_map(&remote));
+    let mut conflicting_fields = Vec::new();
+    for (field, remote_value) in remote {
+        let base_value = base.get(&field).unwrap_or(&serde_json::Value::Null);
+        let local_value = local.get(&field).unwrap_or(&serde_json::Value::Null);
+        if remote_value == *local_value || remote_value == *base_value {
+            continue;
+        }
+        if local_value == base_value {
+            local.insert(field, remote_value);
+        } else {
+            conflicting_fields.push(field);
+        }
+    }
+    SparsePostMerge {
+        merged: serde_json::from_value(serde_json::Value::Object(local))
+            .expect("Merged fields are taken from valid `SparsePost`s"),
+        conflicting_fields,
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparsePostGuid {
+    #[WpContext(edit)]
+    pub raw: Option<String>,
+    #[WpContext(edit, view)]
+    pub rendered: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparsePostTitle {
+    #[WpContext(edit)]
+    pub raw: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub rendered: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparsePostContent {
+    #[WpContext(edit)]
+    pub raw: Option<String>,
+    #[WpContext(edit, view)]
+    pub rendered: Option<String>,
+    #[WpContext(edit, view)]
+    pub protected: Option<bool>,
+    #[WpContext(edit)]
+    pub block_version: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparsePostExcerpt {
+    #[WpContext(edit)]
+    pub raw: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub rendered: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub protected: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, uniffi::Record)]
@@ -409,6 +465,11 @@ pub enum PostStatus {
     Private,
     #[default]
     Publish,
+    Trash,
+    #[serde(rename = "auto-draft")]
+    AutoDraft,
+    /// The status of attachments & revisions, which inherit the status of their parent post.
+    Inherit,
     #[serde(untagged)]
     Custom(String),
 }
@@ -421,11 +482,26 @@ impl PostStatus {
             Self::Pending => "pending",
             Self::Private => "private",
             Self::Publish => "publish",
+            Self::Trash => "trash",
+            Self::AutoDraft => "auto-draft",
+            Self::Inherit => "inherit",
             Self::Custom(status) => status,
         }
     }
 }
 
+impl std::fmt::Display for PostStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl_query_param_value_via_as_str!(
+    PostStatus,
+    [Draft, Future, Pending, Private, Publish, Trash, AutoDraft, Inherit],
+    Custom
+);
+
 #[derive(
     Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, uniffi::Enum,
 )]
@@ -466,3 +542,35 @@ pub enum PostFormat {
     #[serde(untagged)]
     Custom(String),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sparse_post(value: serde_json::Value) -> SparsePost {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn merge_sparse_posts_takes_changes_from_both_sides() {
+        let base = sparse_post(json!({ "id": 1, "slug": "base", "sticky": false }));
+        let local = sparse_post(json!({ "id": 1, "slug": "local", "sticky": false }));
+        let remote = sparse_post(json!({ "id": 1, "slug": "base", "sticky": true }));
+        let merge = merge_sparse_posts(base, local, remote);
+        assert!(merge.conflicting_fields.is_empty());
+        assert_eq!(merge.merged.slug, Some("local".to_string()));
+        assert_eq!(merge.merged.sticky, Some(true));
+    }
+
+    #[test]
+    fn merge_sparse_posts_reports_conflicting_fields() {
+        let base = sparse_post(json!({ "title": { "raw": "base" }, "template": "" }));
+        let local = sparse_post(json!({ "title": { "raw": "local" }, "template": "a" }));
+        let remote = sparse_post(json!({ "title": { "raw": "remote" }, "template": "a" }));
+        let merge = merge_sparse_posts(base, local, remote);
+        assert_eq!(merge.conflicting_fields, vec!["title".to_string()]);
+        assert_eq!(merge.merged.title.unwrap().raw, Some("local".to_string()));
+        assert_eq!(merge.merged.template, Some("a".to_string()));
+    }
+}
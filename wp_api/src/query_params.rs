@@ -0,0 +1,170 @@
+//! Helpers for the code generated by `#[derive(WpQueryParams)]`.
+//!
+//! The derive macro takes care of the shape of a field (`Option<T>`, `Vec<T>` as CSV or as
+//! `foo[]=` pairs) while [QueryParamValue] takes care of converting a single value.
+
+use std::str::FromStr;
+
+pub trait QueryParamValue: Sized {
+    // Returning `None` will exclude the value from the query
+    fn to_query_value(&self) -> Option<String>;
+
+    fn from_query_value(value: &str) -> Option<Self>;
+}
+
+pub fn optional_query_value<T: QueryParamValue>(value: &Option<T>) -> Option<String> {
+    value.as_ref().and_then(|v| v.to_query_value())
+}
+
+pub fn csv_query_value<T: QueryParamValue>(values: &[T]) -> Option<String> {
+    let values = array_query_values(values);
+    (!values.is_empty()).then_some(values.join(","))
+}
+
+pub fn array_query_values<T: QueryParamValue>(values: &[T]) -> Vec<String> {
+    values.iter().filter_map(|v| v.to_query_value()).collect()
+}
+
+#[derive(Debug)]
+pub struct ParsedQueryPairs {
+    pairs: Vec<(String, String)>,
+}
+
+impl ParsedQueryPairs {
+    pub fn new<K, V>(query_pairs: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        Self {
+            pairs: query_pairs
+                .into_iter()
+                .map(|(k, v)| (k.as_ref().to_string(), v.as_ref().to_string()))
+                .collect(),
+        }
+    }
+
+    // If the key is repeated, the last value wins, same as in PHP
+    pub fn optional<T: QueryParamValue>(&self, key: &str) -> Option<T> {
+        self.values(key).last().and_then(T::from_query_value)
+    }
+
+    pub fn csv<T: QueryParamValue>(&self, key: &str) -> Vec<T> {
+        self.optional::<String>(key)
+            .map(|v| {
+                v.split(',')
+                    .filter_map(|v| T::from_query_value(v.trim()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn array<T: QueryParamValue>(&self, key: &str) -> Vec<T> {
+        self.values(key).filter_map(T::from_query_value).collect()
+    }
+
+    fn values<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> {
+        self.pairs
+            .iter()
+            .filter(move |(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+macro_rules! impl_query_param_value_via_from_str {
+    ($($t:ty),*) => {
+        $(
+            impl QueryParamValue for $t {
+                fn to_query_value(&self) -> Option<String> {
+                    Some(self.to_string())
+                }
+
+                fn from_query_value(value: &str) -> Option<Self> {
+                    <$t>::from_str(value).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_query_param_value_via_from_str!(bool, i32, i64, u32, u64, String);
+
+// Implements [QueryParamValue] for a newtype wrapper such as `UserId`
+macro_rules! impl_query_param_value_for_newtype {
+    ($($t:ident),*) => {
+        $(
+            impl $crate::query_params::QueryParamValue for $t {
+                fn to_query_value(&self) -> Option<String> {
+                    $crate::query_params::QueryParamValue::to_query_value(&self.0)
+                }
+
+                fn from_query_value(value: &str) -> Option<Self> {
+                    $crate::query_params::QueryParamValue::from_query_value(value).map(Self)
+                }
+            }
+        )*
+    };
+}
+
+// Implements [QueryParamValue] for an enum using its `as_str` function. The listed variants are
+// used to parse the value back, and the optional fallback variant takes any other value.
+macro_rules! impl_query_param_value_via_as_str {
+    ($t:ident, [$($variant:ident),*]) => {
+        impl $crate::query_params::QueryParamValue for $t {
+            fn to_query_value(&self) -> Option<String> {
+                Some(self.as_str().to_string())
+            }
+
+            fn from_query_value(value: &str) -> Option<Self> {
+                [$(Self::$variant),*].into_iter().find(|v| v.as_str() == value)
+            }
+        }
+    };
+    ($t:ident, [$($variant:ident),*], $fallback:ident) => {
+        impl $crate::query_params::QueryParamValue for $t {
+            fn to_query_value(&self) -> Option<String> {
+                Some(self.as_str().to_string())
+            }
+
+            fn from_query_value(value: &str) -> Option<Self> {
+                [$(Self::$variant),*]
+                    .into_iter()
+                    .find(|v| v.as_str() == value)
+                    .or_else(|| Some(Self::$fallback(value.to_string())))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_query_param_value_for_newtype;
+pub(crate) use impl_query_param_value_via_as_str;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case(&[], None)]
+    #[case(&[1], Some("1"))]
+    #[case(&[1, 2, 3], Some("1,2,3"))]
+    fn test_csv_query_value(#[case] values: &[u32], #[case] expected: Option<&str>) {
+        assert_eq!(csv_query_value(values).as_deref(), expected);
+    }
+
+    #[rstest]
+    fn test_parsed_query_pairs() {
+        let query_pairs = ParsedQueryPairs::new([
+            ("page", "2"),
+            ("page", "3"),
+            ("include", "1, 2,foo"),
+            ("tags[]", "4"),
+            ("tags[]", "5"),
+        ]);
+        assert_eq!(query_pairs.optional::<u32>("page"), Some(3));
+        assert_eq!(query_pairs.optional::<u32>("per_page"), None);
+        assert_eq!(query_pairs.csv::<u32>("include"), vec![1, 2]);
+        assert_eq!(query_pairs.csv::<u32>("exclude"), Vec::<u32>::new());
+        assert_eq!(query_pairs.array::<u32>("tags[]"), vec![4, 5]);
+    }
+}
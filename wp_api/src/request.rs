@@ -7,7 +7,7 @@ use url::Url;
 
 use crate::{
     api_error::{RequestExecutionError, WpError},
-    WpApiError, WpAuthentication,
+    WpApiError, WpAuthentication, WpUuid,
 };
 
 use self::endpoint::WpEndpointUrl;
@@ -15,7 +15,9 @@ use self::endpoint::WpEndpointUrl;
 pub mod endpoint;
 
 const CONTENT_TYPE_JSON: &str = "application/json";
+const CONTENT_TYPE_MULTIPART_FORM_DATA: &str = "multipart/form-data";
 const LINK_HEADER_KEY: &str = "Link";
+const IDEMPOTENCY_KEY_HEADER_KEY: &str = "Idempotency-Key";
 
 #[derive(Debug)]
 struct InnerRequestBuilder {
@@ -33,6 +35,7 @@ impl InnerRequestBuilder {
             url: url.into(),
             header_map: self.header_map().into(),
             body: None,
+            timeout_ms: None,
         }
     }
 
@@ -40,22 +43,76 @@ impl InnerRequestBuilder {
     where
         T: ?Sized + Serialize,
     {
+        self.request_with_json_body(RequestMethod::POST, url, json_body)
+    }
+
+    fn put<T>(&self, url: ApiEndpointUrl, json_body: &T) -> WpNetworkRequest
+    where
+        T: ?Sized + Serialize,
+    {
+        self.request_with_json_body(RequestMethod::PUT, url, json_body)
+    }
+
+    fn patch<T>(&self, url: ApiEndpointUrl, json_body: &T) -> WpNetworkRequest
+    where
+        T: ?Sized + Serialize,
+    {
+        self.request_with_json_body(RequestMethod::PATCH, url, json_body)
+    }
+
+    fn delete(&self, url: ApiEndpointUrl) -> WpNetworkRequest {
         WpNetworkRequest {
-            method: RequestMethod::POST,
+            method: RequestMethod::DELETE,
+            url: url.into(),
+            header_map: self.header_map().into(),
+            body: None,
+            timeout_ms: None,
+        }
+    }
+
+    fn options(&self, url: ApiEndpointUrl) -> WpNetworkRequest {
+        WpNetworkRequest {
+            method: RequestMethod::OPTIONS,
+            url: url.into(),
+            header_map: self.header_map().into(),
+            body: None,
+            timeout_ms: None,
+        }
+    }
+
+    fn request_with_json_body<T>(
+        &self,
+        method: RequestMethod,
+        url: ApiEndpointUrl,
+        json_body: &T,
+    ) -> WpNetworkRequest
+    where
+        T: ?Sized + Serialize,
+    {
+        WpNetworkRequest {
+            method,
             url: url.into(),
             header_map: self.header_map_for_post_request().into(),
             body: serde_json::to_vec(json_body)
                 .ok()
                 .map(|b| Arc::new(WpNetworkRequestBody::new(b))),
+            timeout_ms: None,
         }
     }
 
-    fn delete(&self, url: ApiEndpointUrl) -> WpNetworkRequest {
+    fn post_multipart(&self, url: ApiEndpointUrl, form: MultipartForm) -> WpNetworkRequest {
+        let mut header_map = self.header_map();
+        header_map.inner.insert(
+            http::header::CONTENT_TYPE,
+            HeaderValue::from_str(&form.content_type())
+                .expect("The multipart boundary is always a valid header value"),
+        );
         WpNetworkRequest {
-            method: RequestMethod::DELETE,
+            method: RequestMethod::POST,
             url: url.into(),
-            header_map: self.header_map().into(),
-            body: None,
+            header_map: header_map.into(),
+            body: Some(Arc::new(WpNetworkRequestBody::new(form.finish()))),
+            timeout_ms: None,
         }
     }
 
@@ -65,14 +122,7 @@ impl InnerRequestBuilder {
             http::header::ACCEPT,
             HeaderValue::from_static(CONTENT_TYPE_JSON),
         );
-        match self.authentication {
-            WpAuthentication::None => (),
-            WpAuthentication::AuthorizationHeader { ref token } => {
-                let hv = HeaderValue::from_str(&format!("Basic {}", token));
-                let hv = hv.expect("It shouldn't be possible to build WpAuthentication::AuthorizationHeader with an invalid token");
-                header_map.insert(http::header::AUTHORIZATION, hv);
-            }
-        };
+        insert_authorization_header(&mut header_map, &self.authentication);
         header_map.into()
     }
 
@@ -86,6 +136,20 @@ impl InnerRequestBuilder {
     }
 }
 
+pub(crate) fn insert_authorization_header(
+    header_map: &mut HeaderMap,
+    authentication: &WpAuthentication,
+) {
+    match authentication {
+        WpAuthentication::None => (),
+        WpAuthentication::AuthorizationHeader { token } => {
+            let hv = HeaderValue::from_str(&format!("Basic {}", token));
+            let hv = hv.expect("It shouldn't be possible to build WpAuthentication::AuthorizationHeader with an invalid token");
+            header_map.insert(http::header::AUTHORIZATION, hv);
+        }
+    };
+}
+
 #[uniffi::export(with_foreign)]
 #[async_trait::async_trait]
 pub trait RequestExecutor: Send + Sync + Debug {
@@ -101,7 +165,7 @@ pub struct WpNetworkRequestBody {
 }
 
 impl WpNetworkRequestBody {
-    fn new(body: Vec<u8>) -> Self {
+    pub(crate) fn new(body: Vec<u8>) -> Self {
         Self { inner: body }
     }
 }
@@ -113,6 +177,112 @@ impl WpNetworkRequestBody {
     }
 }
 
+/// A file that's uploaded as a part of a `multipart/form-data` request.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct WpMultipartFile {
+    /// The name of the form field the file is uploaded in.
+    pub field_name: String,
+    pub file_name: String,
+    /// i.e. `font/woff2`
+    pub mime_type: String,
+    pub contents: Vec<u8>,
+}
+
+// A `multipart/form-data` request body, as described in RFC 7578.
+#[derive(Debug)]
+pub(crate) struct MultipartForm {
+    boundary: String,
+    body: Vec<u8>,
+}
+
+impl MultipartForm {
+    pub(crate) fn new() -> Self {
+        Self {
+            boundary: format!("WpApiFormBoundary{}", uuid::Uuid::new_v4().simple()),
+            body: Vec::new(),
+        }
+    }
+
+    pub(crate) fn text(mut self, name: &str, value: &str) -> Self {
+        self.append_part_headers(&format!("form-data; name=\"{}\"", Self::escape(name)), None);
+        self.body.extend_from_slice(value.as_bytes());
+        self.body.extend_from_slice(b"\r\n");
+        self
+    }
+
+    pub(crate) fn file(mut self, file: &WpMultipartFile) -> Self {
+        self.append_part_headers(
+            &format!(
+                "form-data; name=\"{}\"; filename=\"{}\"",
+                Self::escape(&file.field_name),
+                Self::escape(&file.file_name)
+            ),
+            Some(&Self::strip_line_breaks(&file.mime_type)),
+        );
+        self.body.extend_from_slice(&file.contents);
+        self.body.extend_from_slice(b"\r\n");
+        self
+    }
+
+    fn append_part_headers(&mut self, content_disposition: &str, content_type: Option<&str>) {
+        self.body
+            .extend_from_slice(format!("--{}\r\n", self.boundary).as_bytes());
+        self.body.extend_from_slice(
+            format!("Content-Disposition: {}\r\n", content_disposition).as_bytes(),
+        );
+        if let Some(content_type) = content_type {
+            self.body
+                .extend_from_slice(format!("Content-Type: {}\r\n", content_type).as_bytes());
+        }
+        self.body.extend_from_slice(b"\r\n");
+    }
+
+    fn content_type(&self) -> String {
+        format!(
+            "{}; boundary={}",
+            CONTENT_TYPE_MULTIPART_FORM_DATA, self.boundary
+        )
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        self.body
+            .extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+        self.body
+    }
+
+    // Field & file names are quoted, so quotes & line breaks are percent-encoded the same way
+    // browsers do
+    fn escape(value: &str) -> String {
+        value
+            .replace('"', "%22")
+            .replace('\r', "%0D")
+            .replace('\n', "%0A")
+    }
+
+    // The mime type isn't quoted, so line breaks are removed to keep it from adding headers to
+    // the part
+    fn strip_line_breaks(value: &str) -> String {
+        value.replace(['\r', '\n'], "")
+    }
+}
+
+/// Per-request options that are merged into the request built by the generated
+/// `*_with_options` functions.
+#[derive(Debug, Default, uniffi::Record)]
+pub struct WpRequestOptions {
+    /// Headers to add to the request, such as `X-HTTP-Method-Override` or `Prefer`.
+    #[uniffi(default = None)]
+    pub extra_headers: Option<Arc<WpNetworkHeaderMap>>,
+    /// Query pairs to add to the request url, such as `_envelope` or `_locale=user`.
+    pub extra_query_pairs: HashMap<String, String>,
+    /// Sent as the `Idempotency-Key` header, so a retried request isn't applied twice.
+    #[uniffi(default = None)]
+    pub idempotency_key: Option<Arc<WpUuid>>,
+    /// Overrides the request executor's timeout for this request, in milliseconds.
+    #[uniffi(default = None)]
+    pub timeout_ms: Option<u64>,
+}
+
 // Has custom `Debug` trait implementation
 #[derive(uniffi::Object)]
 pub struct WpNetworkRequest {
@@ -120,6 +290,7 @@ pub struct WpNetworkRequest {
     pub(crate) url: WpEndpointUrl,
     pub(crate) header_map: Arc<WpNetworkHeaderMap>,
     pub(crate) body: Option<Arc<WpNetworkRequestBody>>,
+    pub(crate) timeout_ms: Option<u64>,
 }
 
 #[uniffi::export]
@@ -143,6 +314,49 @@ impl WpNetworkRequest {
     pub fn body_as_string(&self) -> Option<String> {
         self.body.as_ref().map(|b| body_as_string(&b.inner))
     }
+
+    // The request executor should use its own default timeout if this is `None`
+    pub fn timeout_ms(&self) -> Option<u64> {
+        self.timeout_ms
+    }
+}
+
+impl WpNetworkRequest {
+    // Merges the given options into the request. Extra headers replace the default headers with
+    // the same name.
+    pub(crate) fn with_request_options(mut self, request_options: &WpRequestOptions) -> Self {
+        if !request_options.extra_query_pairs.is_empty() {
+            if let Ok(mut url) = Url::parse(&self.url.0) {
+                let mut extra_query_pairs =
+                    request_options.extra_query_pairs.iter().collect::<Vec<_>>();
+                // Keep the url deterministic
+                extra_query_pairs.sort();
+                url.query_pairs_mut().extend_pairs(extra_query_pairs);
+                self.url = url.into();
+            }
+        }
+        let mut header_map = self.header_map.as_header_map();
+        if let Some(extra_headers) = &request_options.extra_headers {
+            extra_headers.inner.keys().for_each(|name| {
+                header_map.remove(name);
+            });
+            extra_headers.inner.iter().for_each(|(name, value)| {
+                header_map.append(name, value.clone());
+            });
+        }
+        if let Some(idempotency_key) = &request_options.idempotency_key {
+            header_map.insert(
+                IDEMPOTENCY_KEY_HEADER_KEY,
+                HeaderValue::from_str(&idempotency_key.uuid_string())
+                    .expect("A hyphenated UUID is always a valid header value"),
+            );
+        }
+        self.header_map = WpNetworkHeaderMap::new(header_map).into();
+        if request_options.timeout_ms.is_some() {
+            self.timeout_ms = request_options.timeout_ms;
+        }
+        self
+    }
 }
 
 impl Debug for WpNetworkRequest {
@@ -223,7 +437,7 @@ impl WpNetworkHeaderMap {
 #[uniffi::export]
 impl WpNetworkHeaderMap {
     #[uniffi::constructor]
-    fn from_multi_map(
+    pub(crate) fn from_multi_map(
         hash_map: HashMap<String, Vec<String>>,
     ) -> Result<Self, WpNetworkHeaderMapError> {
         let inner = hash_map
@@ -248,7 +462,7 @@ impl WpNetworkHeaderMap {
         Ok(Self { inner })
     }
 
-    fn to_map(&self) -> HashMap<String, Vec<String>> {
+    pub(crate) fn to_map(&self) -> HashMap<String, Vec<String>> {
         let mut header_hashmap = HashMap::new();
         self.inner.iter().for_each(|(k, v)| {
             let v = String::from_utf8_lossy(v.as_bytes()).into_owned();
@@ -314,7 +528,7 @@ impl WpNetworkResponse {
         parser(self)
     }
 
-    fn parse_response_for_errors(&self) -> Result<(), WpApiError> {
+    pub(crate) fn parse_response_for_errors(&self) -> Result<(), WpApiError> {
         if let Ok(wp_error) = serde_json::from_slice::<WpError>(&self.body) {
             Err(WpApiError::WpError {
                 error_code: wp_error.code,
@@ -359,13 +573,15 @@ impl Debug for WpNetworkResponse {
     }
 }
 
-#[derive(Debug, Clone, uniffi::Enum)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Enum)]
 pub enum RequestMethod {
     GET,
     POST,
     PUT,
+    PATCH,
     DELETE,
     HEAD,
+    OPTIONS,
 }
 
 fn body_as_string(body: &[u8]) -> String {
@@ -377,6 +593,71 @@ mod tests {
     use super::*;
     use rstest::*;
 
+    #[test]
+    fn multipart_form_body() {
+        let form = MultipartForm::new()
+            .text("font_face_settings", "{\"src\":\"file-0\"}")
+            .file(&WpMultipartFile {
+                field_name: "file-0".to_string(),
+                file_name: "open\"sans.woff2".to_string(),
+                mime_type: "font/woff2".to_string(),
+                contents: vec![0, 1, 2],
+            });
+        let boundary = form.boundary.clone();
+        assert_eq!(
+            form.content_type(),
+            format!("multipart/form-data; boundary={}", boundary)
+        );
+        let mut expected_body = format!(
+            "--{b}\r\nContent-Disposition: form-data; name=\"font_face_settings\"\r\n\r\n{{\"src\":\"file-0\"}}\r\n\
+             --{b}\r\nContent-Disposition: form-data; name=\"file-0\"; filename=\"open%22sans.woff2\"\r\nContent-Type: font/woff2\r\n\r\n",
+            b = boundary
+        )
+        .into_bytes();
+        expected_body.extend_from_slice(&[0, 1, 2]);
+        expected_body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+        assert_eq!(form.finish(), expected_body);
+    }
+
+    #[test]
+    fn multipart_form_file_mime_type_cant_add_part_headers() {
+        let body = String::from_utf8(
+            MultipartForm::new()
+                .file(&WpMultipartFile {
+                    field_name: "file-0".to_string(),
+                    file_name: "open-sans.woff2".to_string(),
+                    mime_type: "font/woff2\r\nX-Injected: true".to_string(),
+                    contents: b"wOF2".to_vec(),
+                })
+                .finish(),
+        )
+        .unwrap();
+        assert!(body.contains("\r\nContent-Type: font/woff2X-Injected: true\r\n\r\nwOF2\r\n"));
+        assert!(!body.contains("\r\nX-Injected"));
+    }
+
+    #[test]
+    fn post_multipart_request() {
+        let request = InnerRequestBuilder::new(WpAuthentication::None).post_multipart(
+            Url::parse("https://example.com/wp-json/wp/v2/font-families/1/font-faces")
+                .unwrap()
+                .into(),
+            MultipartForm::new().text("theme_json_version", "3"),
+        );
+        assert_eq!(request.method, RequestMethod::POST);
+        let content_type = request
+            .header_map
+            .as_header_map()
+            .get(http::header::CONTENT_TYPE)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert!(content_type.starts_with("multipart/form-data; boundary=WpApiFormBoundary"));
+        let body = request.body_as_string().unwrap();
+        assert!(body.contains("name=\"theme_json_version\"\r\n\r\n3\r\n"));
+    }
+
     #[rstest]
     #[case(
         "<http://localhost/wp-json/wp/v2/posts?page=2>; rel=\"next\"",
@@ -477,6 +758,62 @@ mod tests {
         assert_header_map_values(&header_map, "User-Agent", vec![]);
     }
 
+    #[rstest]
+    fn test_request_options_are_merged_into_request() {
+        let request = InnerRequestBuilder::new(WpAuthentication::None).get(
+            Url::parse("https://example.com/wp-json/wp/v2/posts?page=2")
+                .unwrap()
+                .into(),
+        );
+        let idempotency_key = WpUuid::new();
+        let request_options = WpRequestOptions {
+            extra_headers: Some(
+                WpNetworkHeaderMap::from_map(
+                    [
+                        ("Accept".to_string(), "text/html".to_string()),
+                        ("Prefer".to_string(), "return=minimal".to_string()),
+                    ]
+                    .into(),
+                )
+                .unwrap()
+                .into(),
+            ),
+            extra_query_pairs: [
+                ("_locale".to_string(), "user".to_string()),
+                ("_envelope".to_string(), "1".to_string()),
+            ]
+            .into(),
+            idempotency_key: Some(idempotency_key.clone().into()),
+            timeout_ms: Some(5000),
+        };
+        let request = request.with_request_options(&request_options);
+        assert_eq!(
+            request.url.0,
+            "https://example.com/wp-json/wp/v2/posts?page=2&_envelope=1&_locale=user"
+        );
+        assert_header_map_values(&request.header_map, "Accept", vec!["text/html"]);
+        assert_header_map_values(&request.header_map, "Prefer", vec!["return=minimal"]);
+        assert_header_map_values(
+            &request.header_map,
+            IDEMPOTENCY_KEY_HEADER_KEY,
+            vec![idempotency_key.uuid_string().as_str()],
+        );
+        assert_eq!(request.timeout_ms(), Some(5000));
+    }
+
+    #[rstest]
+    fn test_default_request_options_dont_change_request() {
+        let request = InnerRequestBuilder::new(WpAuthentication::None).get(
+            Url::parse("https://example.com/wp-json/wp/v2/posts")
+                .unwrap()
+                .into(),
+        );
+        let request = request.with_request_options(&WpRequestOptions::default());
+        assert_eq!(request.url.0, "https://example.com/wp-json/wp/v2/posts");
+        assert_header_map_values(&request.header_map, "Accept", vec![CONTENT_TYPE_JSON]);
+        assert_eq!(request.timeout_ms(), None);
+    }
+
     fn assert_header_map_values(header_map: &WpNetworkHeaderMap, key: &str, values: Vec<&str>) {
         assert_eq!(
             header_map
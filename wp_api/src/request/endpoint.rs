@@ -3,11 +3,32 @@ use url::Url;
 use crate::SparseField;
 
 pub(crate) mod application_passwords_endpoint;
+pub(crate) mod block_patterns_endpoint;
+pub(crate) mod block_renderer_endpoint;
+pub(crate) mod block_types_endpoint;
+pub(crate) mod font_collections_endpoint;
+pub(crate) mod font_faces_endpoint;
+pub(crate) mod font_families_endpoint;
+pub(crate) mod global_styles_endpoint;
+pub(crate) mod menu_items_endpoint;
+pub(crate) mod menu_locations_endpoint;
+pub(crate) mod menus_endpoint;
+pub(crate) mod navigation_endpoint;
+pub(crate) mod oembed_endpoint;
 pub(crate) mod plugins_endpoint;
 pub(crate) mod post_types_endpoint;
 pub(crate) mod posts_endpoint;
+pub(crate) mod search_endpoint;
+pub(crate) mod sidebars_endpoint;
 pub(crate) mod site_settings_endpoint;
+pub(crate) mod statuses_endpoint;
+pub(crate) mod template_parts_endpoint;
+pub(crate) mod templates_endpoint;
+pub(crate) mod themes_endpoint;
 pub(crate) mod users_endpoint;
+pub(crate) mod widget_types_endpoint;
+pub(crate) mod widgets_endpoint;
+pub(crate) mod wp_blocks_endpoint;
 pub(crate) mod wp_site_health_tests_endpoint;
 
 const WP_JSON_PATH_SEGMENTS: [&str; 1] = ["wp-json"];
@@ -155,6 +176,7 @@ trait DerivedRequest {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 enum Namespace {
+    OEmbedV1,
     WpSiteHealthV1,
     WpV2,
 }
@@ -162,6 +184,7 @@ enum Namespace {
 impl Namespace {
     fn as_str(&self) -> &str {
         match self {
+            Self::OEmbedV1 => "/oembed/1.0",
             Self::WpSiteHealthV1 => "/wp-site-health/v1",
             Self::WpV2 => "/wp/v2",
         }
@@ -268,6 +291,10 @@ mod tests {
         validate_endpoint(Namespace::WpSiteHealthV1, endpoint_url, path);
     }
 
+    pub fn validate_oembed_endpoint(endpoint_url: ApiEndpointUrl, path: &str) {
+        validate_endpoint(Namespace::OEmbedV1, endpoint_url, path);
+    }
+
     fn validate_endpoint(namespace: Namespace, endpoint_url: ApiEndpointUrl, path: &str) {
         assert_eq!(
             endpoint_url.as_str(),
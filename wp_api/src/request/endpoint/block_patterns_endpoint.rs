@@ -0,0 +1,43 @@
+use super::{DerivedRequest, Namespace};
+use crate::block_patterns::{BlockPattern, BlockPatternCategory};
+use wp_derive_request_builder::WpDerivedRequest;
+
+#[derive(WpDerivedRequest)]
+enum BlockPatternsRequest {
+    #[get(url = "/block-patterns/patterns", output = Vec<BlockPattern>)]
+    ListPatterns,
+    #[get(url = "/block-patterns/categories", output = Vec<BlockPatternCategory>)]
+    ListCategories,
+}
+
+impl DerivedRequest for BlockPatternsRequest {
+    fn namespace() -> Namespace {
+        Namespace::WpV2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::endpoint::{
+        tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+        ApiBaseUrl,
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    fn list_patterns(endpoint: BlockPatternsRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.list_patterns(), "/block-patterns/patterns");
+    }
+
+    #[rstest]
+    fn list_categories(endpoint: BlockPatternsRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.list_categories(), "/block-patterns/categories");
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> BlockPatternsRequestEndpoint {
+        BlockPatternsRequestEndpoint::new(fixture_api_base_url)
+    }
+}
@@ -0,0 +1,40 @@
+use super::{DerivedRequest, Namespace};
+use crate::block_types::{BlockRendererParams, BlockRendererResponse, BlockTypeName};
+use wp_derive_request_builder::WpDerivedRequest;
+
+#[derive(WpDerivedRequest)]
+enum BlockRendererRequest {
+    // `POST` is used instead of `GET`, so the attributes can be sent as JSON
+    #[post(url = "/block-renderer/<block_type_name>", params = &BlockRendererParams, output = BlockRendererResponse)]
+    Render,
+}
+
+impl DerivedRequest for BlockRendererRequest {
+    fn namespace() -> Namespace {
+        Namespace::WpV2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::endpoint::{
+        tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+        ApiBaseUrl,
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    fn render(endpoint: BlockRendererRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.render(&BlockTypeName::from("core/archives")),
+            "/block-renderer/core/archives",
+        );
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> BlockRendererRequestEndpoint {
+        BlockRendererRequestEndpoint::new(fixture_api_base_url)
+    }
+}
@@ -0,0 +1,95 @@
+use super::{DerivedRequest, Namespace};
+use crate::block_types::{
+    BlockTypeListParams, BlockTypeName, SparseBlockTypeFieldWithEditContext,
+    SparseBlockTypeFieldWithEmbedContext, SparseBlockTypeFieldWithViewContext,
+};
+use crate::SparseField;
+use wp_derive_request_builder::WpDerivedRequest;
+
+#[derive(WpDerivedRequest)]
+enum BlockTypesRequest {
+    #[contextual_get(url = "/block-types", params = &BlockTypeListParams, output = Vec<crate::block_types::SparseBlockType>, filter_by = crate::block_types::SparseBlockTypeField)]
+    List,
+    #[contextual_get(url = "/block-types/<block_type_name>", output = crate::block_types::SparseBlockType, filter_by = crate::block_types::SparseBlockTypeField)]
+    Retrieve,
+}
+
+impl DerivedRequest for BlockTypesRequest {
+    fn namespace() -> Namespace {
+        Namespace::WpV2
+    }
+}
+
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseBlockTypeFieldWithEditContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseBlockTypeFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseBlockTypeFieldWithViewContext
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generate,
+        request::endpoint::{
+            tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+            ApiBaseUrl,
+        },
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    #[case(BlockTypeListParams::default(), "/block-types?context=edit")]
+    #[case(generate!(BlockTypeListParams, (namespace, Some("core".to_string()))), "/block-types?context=edit&namespace=core")]
+    fn list_block_types(
+        endpoint: BlockTypesRequestEndpoint,
+        #[case] params: BlockTypeListParams,
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(endpoint.list_with_edit_context(&params), expected_path);
+    }
+
+    #[rstest]
+    fn filter_list_block_types(endpoint: BlockTypesRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.filter_list_with_view_context(
+                &BlockTypeListParams::default(),
+                &[
+                    SparseBlockTypeFieldWithViewContext::Name,
+                    SparseBlockTypeFieldWithViewContext::Attributes,
+                ],
+            ),
+            "/block-types?context=view&_fields=name%2Cattributes",
+        );
+    }
+
+    #[rstest]
+    fn retrieve_block_type(endpoint: BlockTypesRequestEndpoint) {
+        let name = BlockTypeName::from("core/paragraph");
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_edit_context(&name),
+            "/block-types/core/paragraph?context=edit",
+        );
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_embed_context(&name),
+            "/block-types/core/paragraph?context=embed",
+        );
+        validate_wp_v2_endpoint(
+            endpoint.filter_retrieve_with_view_context(
+                &name,
+                &[SparseBlockTypeFieldWithViewContext::Supports],
+            ),
+            "/block-types/core/paragraph?context=view&_fields=supports",
+        );
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> BlockTypesRequestEndpoint {
+        BlockTypesRequestEndpoint::new(fixture_api_base_url)
+    }
+}
@@ -0,0 +1,69 @@
+use super::{DerivedRequest, Namespace};
+use crate::fonts::{
+    FontCollectionSlug, SparseFontCollectionFieldWithEditContext,
+    SparseFontCollectionFieldWithEmbedContext, SparseFontCollectionFieldWithViewContext,
+};
+use crate::SparseField;
+use wp_derive_request_builder::WpDerivedRequest;
+
+#[derive(WpDerivedRequest)]
+enum FontCollectionsRequest {
+    #[contextual_get(url = "/font-collections", output = Vec<crate::fonts::SparseFontCollection>, filter_by = crate::fonts::SparseFontCollectionField)]
+    List,
+    #[contextual_get(url = "/font-collections/<font_collection_slug>", output = crate::fonts::SparseFontCollection, filter_by = crate::fonts::SparseFontCollectionField)]
+    Retrieve,
+}
+
+impl DerivedRequest for FontCollectionsRequest {
+    fn namespace() -> Namespace {
+        Namespace::WpV2
+    }
+}
+
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseFontCollectionFieldWithEditContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseFontCollectionFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseFontCollectionFieldWithViewContext
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::endpoint::{
+        tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+        ApiBaseUrl,
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    fn list_font_collections(endpoint: FontCollectionsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.list_with_view_context(),
+            "/font-collections?context=view",
+        );
+    }
+
+    #[rstest]
+    fn retrieve_font_collection(endpoint: FontCollectionsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.filter_retrieve_with_edit_context(
+                &FontCollectionSlug::from("google-fonts"),
+                &[
+                    SparseFontCollectionFieldWithEditContext::Name,
+                    SparseFontCollectionFieldWithEditContext::Categories,
+                ],
+            ),
+            "/font-collections/google-fonts?context=edit&_fields=name%2Ccategories",
+        );
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> FontCollectionsRequestEndpoint {
+        FontCollectionsRequestEndpoint::new(fixture_api_base_url)
+    }
+}
@@ -0,0 +1,142 @@
+use super::{DerivedRequest, Namespace};
+use crate::fonts::{
+    FontFaceCreateParams, FontFaceCreateWithFilesParams, FontFaceDeleteResponse, FontFaceId,
+    FontFaceListParams, FontFaceWithEditContext, FontFamilyId, SparseFontFaceFieldWithEditContext,
+    SparseFontFaceFieldWithEmbedContext, SparseFontFaceFieldWithViewContext,
+};
+use crate::{ForceDeleteParams, SparseField};
+use wp_derive_request_builder::WpDerivedRequest;
+
+#[derive(WpDerivedRequest)]
+enum FontFacesRequest {
+    #[contextual_get(url = "/font-families/<font_family_id>/font-faces", params = &FontFaceListParams, output = Vec<crate::fonts::SparseFontFace>, filter_by = crate::fonts::SparseFontFaceField)]
+    List,
+    #[post(url = "/font-families/<font_family_id>/font-faces", params = &FontFaceCreateParams, output = FontFaceWithEditContext)]
+    Create,
+    // Uploads the font files along with the font face
+    #[post_multipart(url = "/font-families/<font_family_id>/font-faces", params = &FontFaceCreateWithFilesParams, output = FontFaceWithEditContext)]
+    CreateWithFiles,
+    #[contextual_get(url = "/font-families/<font_family_id>/font-faces/<font_face_id>", output = crate::fonts::SparseFontFace, filter_by = crate::fonts::SparseFontFaceField)]
+    Retrieve,
+    // Font faces can't be trashed, so they're always deleted permanently
+    #[delete(url = "/font-families/<font_family_id>/font-faces/<font_face_id>", params = &ForceDeleteParams, output = FontFaceDeleteResponse)]
+    Delete,
+}
+
+impl DerivedRequest for FontFacesRequest {
+    fn namespace() -> Namespace {
+        Namespace::WpV2
+    }
+}
+
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseFontFaceFieldWithEditContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseFontFaceFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseFontFaceFieldWithViewContext
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fonts::FontFaceSettings,
+        generate,
+        request::endpoint::{
+            tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+            ApiBaseUrl,
+        },
+        request::{RequestMethod, WpMultipartFile},
+        WpAuthentication,
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    #[case(
+        FontFaceListParams::default(),
+        "/font-families/1/font-faces?context=edit"
+    )]
+    #[case(generate!(FontFaceListParams, (include, vec![FontFaceId(2), FontFaceId(3)])), "/font-families/1/font-faces?context=edit&include=2%2C3")]
+    fn list_font_faces(
+        endpoint: FontFacesRequestEndpoint,
+        #[case] params: FontFaceListParams,
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(
+            endpoint.list_with_edit_context(&FontFamilyId(1), &params),
+            expected_path,
+        );
+    }
+
+    #[rstest]
+    fn retrieve_font_face(endpoint: FontFacesRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_view_context(&FontFamilyId(1), &FontFaceId(2)),
+            "/font-families/1/font-faces/2?context=view",
+        );
+    }
+
+    #[rstest]
+    fn create_font_face(endpoint: FontFacesRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.create(&FontFamilyId(1)),
+            "/font-families/1/font-faces",
+        );
+    }
+
+    #[rstest]
+    fn delete_font_face(endpoint: FontFacesRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.delete(
+                &FontFamilyId(1),
+                &FontFaceId(2),
+                &ForceDeleteParams::default(),
+            ),
+            "/font-families/1/font-faces/2?force=true",
+        );
+    }
+
+    #[rstest]
+    fn create_font_face_with_files_endpoint(endpoint: FontFacesRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.create_with_files(&FontFamilyId(1)),
+            "/font-families/1/font-faces",
+        );
+    }
+
+    #[rstest]
+    fn create_font_face_with_files(fixture_api_base_url: Arc<ApiBaseUrl>) {
+        let request = FontFacesRequestBuilder::new(fixture_api_base_url, WpAuthentication::None)
+            .create_with_files(
+                &FontFamilyId(1),
+                &FontFaceCreateWithFilesParams::new(
+                    FontFaceSettings::new("Open Sans".to_string(), vec!["file-0".to_string()]),
+                    vec![WpMultipartFile {
+                        field_name: "file-0".to_string(),
+                        file_name: "open-sans.woff2".to_string(),
+                        mime_type: "font/woff2".to_string(),
+                        contents: b"wOF2".to_vec(),
+                    }],
+                ),
+            );
+        assert_eq!(request.method, RequestMethod::POST);
+        assert!(request.url.0.ends_with("/wp/v2/font-families/1/font-faces"));
+        let body = request.body_as_string().unwrap();
+        assert!(body.contains(
+            "name=\"font_face_settings\"\r\n\r\n{\"fontFamily\":\"Open Sans\",\"src\":[\"file-0\"]}\r\n"
+        ));
+        assert!(body.contains(
+            "name=\"file-0\"; filename=\"open-sans.woff2\"\r\nContent-Type: font/woff2\r\n\r\nwOF2\r\n"
+        ));
+        assert!(!body.contains("theme_json_version"));
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> FontFacesRequestEndpoint {
+        FontFacesRequestEndpoint::new(fixture_api_base_url)
+    }
+}
@@ -0,0 +1,98 @@
+use super::{DerivedRequest, Namespace};
+use crate::fonts::{
+    FontFamilyCreateParams, FontFamilyDeleteResponse, FontFamilyId, FontFamilyListParams,
+    FontFamilyUpdateParams, FontFamilyWithEditContext, SparseFontFamilyFieldWithEditContext,
+    SparseFontFamilyFieldWithEmbedContext, SparseFontFamilyFieldWithViewContext,
+};
+use crate::{ForceDeleteParams, SparseField};
+use wp_derive_request_builder::WpDerivedRequest;
+
+#[derive(WpDerivedRequest)]
+enum FontFamiliesRequest {
+    #[contextual_get(url = "/font-families", params = &FontFamilyListParams, output = Vec<crate::fonts::SparseFontFamily>, filter_by = crate::fonts::SparseFontFamilyField)]
+    List,
+    #[post(url = "/font-families", params = &FontFamilyCreateParams, output = FontFamilyWithEditContext)]
+    Create,
+    #[contextual_get(url = "/font-families/<font_family_id>", output = crate::fonts::SparseFontFamily, filter_by = crate::fonts::SparseFontFamilyField)]
+    Retrieve,
+    #[post(url = "/font-families/<font_family_id>", params = &FontFamilyUpdateParams, output = FontFamilyWithEditContext)]
+    Update,
+    // Font families can't be trashed, so they're always deleted permanently
+    #[delete(url = "/font-families/<font_family_id>", params = &ForceDeleteParams, output = FontFamilyDeleteResponse)]
+    Delete,
+}
+
+impl DerivedRequest for FontFamiliesRequest {
+    fn namespace() -> Namespace {
+        Namespace::WpV2
+    }
+}
+
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseFontFamilyFieldWithEditContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseFontFamilyFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseFontFamilyFieldWithViewContext
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generate,
+        request::endpoint::{
+            tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+            ApiBaseUrl,
+        },
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    #[case(FontFamilyListParams::default(), "/font-families?context=edit")]
+    #[case(generate!(FontFamilyListParams, (slug, vec!["open-sans".to_string()]), (per_page, Some(100))), "/font-families?context=edit&per_page=100&slug=open-sans")]
+    fn list_font_families(
+        endpoint: FontFamiliesRequestEndpoint,
+        #[case] params: FontFamilyListParams,
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(endpoint.list_with_edit_context(&params), expected_path);
+    }
+
+    #[rstest]
+    fn retrieve_font_family(endpoint: FontFamiliesRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.filter_retrieve_with_edit_context(
+                &FontFamilyId(1),
+                &[SparseFontFamilyFieldWithEditContext::FontFaces],
+            ),
+            "/font-families/1?context=edit&_fields=font_faces",
+        );
+    }
+
+    #[rstest]
+    fn create_font_family(endpoint: FontFamiliesRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.create(), "/font-families");
+    }
+
+    #[rstest]
+    fn update_font_family(endpoint: FontFamiliesRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.update(&FontFamilyId(1)), "/font-families/1");
+    }
+
+    #[rstest]
+    fn delete_font_family(endpoint: FontFamiliesRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.delete(&FontFamilyId(1), &ForceDeleteParams::default()),
+            "/font-families/1?force=true",
+        );
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> FontFamiliesRequestEndpoint {
+        FontFamiliesRequestEndpoint::new(fixture_api_base_url)
+    }
+}
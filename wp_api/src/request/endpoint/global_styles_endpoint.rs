@@ -0,0 +1,92 @@
+use super::{DerivedRequest, Namespace};
+use crate::global_styles::{
+    GlobalStylesId, GlobalStylesUpdateParams, GlobalStylesWithEditContext,
+    SparseGlobalStylesFieldWithEditContext, SparseGlobalStylesFieldWithEmbedContext,
+    SparseGlobalStylesFieldWithViewContext, ThemeGlobalStyles, ThemeGlobalStylesVariation,
+};
+use crate::themes::ThemeStylesheet;
+use crate::SparseField;
+use wp_derive_request_builder::WpDerivedRequest;
+
+#[derive(WpDerivedRequest)]
+enum GlobalStylesRequest {
+    #[contextual_get(url = "/global-styles/<global_styles_id>", output = crate::global_styles::SparseGlobalStyles, filter_by = crate::global_styles::SparseGlobalStylesField)]
+    Retrieve,
+    #[post(url = "/global-styles/<global_styles_id>", params = &GlobalStylesUpdateParams, output = GlobalStylesWithEditContext)]
+    Update,
+    #[get(url = "/global-styles/themes/<theme_stylesheet>", output = ThemeGlobalStyles)]
+    RetrieveTheme,
+    #[get(url = "/global-styles/themes/<theme_stylesheet>/variations", output = Vec<ThemeGlobalStylesVariation>)]
+    ListThemeVariations,
+}
+
+impl DerivedRequest for GlobalStylesRequest {
+    fn namespace() -> Namespace {
+        Namespace::WpV2
+    }
+}
+
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseGlobalStylesFieldWithEditContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseGlobalStylesFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseGlobalStylesFieldWithViewContext
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::endpoint::{
+        tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+        ApiBaseUrl,
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    fn retrieve_global_styles(endpoint: GlobalStylesRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_edit_context(&GlobalStylesId(7)),
+            "/global-styles/7?context=edit",
+        );
+        validate_wp_v2_endpoint(
+            endpoint.filter_retrieve_with_view_context(
+                &GlobalStylesId(7),
+                &[
+                    SparseGlobalStylesFieldWithViewContext::Settings,
+                    SparseGlobalStylesFieldWithViewContext::Styles,
+                ],
+            ),
+            "/global-styles/7?context=view&_fields=settings%2Cstyles",
+        );
+    }
+
+    #[rstest]
+    fn update_global_styles(endpoint: GlobalStylesRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.update(&GlobalStylesId(7)), "/global-styles/7");
+    }
+
+    #[rstest]
+    fn retrieve_theme_global_styles(endpoint: GlobalStylesRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_theme(&ThemeStylesheet::from("twentytwentyfour")),
+            "/global-styles/themes/twentytwentyfour",
+        );
+    }
+
+    #[rstest]
+    fn list_theme_global_styles_variations(endpoint: GlobalStylesRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.list_theme_variations(&ThemeStylesheet::from("twentytwentyfour")),
+            "/global-styles/themes/twentytwentyfour/variations",
+        );
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> GlobalStylesRequestEndpoint {
+        GlobalStylesRequestEndpoint::new(fixture_api_base_url)
+    }
+}
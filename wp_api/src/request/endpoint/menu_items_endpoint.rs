@@ -0,0 +1,130 @@
+use super::{DerivedRequest, Namespace};
+use crate::menus::{
+    menu_item_reorder_updates, MenuItemCreateParams, MenuItemDeleteResponse, MenuItemId,
+    MenuItemListParams, MenuItemOrder, MenuItemUpdateParams, MenuItemWithEditContext,
+    SparseMenuItemFieldWithEditContext, SparseMenuItemFieldWithEmbedContext,
+    SparseMenuItemFieldWithViewContext,
+};
+use crate::{ForceDeleteParams, SparseField, WpApiError};
+use wp_derive_request_builder::WpDerivedRequest;
+
+#[derive(WpDerivedRequest)]
+enum MenuItemsRequest {
+    #[contextual_get(url = "/menu-items", params = &MenuItemListParams, output = Vec<crate::menus::SparseMenuItem>, filter_by = crate::menus::SparseMenuItemField)]
+    List,
+    #[post(url = "/menu-items", params = &MenuItemCreateParams, output = MenuItemWithEditContext)]
+    Create,
+    #[contextual_get(url = "/menu-items/<menu_item_id>", output = crate::menus::SparseMenuItem, filter_by = crate::menus::SparseMenuItemField)]
+    Retrieve,
+    #[post(url = "/menu-items/<menu_item_id>", params = &MenuItemUpdateParams, output = MenuItemWithEditContext)]
+    Update,
+    // Menu items can't be trashed, so they're always deleted permanently
+    #[delete(url = "/menu-items/<menu_item_id>", params = &ForceDeleteParams, output = MenuItemDeleteResponse)]
+    Delete,
+}
+
+impl DerivedRequest for MenuItemsRequest {
+    fn namespace() -> Namespace {
+        Namespace::WpV2
+    }
+}
+
+#[uniffi::export]
+impl MenuItemsRequestExecutor {
+    /// Moves the menu items into the desired order, updating only the items whose position
+    /// changed. See `menu_item_reorder_updates`.
+    ///
+    /// The updates are made one by one, so if one of them fails, the ones before it are kept.
+    pub async fn reorder(
+        &self,
+        menu_items: &[MenuItemWithEditContext],
+        desired_order: &[MenuItemOrder],
+    ) -> Result<Vec<MenuItemWithEditContext>, WpApiError> {
+        let mut updated_menu_items = Vec::new();
+        for update in menu_item_reorder_updates(menu_items, desired_order) {
+            let params = MenuItemUpdateParams {
+                parent: Some(update.parent),
+                menu_order: Some(update.menu_order),
+                ..Default::default()
+            };
+            updated_menu_items.push(self.update(&update.id, &params).await?);
+        }
+        Ok(updated_menu_items)
+    }
+}
+
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseMenuItemFieldWithEditContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseMenuItemFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseMenuItemFieldWithViewContext
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generate,
+        menus::MenuId,
+        request::endpoint::{
+            tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+            ApiBaseUrl,
+        },
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    #[case(MenuItemListParams::default(), "/menu-items?context=edit")]
+    #[case(generate!(MenuItemListParams, (menus, vec![MenuId(2)]), (per_page, Some(100))), "/menu-items?context=edit&per_page=100&menus=2")]
+    fn list_menu_items(
+        endpoint: MenuItemsRequestEndpoint,
+        #[case] params: MenuItemListParams,
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(endpoint.list_with_edit_context(&params), expected_path);
+    }
+
+    #[rstest]
+    fn filter_list_menu_items(endpoint: MenuItemsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.filter_list_with_embed_context(
+                &MenuItemListParams::default(),
+                &[
+                    SparseMenuItemFieldWithEmbedContext::Parent,
+                    SparseMenuItemFieldWithEmbedContext::MenuOrder,
+                ],
+            ),
+            "/menu-items?context=embed&_fields=parent%2Cmenu_order",
+        );
+    }
+
+    #[rstest]
+    fn retrieve_menu_item(endpoint: MenuItemsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_edit_context(&MenuItemId(7)),
+            "/menu-items/7?context=edit",
+        );
+    }
+
+    #[rstest]
+    fn update_menu_item(endpoint: MenuItemsRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.update(&MenuItemId(7)), "/menu-items/7");
+    }
+
+    #[rstest]
+    fn delete_menu_item(endpoint: MenuItemsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.delete(&MenuItemId(7), &ForceDeleteParams::default()),
+            "/menu-items/7?force=true",
+        );
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> MenuItemsRequestEndpoint {
+        MenuItemsRequestEndpoint::new(fixture_api_base_url)
+    }
+}
@@ -0,0 +1,70 @@
+use super::{DerivedRequest, Namespace};
+use crate::menus::{
+    MenuLocationName, SparseMenuLocationFieldWithEditContext,
+    SparseMenuLocationFieldWithEmbedContext, SparseMenuLocationFieldWithViewContext,
+};
+use crate::SparseField;
+use wp_derive_request_builder::WpDerivedRequest;
+
+#[derive(WpDerivedRequest)]
+enum MenuLocationsRequest {
+    #[contextual_get(url = "/menu-locations", output = crate::menus::SparseMenuLocationsResponse)]
+    List,
+    #[contextual_get(url = "/menu-locations/<menu_location_name>", output = crate::menus::SparseMenuLocation, filter_by = crate::menus::SparseMenuLocationField)]
+    Retrieve,
+}
+
+impl DerivedRequest for MenuLocationsRequest {
+    fn namespace() -> Namespace {
+        Namespace::WpV2
+    }
+}
+
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseMenuLocationFieldWithEditContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseMenuLocationFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseMenuLocationFieldWithViewContext
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::endpoint::{
+        tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+        ApiBaseUrl,
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    fn list_menu_locations(endpoint: MenuLocationsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.list_with_edit_context(),
+            "/menu-locations?context=edit",
+        );
+    }
+
+    #[rstest]
+    fn retrieve_menu_location(endpoint: MenuLocationsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_view_context(&MenuLocationName::from("primary")),
+            "/menu-locations/primary?context=view",
+        );
+        validate_wp_v2_endpoint(
+            endpoint.filter_retrieve_with_edit_context(
+                &MenuLocationName::from("primary"),
+                &[SparseMenuLocationFieldWithEditContext::Menu],
+            ),
+            "/menu-locations/primary?context=edit&_fields=menu",
+        );
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> MenuLocationsRequestEndpoint {
+        MenuLocationsRequestEndpoint::new(fixture_api_base_url)
+    }
+}
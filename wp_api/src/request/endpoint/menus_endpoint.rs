@@ -0,0 +1,98 @@
+use super::{DerivedRequest, Namespace};
+use crate::menus::{
+    MenuCreateParams, MenuDeleteResponse, MenuId, MenuListParams, MenuUpdateParams,
+    MenuWithEditContext, SparseMenuFieldWithEditContext, SparseMenuFieldWithEmbedContext,
+    SparseMenuFieldWithViewContext,
+};
+use crate::{ForceDeleteParams, SparseField};
+use wp_derive_request_builder::WpDerivedRequest;
+
+#[derive(WpDerivedRequest)]
+enum MenusRequest {
+    #[contextual_get(url = "/menus", params = &MenuListParams, output = Vec<crate::menus::SparseMenu>, filter_by = crate::menus::SparseMenuField)]
+    List,
+    #[post(url = "/menus", params = &MenuCreateParams, output = MenuWithEditContext)]
+    Create,
+    #[contextual_get(url = "/menus/<menu_id>", output = crate::menus::SparseMenu, filter_by = crate::menus::SparseMenuField)]
+    Retrieve,
+    #[post(url = "/menus/<menu_id>", params = &MenuUpdateParams, output = MenuWithEditContext)]
+    Update,
+    // Menus can't be trashed, so they're always deleted permanently
+    #[delete(url = "/menus/<menu_id>", params = &ForceDeleteParams, output = MenuDeleteResponse)]
+    Delete,
+}
+
+impl DerivedRequest for MenusRequest {
+    fn namespace() -> Namespace {
+        Namespace::WpV2
+    }
+}
+
+super::macros::default_sparse_field_implementation_from_field_name!(SparseMenuFieldWithEditContext);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseMenuFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(SparseMenuFieldWithViewContext);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generate,
+        request::endpoint::{
+            tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+            ApiBaseUrl,
+        },
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    #[case(MenuListParams::default(), "/menus?context=edit")]
+    #[case(generate!(MenuListParams, (slug, vec!["primary".to_string()])), "/menus?context=edit&slug=primary")]
+    fn list_menus(
+        endpoint: MenusRequestEndpoint,
+        #[case] params: MenuListParams,
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(endpoint.list_with_edit_context(&params), expected_path);
+    }
+
+    #[rstest]
+    fn retrieve_menu(endpoint: MenusRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_view_context(&MenuId(2)),
+            "/menus/2?context=view",
+        );
+        validate_wp_v2_endpoint(
+            endpoint.filter_retrieve_with_edit_context(
+                &MenuId(2),
+                &[SparseMenuFieldWithEditContext::Locations],
+            ),
+            "/menus/2?context=edit&_fields=locations",
+        );
+    }
+
+    #[rstest]
+    fn create_menu(endpoint: MenusRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.create(), "/menus");
+    }
+
+    #[rstest]
+    fn update_menu(endpoint: MenusRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.update(&MenuId(2)), "/menus/2");
+    }
+
+    #[rstest]
+    fn delete_menu(endpoint: MenusRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.delete(&MenuId(2), &ForceDeleteParams::default()),
+            "/menus/2?force=true",
+        );
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> MenusRequestEndpoint {
+        MenusRequestEndpoint::new(fixture_api_base_url)
+    }
+}
@@ -0,0 +1,108 @@
+use super::{DerivedRequest, Namespace};
+use crate::navigation::{
+    NavigationCreateParams, NavigationDeleteResponse, NavigationListParams, NavigationUpdateParams,
+    NavigationWithEditContext, SparseNavigationFieldWithEditContext,
+    SparseNavigationFieldWithEmbedContext, SparseNavigationFieldWithViewContext,
+};
+use crate::posts::PostId;
+use crate::{ForceDeleteParams, SparseField};
+use wp_derive_request_builder::WpDerivedRequest;
+
+#[derive(WpDerivedRequest)]
+enum NavigationRequest {
+    #[contextual_get(url = "/navigation", params = &NavigationListParams, output = Vec<crate::navigation::SparseNavigation>, filter_by = crate::navigation::SparseNavigationField)]
+    List,
+    #[post(url = "/navigation", params = &NavigationCreateParams, output = NavigationWithEditContext)]
+    Create,
+    #[contextual_get(url = "/navigation/<post_id>", output = crate::navigation::SparseNavigation, filter_by = crate::navigation::SparseNavigationField)]
+    Retrieve,
+    #[post(url = "/navigation/<post_id>", params = &NavigationUpdateParams, output = NavigationWithEditContext)]
+    Update,
+    #[delete(url = "/navigation/<post_id>", output = NavigationWithEditContext)]
+    Trash,
+    #[delete(url = "/navigation/<post_id>", params = &ForceDeleteParams, output = NavigationDeleteResponse)]
+    Delete,
+}
+
+impl DerivedRequest for NavigationRequest {
+    fn namespace() -> Namespace {
+        Namespace::WpV2
+    }
+}
+
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseNavigationFieldWithEditContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseNavigationFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseNavigationFieldWithViewContext
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generate,
+        request::endpoint::{
+            tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+            ApiBaseUrl,
+        },
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    #[case(NavigationListParams::default(), "/navigation?context=edit")]
+    #[case(generate!(NavigationListParams, (per_page, Some(5))), "/navigation?context=edit&per_page=5")]
+    fn list_navigation(
+        endpoint: NavigationRequestEndpoint,
+        #[case] params: NavigationListParams,
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(endpoint.list_with_edit_context(&params), expected_path);
+    }
+
+    #[rstest]
+    fn filter_retrieve_navigation(endpoint: NavigationRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.filter_retrieve_with_edit_context(
+                &PostId(4),
+                &[
+                    SparseNavigationFieldWithEditContext::Title,
+                    SparseNavigationFieldWithEditContext::Content,
+                ],
+            ),
+            "/navigation/4?context=edit&_fields=title%2Ccontent",
+        );
+    }
+
+    #[rstest]
+    fn create_navigation(endpoint: NavigationRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.create(), "/navigation");
+    }
+
+    #[rstest]
+    fn update_navigation(endpoint: NavigationRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.update(&PostId(4)), "/navigation/4");
+    }
+
+    #[rstest]
+    fn trash_navigation(endpoint: NavigationRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.trash(&PostId(4)), "/navigation/4");
+    }
+
+    #[rstest]
+    fn delete_navigation(endpoint: NavigationRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.delete(&PostId(4), &ForceDeleteParams::default()),
+            "/navigation/4?force=true",
+        );
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> NavigationRequestEndpoint {
+        NavigationRequestEndpoint::new(fixture_api_base_url)
+    }
+}
@@ -0,0 +1,54 @@
+use super::{DerivedRequest, Namespace};
+use crate::oembed::{OEmbedParams, OEmbedProxyParams, OEmbedResponse};
+use wp_derive_request_builder::WpDerivedRequest;
+
+#[derive(WpDerivedRequest)]
+enum OEmbedRequest {
+    #[get(url = "/embed", params = &OEmbedParams, output = OEmbedResponse)]
+    Embed,
+    #[get(url = "/proxy", params = &OEmbedProxyParams, output = OEmbedResponse)]
+    Proxy,
+}
+
+impl DerivedRequest for OEmbedRequest {
+    fn namespace() -> Namespace {
+        Namespace::OEmbedV1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::endpoint::{
+        tests::{fixture_api_base_url, validate_oembed_endpoint},
+        ApiBaseUrl,
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    fn embed(endpoint: OEmbedRequestEndpoint) {
+        validate_oembed_endpoint(
+            endpoint.embed(&OEmbedParams::new(
+                "https://example.com/hello-world".to_string(),
+            )),
+            "/embed?url=https%3A%2F%2Fexample.com%2Fhello-world",
+        );
+    }
+
+    #[rstest]
+    fn proxy(endpoint: OEmbedRequestEndpoint) {
+        validate_oembed_endpoint(
+            endpoint.proxy(&OEmbedProxyParams {
+                maxwidth: Some(400),
+                ..OEmbedProxyParams::new("https://youtu.be/foo".to_string())
+            }),
+            "/proxy?url=https%3A%2F%2Fyoutu.be%2Ffoo&maxwidth=400",
+        );
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> OEmbedRequestEndpoint {
+        OEmbedRequestEndpoint::new(fixture_api_base_url)
+    }
+}
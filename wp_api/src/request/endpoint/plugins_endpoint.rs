@@ -1,7 +1,11 @@
 use crate::{
-    PluginSlug, SparseField, SparsePluginFieldWithEditContext, SparsePluginFieldWithEmbedContext,
-    SparsePluginFieldWithViewContext,
+    available_plugin_updates, missing_plugin_dependencies, plugin_activation_order,
+    PluginActionError, PluginBulkActionResult, PluginListParams, PluginSlug, PluginStatus,
+    PluginUpdate, PluginUpdateCheckError, PluginUpdateParams, PluginUpdateSource,
+    PluginWithEditContext, SparseField, SparsePluginFieldWithEditContext,
+    SparsePluginFieldWithEmbedContext, SparsePluginFieldWithViewContext, WpApiError, WpErrorCode,
 };
+use std::sync::Arc;
 use wp_derive_request_builder::WpDerivedRequest;
 
 use super::{DerivedRequest, Namespace};
@@ -26,6 +30,151 @@ impl DerivedRequest for PluginsRequest {
     }
 }
 
+#[uniffi::export]
+impl PluginsRequestExecutor {
+    /// Activates the plugin, unless the plugins it requires aren't installed & active. See
+    /// `missing_plugin_dependencies`.
+    pub async fn activate(
+        &self,
+        plugin_slug: &PluginSlug,
+    ) -> Result<PluginWithEditContext, PluginActionError> {
+        let installed_plugins = self
+            .list_with_edit_context(&PluginListParams::default())
+            .await?;
+        self.activate_installed_plugin(plugin_slug, &installed_plugins)
+            .await
+    }
+
+    /// Activates the plugins one by one, after the dependencies that are activated along with
+    /// them. The results are in the order the plugins were activated in.
+    ///
+    /// Only fetching the installed plugins fails the whole action. Each plugin's failure is
+    /// returned in its result instead.
+    pub async fn bulk_activate(
+        &self,
+        plugin_slugs: Vec<PluginSlug>,
+    ) -> Result<Vec<PluginBulkActionResult>, WpApiError> {
+        let mut installed_plugins = self
+            .list_with_edit_context(&PluginListParams::default())
+            .await?;
+        let mut results = Vec::new();
+        for plugin_slug in plugin_activation_order(plugin_slugs, &installed_plugins) {
+            match self
+                .activate_installed_plugin(&plugin_slug, &installed_plugins)
+                .await
+            {
+                Ok(plugin) => {
+                    // So that the plugins that depend on it can be activated next
+                    if let Some(installed) = installed_plugins
+                        .iter_mut()
+                        .find(|p| p.plugin == plugin.plugin)
+                    {
+                        installed.status = plugin.status;
+                    }
+                    results.push(PluginBulkActionResult::Succeeded { plugin });
+                }
+                Err(error) => results.push(PluginBulkActionResult::Failed {
+                    plugin: plugin_slug,
+                    error,
+                }),
+            }
+        }
+        Ok(results)
+    }
+
+    /// Deactivates the plugins one by one, returning a result for each of them.
+    pub async fn bulk_deactivate(
+        &self,
+        plugin_slugs: Vec<PluginSlug>,
+    ) -> Vec<PluginBulkActionResult> {
+        let params = PluginUpdateParams {
+            status: PluginStatus::Inactive,
+        };
+        let mut results = Vec::new();
+        for plugin_slug in plugin_slugs {
+            results.push(match self.update(&plugin_slug, &params).await {
+                Ok(plugin) => PluginBulkActionResult::Succeeded { plugin },
+                Err(error) => PluginBulkActionResult::Failed {
+                    plugin: plugin_slug,
+                    error: error.into(),
+                },
+            });
+        }
+        results
+    }
+
+    /// Deletes the plugins one by one, returning a result for each of them. Active plugins can't
+    /// be deleted, so they fail with `WpErrorCode::CannotDeleteActivePlugin`.
+    pub async fn bulk_delete(&self, plugin_slugs: Vec<PluginSlug>) -> Vec<PluginBulkActionResult> {
+        let mut results = Vec::new();
+        for plugin_slug in plugin_slugs {
+            results.push(match self.delete(&plugin_slug).await {
+                Ok(response) => PluginBulkActionResult::Succeeded {
+                    plugin: response.previous,
+                },
+                Err(error) => PluginBulkActionResult::Failed {
+                    plugin: plugin_slug,
+                    error: error.into(),
+                },
+            });
+        }
+        results
+    }
+
+    /// Returns the installed plugins that have a newer version in the given source.
+    pub async fn available_updates(
+        &self,
+        source: Arc<dyn PluginUpdateSource>,
+    ) -> Result<Vec<PluginUpdate>, PluginUpdateCheckError> {
+        let installed_plugins = self
+            .list_with_edit_context(&PluginListParams::default())
+            .await?;
+        let latest_versions = source
+            .latest_versions(installed_plugins.iter().map(|p| p.plugin.clone()).collect())
+            .await?;
+        Ok(available_plugin_updates(
+            &installed_plugins,
+            &latest_versions,
+        ))
+    }
+}
+
+impl PluginsRequestExecutor {
+    async fn activate_installed_plugin(
+        &self,
+        plugin_slug: &PluginSlug,
+        installed_plugins: &[PluginWithEditContext],
+    ) -> Result<PluginWithEditContext, PluginActionError> {
+        // Unknown plugins are left for the server to reject
+        let missing_dependencies = installed_plugins
+            .iter()
+            .find(|p| p.plugin == *plugin_slug)
+            .map(|plugin| missing_plugin_dependencies(plugin, installed_plugins))
+            .unwrap_or_default();
+        if !missing_dependencies.is_empty() {
+            return Err(PluginActionError::MissingDependencies {
+                plugin: plugin_slug.clone(),
+                missing_dependencies,
+            });
+        }
+        let params = PluginUpdateParams {
+            status: PluginStatus::Active,
+        };
+        self.update(plugin_slug, &params)
+            .await
+            .map_err(|error| match error {
+                WpApiError::WpError {
+                    error_code: WpErrorCode::WpCorePluginMissingDependencies,
+                    ..
+                } => PluginActionError::MissingDependencies {
+                    plugin: plugin_slug.clone(),
+                    missing_dependencies,
+                },
+                error => error.into(),
+            })
+    }
+}
+
 super::macros::default_sparse_field_implementation_from_field_name!(
     SparsePluginFieldWithEditContext
 );
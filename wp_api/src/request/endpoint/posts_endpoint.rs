@@ -1,6 +1,7 @@
 use crate::{
     posts::{
-        PostListParams, SparsePostFieldWithEditContext, SparsePostFieldWithEmbedContext,
+        PostId, PostListParams, PostUpdateError, PostUpdateParams, PostWithEditContext,
+        SparsePostFieldWithEditContext, SparsePostFieldWithEmbedContext,
         SparsePostFieldWithViewContext,
     },
     SparseField,
@@ -13,6 +14,10 @@ use super::{DerivedRequest, Namespace};
 enum PostsRequest {
     #[contextual_get(url = "/posts", params = &PostListParams, output = Vec<crate::posts::SparsePost>, filter_by = crate::posts::SparsePostField)]
     List,
+    #[contextual_get(url = "/posts/<post_id>", output = crate::posts::SparsePost, filter_by = crate::posts::SparsePostField)]
+    Retrieve,
+    #[post(url = "/posts/<post_id>", params = &PostUpdateParams, output = PostWithEditContext)]
+    Update,
 }
 
 impl DerivedRequest for PostsRequest {
@@ -21,8 +26,89 @@ impl DerivedRequest for PostsRequest {
     }
 }
 
+#[uniffi::export]
+impl PostsRequestExecutor {
+    /// Updates the post only if it wasn't modified on the server since `client_modified_gmt`,
+    /// the `modified_gmt` of the version the client last saw. Otherwise, returns
+    /// `PostUpdateError::Conflict` with the current version of the post, which can be reconciled
+    /// with `merge_sparse_posts`.
+    ///
+    /// WordPress doesn't support `If-Unmodified-Since`, so the post is retrieved first, and a
+    /// change that happens between the two requests will still be overwritten.
+    ///
+    /// Conflict detection is only available for posts. Users & site settings don't expose a
+    /// `modified` date to compare against, and pages don't have an endpoint yet, so their updates
+    /// always overwrite the server's version.
+    pub async fn update_if_unmodified(
+        &self,
+        post_id: &PostId,
+        client_modified_gmt: String,
+        params: &PostUpdateParams,
+    ) -> Result<PostWithEditContext, PostUpdateError> {
+        let server_version = self.retrieve_with_edit_context(post_id).await?;
+        if server_version.modified_gmt != client_modified_gmt {
+            return Err(PostUpdateError::Conflict {
+                client_modified_gmt,
+                server_version,
+            });
+        }
+        Ok(self.update(post_id, params).await?)
+    }
+}
+
 super::macros::default_sparse_field_implementation_from_field_name!(SparsePostFieldWithEditContext);
 super::macros::default_sparse_field_implementation_from_field_name!(
     SparsePostFieldWithEmbedContext
 );
 super::macros::default_sparse_field_implementation_from_field_name!(SparsePostFieldWithViewContext);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::endpoint::{
+        tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+        ApiBaseUrl,
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    fn list_posts(endpoint: PostsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.list_with_edit_context(&PostListParams::default()),
+            "/posts?context=edit",
+        );
+    }
+
+    #[rstest]
+    fn retrieve_post(endpoint: PostsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_edit_context(&PostId(4)),
+            "/posts/4?context=edit",
+        );
+    }
+
+    #[rstest]
+    fn filter_retrieve_post(endpoint: PostsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.filter_retrieve_with_edit_context(
+                &PostId(4),
+                &[
+                    SparsePostFieldWithEditContext::Id,
+                    SparsePostFieldWithEditContext::ModifiedGmt,
+                ],
+            ),
+            "/posts/4?context=edit&_fields=id%2Cmodified_gmt",
+        );
+    }
+
+    #[rstest]
+    fn update_post(endpoint: PostsRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.update(&PostId(4)), "/posts/4");
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> PostsRequestEndpoint {
+        PostsRequestEndpoint::new(fixture_api_base_url)
+    }
+}
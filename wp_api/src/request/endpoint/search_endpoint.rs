@@ -0,0 +1,51 @@
+use super::{DerivedRequest, Namespace};
+use crate::search::{SearchListParams, SearchResult};
+use wp_derive_request_builder::WpDerivedRequest;
+
+#[derive(WpDerivedRequest)]
+enum SearchRequest {
+    // Search results are only available in `view` & `embed` contexts, which are the same, so the
+    // request doesn't take a `context`
+    #[get(url = "/search", params = &SearchListParams, output = Vec<SearchResult>)]
+    List,
+}
+
+impl DerivedRequest for SearchRequest {
+    fn namespace() -> Namespace {
+        Namespace::WpV2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generate,
+        request::endpoint::{
+            tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+            ApiBaseUrl,
+        },
+        search::{SearchSubtype, SearchType},
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    #[case(generate!(SearchListParams, (search, Some("foo".to_string()))), "/search?search=foo")]
+    #[case(
+        generate!(SearchListParams, (search, Some("foo".to_string())), (search_type, Some(SearchType::Term)), (subtype, vec![SearchSubtype::Category, SearchSubtype::PostTag])),
+        "/search?search=foo&type=term&subtype=category%2Cpost_tag"
+    )]
+    fn list_search_results(
+        endpoint: SearchRequestEndpoint,
+        #[case] params: SearchListParams,
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(endpoint.list(&params), expected_path);
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> SearchRequestEndpoint {
+        SearchRequestEndpoint::new(fixture_api_base_url)
+    }
+}
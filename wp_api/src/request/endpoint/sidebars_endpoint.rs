@@ -0,0 +1,77 @@
+use super::{DerivedRequest, Namespace};
+use crate::widgets::{
+    SidebarId, SidebarUpdateParams, SidebarWithEditContext, SparseSidebarFieldWithEditContext,
+    SparseSidebarFieldWithEmbedContext, SparseSidebarFieldWithViewContext,
+};
+use crate::SparseField;
+use wp_derive_request_builder::WpDerivedRequest;
+
+#[derive(WpDerivedRequest)]
+enum SidebarsRequest {
+    #[contextual_get(url = "/sidebars", output = Vec<crate::widgets::SparseSidebar>, filter_by = crate::widgets::SparseSidebarField)]
+    List,
+    #[contextual_get(url = "/sidebars/<sidebar_id>", output = crate::widgets::SparseSidebar, filter_by = crate::widgets::SparseSidebarField)]
+    Retrieve,
+    #[post(url = "/sidebars/<sidebar_id>", params = &SidebarUpdateParams, output = SidebarWithEditContext)]
+    Update,
+}
+
+impl DerivedRequest for SidebarsRequest {
+    fn namespace() -> Namespace {
+        Namespace::WpV2
+    }
+}
+
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseSidebarFieldWithEditContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseSidebarFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseSidebarFieldWithViewContext
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::endpoint::{
+        tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+        ApiBaseUrl,
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    fn list_sidebars(endpoint: SidebarsRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.list_with_edit_context(), "/sidebars?context=edit");
+        validate_wp_v2_endpoint(
+            endpoint.filter_list_with_view_context(&[
+                SparseSidebarFieldWithViewContext::Id,
+                SparseSidebarFieldWithViewContext::Widgets,
+            ]),
+            "/sidebars?context=view&_fields=id%2Cwidgets",
+        );
+    }
+
+    #[rstest]
+    fn retrieve_sidebar(endpoint: SidebarsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_embed_context(&SidebarId::from("sidebar-1")),
+            "/sidebars/sidebar-1?context=embed",
+        );
+    }
+
+    #[rstest]
+    fn update_sidebar(endpoint: SidebarsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.update(&SidebarId::from("sidebar-1")),
+            "/sidebars/sidebar-1",
+        );
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> SidebarsRequestEndpoint {
+        SidebarsRequestEndpoint::new(fixture_api_base_url)
+    }
+}
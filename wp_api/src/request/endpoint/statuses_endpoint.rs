@@ -0,0 +1,81 @@
+use super::{DerivedRequest, Namespace};
+use crate::posts::PostStatus;
+use crate::statuses::{
+    SparseStatusFieldWithEditContext, SparseStatusFieldWithEmbedContext,
+    SparseStatusFieldWithViewContext,
+};
+use crate::SparseField;
+use wp_derive_request_builder::WpDerivedRequest;
+
+#[derive(WpDerivedRequest)]
+enum StatusesRequest {
+    #[contextual_get(url = "/statuses", output = crate::statuses::SparseStatusesResponse)]
+    List,
+    #[contextual_get(url = "/statuses/<post_status>", output = crate::statuses::SparseStatus, filter_by = crate::statuses::SparseStatusField)]
+    Retrieve,
+}
+
+impl DerivedRequest for StatusesRequest {
+    fn namespace() -> Namespace {
+        Namespace::WpV2
+    }
+}
+
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseStatusFieldWithEditContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseStatusFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseStatusFieldWithViewContext
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::endpoint::{
+        tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+        ApiBaseUrl,
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    fn list_statuses(endpoint: StatusesRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.list_with_edit_context(), "/statuses?context=edit");
+        validate_wp_v2_endpoint(
+            endpoint.list_with_embed_context(),
+            "/statuses?context=embed",
+        );
+        validate_wp_v2_endpoint(endpoint.list_with_view_context(), "/statuses?context=view");
+    }
+
+    #[rstest]
+    #[case(PostStatus::Publish, "/statuses/publish")]
+    #[case(PostStatus::Trash, "/statuses/trash")]
+    #[case(PostStatus::AutoDraft, "/statuses/auto-draft")]
+    #[case(PostStatus::Custom("wc-pending".to_string()), "/statuses/wc-pending")]
+    fn retrieve_status(
+        endpoint: StatusesRequestEndpoint,
+        #[case] post_status: PostStatus,
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_edit_context(&post_status),
+            format!("{}?context=edit", expected_path).as_str(),
+        );
+        validate_wp_v2_endpoint(
+            endpoint.filter_retrieve_with_view_context(
+                &post_status,
+                &[SparseStatusFieldWithViewContext::Queryable],
+            ),
+            format!("{}?context=view&_fields=queryable", expected_path).as_str(),
+        );
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> StatusesRequestEndpoint {
+        StatusesRequestEndpoint::new(fixture_api_base_url)
+    }
+}
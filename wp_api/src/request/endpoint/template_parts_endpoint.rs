@@ -0,0 +1,92 @@
+use super::{DerivedRequest, Namespace};
+use crate::templates::{
+    TemplateCreateParams, TemplateDeleteResponse, TemplateId, TemplatePartListParams,
+    TemplateUpdateParams, TemplateWithEditContext,
+};
+use crate::ForceDeleteParams;
+use wp_derive_request_builder::WpDerivedRequest;
+
+// The sparse fields are shared with `TemplatesRequest`, which implements `SparseField` for them
+#[derive(WpDerivedRequest)]
+enum TemplatePartsRequest {
+    #[contextual_get(url = "/template-parts", params = &TemplatePartListParams, output = Vec<crate::templates::SparseTemplate>, filter_by = crate::templates::SparseTemplateField)]
+    List,
+    #[post(url = "/template-parts", params = &TemplateCreateParams, output = TemplateWithEditContext)]
+    Create,
+    #[contextual_get(url = "/template-parts/<template_id>", output = crate::templates::SparseTemplate, filter_by = crate::templates::SparseTemplateField)]
+    Retrieve,
+    #[post(url = "/template-parts/<template_id>", params = &TemplateUpdateParams, output = TemplateWithEditContext)]
+    Update,
+    #[delete(url = "/template-parts/<template_id>", output = TemplateWithEditContext)]
+    Trash,
+    // Deleting a template part that customizes a theme's file reverts it to the file
+    #[delete(url = "/template-parts/<template_id>", params = &ForceDeleteParams, output = TemplateDeleteResponse)]
+    Delete,
+}
+
+impl DerivedRequest for TemplatePartsRequest {
+    fn namespace() -> Namespace {
+        Namespace::WpV2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generate,
+        request::endpoint::{
+            tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+            ApiBaseUrl,
+        },
+        templates::{SparseTemplateFieldWithEmbedContext, TemplatePartArea},
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    #[case(TemplatePartListParams::default(), "/template-parts?context=edit")]
+    #[case(generate!(TemplatePartListParams, (area, Some(TemplatePartArea::Footer))), "/template-parts?context=edit&area=footer")]
+    fn list_template_parts(
+        endpoint: TemplatePartsRequestEndpoint,
+        #[case] params: TemplatePartListParams,
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(endpoint.list_with_edit_context(&params), expected_path);
+    }
+
+    #[rstest]
+    fn filter_list_template_parts(endpoint: TemplatePartsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.filter_list_with_embed_context(
+                &TemplatePartListParams::default(),
+                &[SparseTemplateFieldWithEmbedContext::Area],
+            ),
+            "/template-parts?context=embed&_fields=area",
+        );
+    }
+
+    #[rstest]
+    fn retrieve_template_part(endpoint: TemplatePartsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_view_context(&TemplateId::from("twentytwentyfour//header")),
+            "/template-parts/twentytwentyfour/header?context=view",
+        );
+    }
+
+    #[rstest]
+    fn delete_template_part(endpoint: TemplatePartsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.delete(
+                &TemplateId::from("twentytwentyfour//header"),
+                &ForceDeleteParams::default(),
+            ),
+            "/template-parts/twentytwentyfour/header?force=true",
+        );
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> TemplatePartsRequestEndpoint {
+        TemplatePartsRequestEndpoint::new(fixture_api_base_url)
+    }
+}
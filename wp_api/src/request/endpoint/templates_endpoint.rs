@@ -0,0 +1,136 @@
+use super::{DerivedRequest, Namespace};
+use crate::templates::{
+    SparseTemplateFieldWithEditContext, SparseTemplateFieldWithEmbedContext,
+    SparseTemplateFieldWithViewContext, TemplateCreateParams, TemplateDeleteResponse, TemplateId,
+    TemplateListParams, TemplateLookupParams, TemplateUpdateParams, TemplateWithEditContext,
+    TemplateWithViewContext,
+};
+use crate::{ForceDeleteParams, SparseField};
+use wp_derive_request_builder::WpDerivedRequest;
+
+#[derive(WpDerivedRequest)]
+enum TemplatesRequest {
+    #[contextual_get(url = "/templates", params = &TemplateListParams, output = Vec<crate::templates::SparseTemplate>, filter_by = crate::templates::SparseTemplateField)]
+    List,
+    #[post(url = "/templates", params = &TemplateCreateParams, output = TemplateWithEditContext)]
+    Create,
+    // WordPress doesn't take a `context` for the fallback template, so it's always in `view` context
+    #[get(url = "/templates/lookup", params = &TemplateLookupParams, output = TemplateWithViewContext)]
+    Lookup,
+    #[contextual_get(url = "/templates/<template_id>", output = crate::templates::SparseTemplate, filter_by = crate::templates::SparseTemplateField)]
+    Retrieve,
+    #[post(url = "/templates/<template_id>", params = &TemplateUpdateParams, output = TemplateWithEditContext)]
+    Update,
+    #[delete(url = "/templates/<template_id>", output = TemplateWithEditContext)]
+    Trash,
+    // Deleting a template that customizes a theme's file reverts it to the file
+    #[delete(url = "/templates/<template_id>", params = &ForceDeleteParams, output = TemplateDeleteResponse)]
+    Delete,
+}
+
+impl DerivedRequest for TemplatesRequest {
+    fn namespace() -> Namespace {
+        Namespace::WpV2
+    }
+}
+
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseTemplateFieldWithEditContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseTemplateFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseTemplateFieldWithViewContext
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generate,
+        post_types::PostType,
+        request::endpoint::{
+            tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+            ApiBaseUrl,
+        },
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    #[case(TemplateListParams::default(), "/templates?context=edit")]
+    #[case(generate!(TemplateListParams, (post_type, Some(PostType::Page))), "/templates?context=edit&post_type=page")]
+    fn list_templates(
+        endpoint: TemplatesRequestEndpoint,
+        #[case] params: TemplateListParams,
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(endpoint.list_with_edit_context(&params), expected_path);
+    }
+
+    #[rstest]
+    fn create_template(endpoint: TemplatesRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.create(), "/templates");
+    }
+
+    #[rstest]
+    fn lookup_template(endpoint: TemplatesRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.lookup(&TemplateLookupParams::new("single-post".to_string())),
+            "/templates/lookup?slug=single-post",
+        );
+    }
+
+    // The `//` of the template id becomes a single `/`, which WordPress converts back
+    #[rstest]
+    fn retrieve_template(endpoint: TemplatesRequestEndpoint) {
+        let template_id = TemplateId::from("twentytwentyfour//home");
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_edit_context(&template_id),
+            "/templates/twentytwentyfour/home?context=edit",
+        );
+        validate_wp_v2_endpoint(
+            endpoint.filter_retrieve_with_view_context(
+                &template_id,
+                &[
+                    SparseTemplateFieldWithViewContext::Id,
+                    SparseTemplateFieldWithViewContext::Source,
+                ],
+            ),
+            "/templates/twentytwentyfour/home?context=view&_fields=id%2Csource",
+        );
+    }
+
+    #[rstest]
+    fn update_template(endpoint: TemplatesRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.update(&TemplateId::from("twentytwentyfour//home")),
+            "/templates/twentytwentyfour/home",
+        );
+    }
+
+    #[rstest]
+    fn trash_template(endpoint: TemplatesRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.trash(&TemplateId::from("twentytwentyfour//home")),
+            "/templates/twentytwentyfour/home",
+        );
+    }
+
+    #[rstest]
+    fn delete_template(endpoint: TemplatesRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.delete(
+                &TemplateId::from("twentytwentyfour//home"),
+                &ForceDeleteParams::default(),
+            ),
+            "/templates/twentytwentyfour/home?force=true",
+        );
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> TemplatesRequestEndpoint {
+        TemplatesRequestEndpoint::new(fixture_api_base_url)
+    }
+}
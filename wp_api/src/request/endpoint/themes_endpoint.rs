@@ -0,0 +1,97 @@
+use super::{DerivedRequest, Namespace};
+use crate::themes::{
+    SparseThemeFieldWithEditContext, SparseThemeFieldWithEmbedContext,
+    SparseThemeFieldWithViewContext, ThemeListParams, ThemeStylesheet,
+};
+use crate::SparseField;
+use wp_derive_request_builder::WpDerivedRequest;
+
+#[derive(WpDerivedRequest)]
+enum ThemesRequest {
+    #[contextual_get(url = "/themes", params = &ThemeListParams, output = Vec<crate::themes::SparseTheme>, filter_by = crate::themes::SparseThemeField)]
+    List,
+    #[contextual_get(url = "/themes/<theme_stylesheet>", output = crate::themes::SparseTheme, filter_by = crate::themes::SparseThemeField)]
+    Retrieve,
+}
+
+impl DerivedRequest for ThemesRequest {
+    fn namespace() -> Namespace {
+        Namespace::WpV2
+    }
+}
+
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseThemeFieldWithEditContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseThemeFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseThemeFieldWithViewContext
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generate,
+        request::endpoint::{
+            tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+            ApiBaseUrl,
+        },
+        themes::ThemeStatus,
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    #[case(ThemeListParams::default(), "/themes?context=edit")]
+    #[case(generate!(ThemeListParams, (status, vec![ThemeStatus::Active])), "/themes?context=edit&status=active")]
+    #[case(generate!(ThemeListParams, (status, vec![ThemeStatus::Active, ThemeStatus::Inactive])), "/themes?context=edit&status=active%2Cinactive")]
+    fn list_themes(
+        endpoint: ThemesRequestEndpoint,
+        #[case] params: ThemeListParams,
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(endpoint.list_with_edit_context(&params), expected_path);
+    }
+
+    #[rstest]
+    fn filter_list_themes(endpoint: ThemesRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.filter_list_with_view_context(
+                &ThemeListParams::default(),
+                &[
+                    SparseThemeFieldWithViewContext::Stylesheet,
+                    SparseThemeFieldWithViewContext::ThemeSupports,
+                ],
+            ),
+            "/themes?context=view&_fields=stylesheet%2Ctheme_supports",
+        );
+    }
+
+    #[rstest]
+    fn retrieve_theme(endpoint: ThemesRequestEndpoint) {
+        let stylesheet = ThemeStylesheet::from("twentytwentyfour");
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_edit_context(&stylesheet),
+            "/themes/twentytwentyfour?context=edit",
+        );
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_embed_context(&stylesheet),
+            "/themes/twentytwentyfour?context=embed",
+        );
+        validate_wp_v2_endpoint(
+            endpoint.filter_retrieve_with_view_context(
+                &stylesheet,
+                &[SparseThemeFieldWithViewContext::ThemeSupports],
+            ),
+            "/themes/twentytwentyfour?context=view&_fields=theme_supports",
+        );
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> ThemesRequestEndpoint {
+        ThemesRequestEndpoint::new(fixture_api_base_url)
+    }
+}
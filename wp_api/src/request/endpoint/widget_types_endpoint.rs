@@ -0,0 +1,91 @@
+use super::{DerivedRequest, Namespace};
+use crate::widgets::{
+    SparseWidgetTypeFieldWithEditContext, SparseWidgetTypeFieldWithEmbedContext,
+    SparseWidgetTypeFieldWithViewContext, WidgetTypeEncodeParams, WidgetTypeEncodeResponse,
+    WidgetTypeId, WidgetTypeRenderParams, WidgetTypeRenderResponse,
+};
+use crate::SparseField;
+use wp_derive_request_builder::WpDerivedRequest;
+
+#[derive(WpDerivedRequest)]
+enum WidgetTypesRequest {
+    #[contextual_get(url = "/widget-types", output = Vec<crate::widgets::SparseWidgetType>, filter_by = crate::widgets::SparseWidgetTypeField)]
+    List,
+    #[contextual_get(url = "/widget-types/<widget_type_id>", output = crate::widgets::SparseWidgetType, filter_by = crate::widgets::SparseWidgetTypeField)]
+    Retrieve,
+    #[post(url = "/widget-types/<widget_type_id>/encode", params = &WidgetTypeEncodeParams, output = WidgetTypeEncodeResponse)]
+    Encode,
+    #[post(url = "/widget-types/<widget_type_id>/render", params = &WidgetTypeRenderParams, output = WidgetTypeRenderResponse)]
+    Render,
+}
+
+impl DerivedRequest for WidgetTypesRequest {
+    fn namespace() -> Namespace {
+        Namespace::WpV2
+    }
+}
+
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseWidgetTypeFieldWithEditContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseWidgetTypeFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseWidgetTypeFieldWithViewContext
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::endpoint::{
+        tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+        ApiBaseUrl,
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    fn list_widget_types(endpoint: WidgetTypesRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.list_with_edit_context(),
+            "/widget-types?context=edit",
+        );
+    }
+
+    #[rstest]
+    fn retrieve_widget_type(endpoint: WidgetTypesRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_view_context(&WidgetTypeId::from("search")),
+            "/widget-types/search?context=view",
+        );
+        validate_wp_v2_endpoint(
+            endpoint.filter_retrieve_with_view_context(
+                &WidgetTypeId::from("search"),
+                &[SparseWidgetTypeFieldWithViewContext::IsMulti],
+            ),
+            "/widget-types/search?context=view&_fields=is_multi",
+        );
+    }
+
+    #[rstest]
+    fn encode_widget_type(endpoint: WidgetTypesRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.encode(&WidgetTypeId::from("search")),
+            "/widget-types/search/encode",
+        );
+    }
+
+    #[rstest]
+    fn render_widget_type(endpoint: WidgetTypesRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.render(&WidgetTypeId::from("search")),
+            "/widget-types/search/render",
+        );
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> WidgetTypesRequestEndpoint {
+        WidgetTypesRequestEndpoint::new(fixture_api_base_url)
+    }
+}
@@ -0,0 +1,112 @@
+use super::{DerivedRequest, Namespace};
+use crate::widgets::{
+    SparseWidgetFieldWithEditContext, SparseWidgetFieldWithEmbedContext,
+    SparseWidgetFieldWithViewContext, WidgetCreateParams, WidgetDeleteResponse, WidgetId,
+    WidgetListParams, WidgetUpdateParams, WidgetWithEditContext,
+};
+use crate::{ForceDeleteParams, SparseField};
+use wp_derive_request_builder::WpDerivedRequest;
+
+#[derive(WpDerivedRequest)]
+enum WidgetsRequest {
+    #[contextual_get(url = "/widgets", params = &WidgetListParams, output = Vec<crate::widgets::SparseWidget>, filter_by = crate::widgets::SparseWidgetField)]
+    List,
+    #[post(url = "/widgets", params = &WidgetCreateParams, output = WidgetWithEditContext)]
+    Create,
+    #[contextual_get(url = "/widgets/<widget_id>", output = crate::widgets::SparseWidget, filter_by = crate::widgets::SparseWidgetField)]
+    Retrieve,
+    #[post(url = "/widgets/<widget_id>", params = &WidgetUpdateParams, output = WidgetWithEditContext)]
+    Update,
+    // Moves the widget to the inactive widgets
+    #[delete(url = "/widgets/<widget_id>", output = WidgetWithEditContext)]
+    Deactivate,
+    #[delete(url = "/widgets/<widget_id>", params = &ForceDeleteParams, output = WidgetDeleteResponse)]
+    Delete,
+}
+
+impl DerivedRequest for WidgetsRequest {
+    fn namespace() -> Namespace {
+        Namespace::WpV2
+    }
+}
+
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseWidgetFieldWithEditContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseWidgetFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseWidgetFieldWithViewContext
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generate,
+        request::endpoint::{
+            tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+            ApiBaseUrl,
+        },
+        widgets::SidebarId,
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    #[case(WidgetListParams::default(), "/widgets?context=edit")]
+    #[case(generate!(WidgetListParams, (sidebar, Some(SidebarId::from("sidebar-1")))), "/widgets?context=edit&sidebar=sidebar-1")]
+    fn list_widgets(
+        endpoint: WidgetsRequestEndpoint,
+        #[case] params: WidgetListParams,
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(endpoint.list_with_edit_context(&params), expected_path);
+    }
+
+    #[rstest]
+    fn retrieve_widget(endpoint: WidgetsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.filter_retrieve_with_edit_context(
+                &WidgetId::from("block-2"),
+                &[SparseWidgetFieldWithEditContext::Instance],
+            ),
+            "/widgets/block-2?context=edit&_fields=instance",
+        );
+    }
+
+    #[rstest]
+    fn create_widget(endpoint: WidgetsRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.create(), "/widgets");
+    }
+
+    #[rstest]
+    fn update_widget(endpoint: WidgetsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.update(&WidgetId::from("block-2")),
+            "/widgets/block-2",
+        );
+    }
+
+    #[rstest]
+    fn deactivate_widget(endpoint: WidgetsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.deactivate(&WidgetId::from("block-2")),
+            "/widgets/block-2",
+        );
+    }
+
+    #[rstest]
+    fn delete_widget(endpoint: WidgetsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.delete(&WidgetId::from("block-2"), &ForceDeleteParams::default()),
+            "/widgets/block-2?force=true",
+        );
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> WidgetsRequestEndpoint {
+        WidgetsRequestEndpoint::new(fixture_api_base_url)
+    }
+}
@@ -0,0 +1,160 @@
+use super::{DerivedRequest, Namespace};
+use crate::posts::PostId;
+use crate::wp_blocks::{
+    SparseWpBlockFieldWithEditContext, SparseWpBlockFieldWithEmbedContext,
+    SparseWpBlockFieldWithViewContext, SparseWpPatternCategoryFieldWithEditContext,
+    SparseWpPatternCategoryFieldWithEmbedContext, SparseWpPatternCategoryFieldWithViewContext,
+    WpBlockCreateParams, WpBlockDeleteResponse, WpBlockListParams, WpBlockUpdateParams,
+    WpBlockWithEditContext, WpPatternCategoryCreateParams, WpPatternCategoryListParams,
+    WpPatternCategoryWithEditContext,
+};
+use crate::{ForceDeleteParams, SparseField};
+use wp_derive_request_builder::WpDerivedRequest;
+
+#[derive(WpDerivedRequest)]
+enum WpBlocksRequest {
+    #[contextual_get(url = "/blocks", params = &WpBlockListParams, output = Vec<crate::wp_blocks::SparseWpBlock>, filter_by = crate::wp_blocks::SparseWpBlockField)]
+    List,
+    #[post(url = "/blocks", params = &WpBlockCreateParams, output = WpBlockWithEditContext)]
+    Create,
+    #[contextual_get(url = "/blocks/<post_id>", output = crate::wp_blocks::SparseWpBlock, filter_by = crate::wp_blocks::SparseWpBlockField)]
+    Retrieve,
+    #[post(url = "/blocks/<post_id>", params = &WpBlockUpdateParams, output = WpBlockWithEditContext)]
+    Update,
+    #[delete(url = "/blocks/<post_id>", output = WpBlockWithEditContext)]
+    Trash,
+    #[delete(url = "/blocks/<post_id>", params = &ForceDeleteParams, output = WpBlockDeleteResponse)]
+    Delete,
+    #[contextual_get(url = "/wp_pattern_category", params = &WpPatternCategoryListParams, output = Vec<crate::wp_blocks::SparseWpPatternCategory>, filter_by = crate::wp_blocks::SparseWpPatternCategoryField)]
+    ListPatternCategories,
+    #[post(url = "/wp_pattern_category", params = &WpPatternCategoryCreateParams, output = WpPatternCategoryWithEditContext)]
+    CreatePatternCategory,
+}
+
+impl DerivedRequest for WpBlocksRequest {
+    fn namespace() -> Namespace {
+        Namespace::WpV2
+    }
+}
+
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseWpBlockFieldWithEditContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseWpBlockFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseWpBlockFieldWithViewContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseWpPatternCategoryFieldWithEditContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseWpPatternCategoryFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseWpPatternCategoryFieldWithViewContext
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generate,
+        request::endpoint::{
+            tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+            ApiBaseUrl,
+        },
+        wp_blocks::WpPatternCategoryId,
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    #[case(WpBlockListParams::default(), "/blocks?context=edit")]
+    #[case(generate!(WpBlockListParams, (search, Some("footer".to_string()))), "/blocks?context=edit&search=footer")]
+    #[case(generate!(WpBlockListParams, (wp_pattern_category, vec![WpPatternCategoryId(2)])), "/blocks?context=edit&wp_pattern_category=2")]
+    fn list_wp_blocks(
+        endpoint: WpBlocksRequestEndpoint,
+        #[case] params: WpBlockListParams,
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(endpoint.list_with_edit_context(&params), expected_path);
+    }
+
+    #[rstest]
+    fn filter_list_wp_blocks(endpoint: WpBlocksRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.filter_list_with_view_context(
+                &WpBlockListParams::default(),
+                &[
+                    SparseWpBlockFieldWithViewContext::Id,
+                    SparseWpBlockFieldWithViewContext::Meta,
+                ],
+            ),
+            "/blocks?context=view&_fields=id%2Cmeta",
+        );
+    }
+
+    #[rstest]
+    fn create_wp_block(endpoint: WpBlocksRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.create(), "/blocks");
+    }
+
+    #[rstest]
+    fn retrieve_wp_block(endpoint: WpBlocksRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_edit_context(&PostId(54)),
+            "/blocks/54?context=edit",
+        );
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_embed_context(&PostId(54)),
+            "/blocks/54?context=embed",
+        );
+    }
+
+    #[rstest]
+    fn update_wp_block(endpoint: WpBlocksRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.update(&PostId(54)), "/blocks/54");
+    }
+
+    #[rstest]
+    fn trash_wp_block(endpoint: WpBlocksRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.trash(&PostId(54)), "/blocks/54");
+    }
+
+    #[rstest]
+    fn delete_wp_block(endpoint: WpBlocksRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.delete(&PostId(54), &ForceDeleteParams::default()),
+            "/blocks/54?force=true",
+        );
+    }
+
+    #[rstest]
+    #[case(
+        WpPatternCategoryListParams::default(),
+        "/wp_pattern_category?context=edit"
+    )]
+    #[case(generate!(WpPatternCategoryListParams, (hide_empty, Some(true))), "/wp_pattern_category?context=edit&hide_empty=true")]
+    fn list_pattern_categories(
+        endpoint: WpBlocksRequestEndpoint,
+        #[case] params: WpPatternCategoryListParams,
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(
+            endpoint.list_pattern_categories_with_edit_context(&params),
+            expected_path,
+        );
+    }
+
+    #[rstest]
+    fn create_pattern_category(endpoint: WpBlocksRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.create_pattern_category(), "/wp_pattern_category");
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> WpBlocksRequestEndpoint {
+        WpBlocksRequestEndpoint::new(fixture_api_base_url)
+    }
+}
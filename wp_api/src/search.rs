@@ -0,0 +1,246 @@
+use serde::{Deserialize, Serialize};
+use wp_derive_request_builder::WpQueryParams;
+
+use crate::{
+    post_types::PostType,
+    posts::{CategoryId, PostFormat, PostId, TagId},
+    query_params::impl_query_param_value_via_as_str,
+};
+
+#[derive(Debug, Default, PartialEq, Eq, uniffi::Record, WpQueryParams)]
+pub struct SearchListParams {
+    /// Current page of the collection.
+    /// Default: `1`
+    #[uniffi(default = None)]
+    pub page: Option<u32>,
+    /// Maximum number of items to be returned in result set.
+    /// Default: `10`
+    #[uniffi(default = None)]
+    pub per_page: Option<u32>,
+    /// Limit results to those matching a string.
+    #[uniffi(default = None)]
+    pub search: Option<String>,
+    /// Limit results to items of an object type.
+    /// Default: `post`
+    #[uniffi(default = None)]
+    #[WpQueryParam(rename = "type")]
+    pub search_type: Option<SearchType>,
+    /// Limit results to items of one or more object subtypes, such as post types for `post`
+    /// results or taxonomies for `term` results.
+    /// Default: `any`
+    #[uniffi(default = [])]
+    pub subtype: Vec<SearchSubtype>,
+    /// Ensure result set excludes specific IDs.
+    #[uniffi(default = [])]
+    pub exclude: Vec<u32>,
+    /// Limit result set to specific IDs.
+    #[uniffi(default = [])]
+    pub include: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, uniffi::Enum)]
+#[serde(rename_all = "kebab-case")]
+pub enum SearchType {
+    Post,
+    Term,
+    PostFormat,
+}
+
+impl SearchType {
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Post => "post",
+            Self::Term => "term",
+            Self::PostFormat => "post-format",
+        }
+    }
+}
+
+impl_query_param_value_via_as_str!(SearchType, [Post, Term, PostFormat]);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Enum)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchSubtype {
+    /// All the subtypes of the searched type.
+    Any,
+    Post,
+    Page,
+    Category,
+    PostTag,
+    #[serde(untagged)]
+    Custom(String),
+}
+
+impl SearchSubtype {
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Any => "any",
+            Self::Post => "post",
+            Self::Page => "page",
+            Self::Category => "category",
+            Self::PostTag => "post_tag",
+            Self::Custom(subtype) => subtype.as_str(),
+        }
+    }
+}
+
+impl_query_param_value_via_as_str!(SearchSubtype, [Any, Post, Page, Category, PostTag], Custom);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Enum)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum SearchResult {
+    Post(SearchPostResult),
+    Term(SearchTermResult),
+    PostFormat(SearchPostFormatResult),
+    /// A result from a search handler registered by a plugin.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+pub struct SearchPostResult {
+    pub id: PostId,
+    pub title: String,
+    pub url: String,
+    #[serde(rename = "subtype")]
+    pub post_type: PostType,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+#[serde(from = "SearchTermResultJson", into = "SearchTermResultJson")]
+pub struct SearchTermResult {
+    pub id: SearchTermId,
+    pub title: String,
+    pub url: String,
+}
+
+/// The ID of a term search result, typed by the term's taxonomy.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Enum)]
+pub enum SearchTermId {
+    Category { id: CategoryId },
+    Tag { id: TagId },
+    Custom { taxonomy: String, id: i32 },
+}
+
+// Term results are identified by the term ID & the taxonomy, which WordPress returns as the
+// result's `subtype`
+#[derive(Serialize, Deserialize)]
+struct SearchTermResultJson {
+    id: i32,
+    title: String,
+    url: String,
+    subtype: String,
+}
+
+impl From<SearchTermResultJson> for SearchTermResult {
+    fn from(value: SearchTermResultJson) -> Self {
+        let id = match value.subtype.as_str() {
+            "category" => SearchTermId::Category {
+                id: CategoryId(value.id),
+            },
+            "post_tag" => SearchTermId::Tag {
+                id: TagId(value.id),
+            },
+            _ => SearchTermId::Custom {
+                taxonomy: value.subtype,
+                id: value.id,
+            },
+        };
+        Self {
+            id,
+            title: value.title,
+            url: value.url,
+        }
+    }
+}
+
+impl From<SearchTermResult> for SearchTermResultJson {
+    fn from(value: SearchTermResult) -> Self {
+        let (subtype, id) = match value.id {
+            SearchTermId::Category { id } => ("category".to_string(), id.0),
+            SearchTermId::Tag { id } => ("post_tag".to_string(), id.0),
+            SearchTermId::Custom { taxonomy, id } => (taxonomy, id),
+        };
+        Self {
+            id,
+            title: value.title,
+            url: value.url,
+            subtype,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+pub struct SearchPostFormatResult {
+    /// Post format results are identified by the format's slug.
+    pub id: PostFormat,
+    pub title: String,
+    pub url: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, unit_test_common::assert_expected_query_pairs};
+    use rstest::*;
+    use serde_json::json;
+
+    #[rstest]
+    #[case(SearchListParams::default(), &[])]
+    #[case(generate!(SearchListParams, (search, Some("foo".to_string()))), &[("search", "foo")])]
+    #[case(generate!(SearchListParams, (search_type, Some(SearchType::PostFormat))), &[("type", "post-format")])]
+    #[case(generate!(SearchListParams, (subtype, vec![SearchSubtype::Category, SearchSubtype::Custom("genre".to_string())])), &[("subtype", "category,genre")])]
+    #[case(generate!(SearchListParams, (include, vec![1, 2])), &[("include", "1,2")])]
+    #[trace]
+    fn test_search_list_params(
+        #[case] params: SearchListParams,
+        #[case] expected_pairs: &[(&str, &str)],
+    ) {
+        assert_expected_query_pairs(params.query_pairs(), expected_pairs);
+    }
+
+    #[rstest]
+    #[case(
+        json!({ "id": 1, "title": "Hello world!", "url": "https://example.com/hello-world", "type": "post", "subtype": "post" }),
+        SearchResult::Post(SearchPostResult { id: PostId(1), title: "Hello world!".to_string(), url: "https://example.com/hello-world".to_string(), post_type: PostType::Post })
+    )]
+    #[case(
+        json!({ "id": 2, "title": "News", "url": "https://example.com/category/news", "type": "term", "subtype": "category" }),
+        SearchResult::Term(SearchTermResult { id: SearchTermId::Category { id: CategoryId(2) }, title: "News".to_string(), url: "https://example.com/category/news".to_string() })
+    )]
+    #[case(
+        json!({ "id": 3, "title": "Rust", "url": "https://example.com/tag/rust", "type": "term", "subtype": "post_tag" }),
+        SearchResult::Term(SearchTermResult { id: SearchTermId::Tag { id: TagId(3) }, title: "Rust".to_string(), url: "https://example.com/tag/rust".to_string() })
+    )]
+    #[case(
+        json!({ "id": 4, "title": "Jazz", "url": "https://example.com/genre/jazz", "type": "term", "subtype": "genre" }),
+        SearchResult::Term(SearchTermResult { id: SearchTermId::Custom { taxonomy: "genre".to_string(), id: 4 }, title: "Jazz".to_string(), url: "https://example.com/genre/jazz".to_string() })
+    )]
+    #[case(
+        json!({ "id": "aside", "title": "Aside", "url": "https://example.com/type/aside", "type": "post-format", "subtype": "" }),
+        SearchResult::PostFormat(SearchPostFormatResult { id: PostFormat::Aside, title: "Aside".to_string(), url: "https://example.com/type/aside".to_string() })
+    )]
+    #[case(
+        json!({ "id": 5, "title": "Product", "url": "https://example.com/product", "type": "product", "subtype": "shoes" }),
+        SearchResult::Unknown
+    )]
+    fn deserialize_search_result(#[case] json: serde_json::Value, #[case] expected: SearchResult) {
+        assert_eq!(
+            serde_json::from_value::<SearchResult>(json).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn serialize_term_search_result_with_its_taxonomy() {
+        let result = SearchResult::Term(SearchTermResult {
+            id: SearchTermId::Tag { id: TagId(3) },
+            title: "Rust".to_string(),
+            url: "https://example.com/tag/rust".to_string(),
+        });
+        assert_eq!(
+            serde_json::to_value(&result).unwrap(),
+            json!({ "type": "term", "id": 3, "title": "Rust", "url": "https://example.com/tag/rust", "subtype": "post_tag" })
+        );
+    }
+}
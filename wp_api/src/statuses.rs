@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use wp_contextual::WpContextual;
+
+use crate::{post_types::PostType, posts::PostStatus};
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+#[serde(transparent)]
+pub struct SparseStatusesResponse {
+    #[serde(flatten)]
+    #[WpContext(edit, embed, view)]
+    #[WpContextualField]
+    pub statuses: Option<HashMap<PostStatus, SparseStatus>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseStatus {
+    /// The label of the status.
+    #[WpContext(edit, embed, view)]
+    pub name: Option<String>,
+    /// Whether posts with the status should be private.
+    #[WpContext(edit)]
+    pub private: Option<bool>,
+    /// Whether posts with the status should be protected.
+    #[WpContext(edit)]
+    pub protected: Option<bool>,
+    /// Whether posts of the status should be shown in the front end of the site.
+    #[WpContext(edit, view)]
+    pub public: Option<bool>,
+    /// Whether posts with the status should be publicly-queryable.
+    #[WpContext(edit, view)]
+    pub queryable: Option<bool>,
+    /// Whether to include posts in the edit listing for their post type.
+    #[WpContext(edit)]
+    pub show_in_list: Option<bool>,
+    #[WpContext(edit, embed, view)]
+    pub slug: Option<PostStatus>,
+    /// Whether posts of the status may have floating published dates.
+    #[WpContext(edit, view)]
+    pub date_floating: Option<bool>,
+}
+
+/// Returns the statuses that posts of the given type can be filtered by, i.e. to show a status
+/// filter above a post list.
+///
+/// WordPress doesn't associate statuses with post types, so statuses registered by plugins are
+/// returned for every post type. The exception is attachments, which always inherit the status of
+/// their parent post & can only be filtered by `inherit`, `private` & `trash`.
+///
+/// Other post types can be filtered by the statuses that are shown in their list, i.e.
+/// `show_in_list`, and by `trash`. WordPress hides `trash` from the "All" list, so its
+/// `show_in_list` is always `false`, but it's the one internal status the REST API exposes, so
+/// trashed posts can be listed.
+///
+/// `statuses` only includes the statuses the current user can read, so the returned statuses
+/// respect the user's capabilities as well.
+#[uniffi::export]
+pub fn post_status_filters(
+    statuses: &StatusesResponseWithEditContext,
+    post_type: &PostType,
+) -> Vec<PostStatus> {
+    if *post_type == PostType::Attachment {
+        return vec![PostStatus::Private, PostStatus::Trash, PostStatus::Inherit];
+    }
+    let mut filters: Vec<PostStatus> = statuses
+        .statuses
+        .values()
+        .filter(|status| status.show_in_list || status.slug == PostStatus::Trash)
+        .map(|status| status.slug.clone())
+        .collect();
+    filters.sort();
+    filters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn statuses() -> StatusesResponseWithEditContext {
+        let status = |slug: &str, show_in_list: bool| {
+            json!({
+                "name": slug,
+                "private": false,
+                "protected": false,
+                "public": false,
+                "queryable": false,
+                "show_in_list": show_in_list,
+                "slug": slug,
+                "date_floating": false
+            })
+        };
+        serde_json::from_value(json!({
+            "publish": status("publish", true),
+            "future": status("future", true),
+            "draft": status("draft", true),
+            "pending": status("pending", true),
+            "private": status("private", true),
+            "trash": status("trash", false),
+            "wc-pending": status("wc-pending", true),
+            "wc-checkout-draft": status("wc-checkout-draft", false),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn deserialize_statuses_response() {
+        let statuses = statuses();
+        assert_eq!(
+            statuses.statuses.get(&PostStatus::Trash).unwrap().slug,
+            PostStatus::Trash
+        );
+        assert_eq!(
+            statuses
+                .statuses
+                .get(&PostStatus::Custom("wc-pending".to_string()))
+                .unwrap()
+                .slug,
+            PostStatus::Custom("wc-pending".to_string())
+        );
+    }
+
+    #[test]
+    fn post_status_filters_for_posts() {
+        assert_eq!(
+            post_status_filters(&statuses(), &PostType::Post),
+            vec![
+                PostStatus::Draft,
+                PostStatus::Future,
+                PostStatus::Pending,
+                PostStatus::Private,
+                PostStatus::Publish,
+                PostStatus::Trash,
+                PostStatus::Custom("wc-pending".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn post_status_filters_for_attachments() {
+        assert_eq!(
+            post_status_filters(&statuses(), &PostType::Attachment),
+            vec![PostStatus::Private, PostStatus::Trash, PostStatus::Inherit]
+        );
+    }
+}
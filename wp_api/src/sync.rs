@@ -0,0 +1,603 @@
+//! Offline-first sync of site content into a local store.
+//!
+//! [`PostsSyncEngine`] pulls posts incrementally into a [`SyncStore`], so that the native apps
+//! can read their content from the store instead of each implementing their own sync. Every sync
+//! is a new "generation": records that are changed or deleted during a sync are stamped with it,
+//! and [`PostsSyncEngine::changes_since`] returns what changed after a given generation.
+//!
+//! Only posts are synced for now. Pages & comments will get their own collections once the client
+//! has endpoints for them.
+//!
+//! Writes that are made while offline can be queued in a [`MutationQueue`] & replayed once the
+//! site is reachable again.
+//!
+//! The stores are pluggable through the [`SyncStore`] & [`MutationQueueStore`] traits, which can
+//! be implemented natively. [`InMemorySyncStore`] is always available, and [`SqliteSyncStore`] is
+//! available with the `sync-sqlite` feature.
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Debug,
+    sync::{Arc, Mutex},
+};
+
+use crate::{
+    posts::{
+        PostId, PostListParams, PostStatus, PostWithEditContext, SparsePostFieldWithEditContext,
+        WpApiParamPostsOrderBy,
+    },
+    request::RequestExecutor,
+    ParsedUrl, WpApiClient, WpApiError, WpApiParamOrder, WpAuthentication, WpErrorCode,
+};
+
+mod mutation_queue;
+#[cfg(feature = "sync-sqlite")]
+mod sqlite_store;
+
+pub use mutation_queue::{
+    MutationFailure, MutationQueue, MutationQueueStore, MutationReplaySummary, QueuedMutation,
+};
+#[cfg(feature = "sync-sqlite")]
+pub use sqlite_store::SqliteSyncStore;
+
+pub const POSTS_SYNC_COLLECTION: &str = "posts";
+
+const SYNC_PER_PAGE: u32 = 100;
+
+// `PostListParams.status` defaults to `publish`, so the other statuses need to be requested
+// explicitly. Trashed posts are left out, which turns them into tombstones.
+const SYNCED_POST_STATUSES: [PostStatus; 5] = [
+    PostStatus::Publish,
+    PostStatus::Future,
+    PostStatus::Draft,
+    PostStatus::Pending,
+    PostStatus::Private,
+];
+
+/// Stores synced records, grouped by collection, i.e. `posts`.
+///
+/// Implementations should apply each call atomically.
+#[uniffi::export(with_foreign)]
+pub trait SyncStore: Send + Sync + Debug {
+    fn load_state(&self, collection: String) -> Result<Option<SyncState>, SyncStoreError>;
+
+    fn save_state(&self, collection: String, state: SyncState) -> Result<(), SyncStoreError>;
+
+    /// Inserts the records, or replaces the existing records with the same `id`.
+    fn upsert_records(
+        &self,
+        collection: String,
+        records: Vec<SyncRecord>,
+    ) -> Result<(), SyncStoreError>;
+
+    /// Returns the ids of the records that are not deleted.
+    fn record_ids(&self, collection: String) -> Result<Vec<i64>, SyncStoreError>;
+
+    /// Turns the records into tombstones by setting `deleted` & their `generation`.
+    fn mark_deleted(
+        &self,
+        collection: String,
+        ids: Vec<i64>,
+        generation: u64,
+    ) -> Result<(), SyncStoreError>;
+
+    /// Returns the records, including tombstones, whose `generation` is greater than the given
+    /// one, ordered by `id`.
+    fn changed_since(
+        &self,
+        collection: String,
+        generation: u64,
+    ) -> Result<Vec<SyncRecord>, SyncStoreError>;
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct SyncState {
+    /// The generation of the last completed sync.
+    pub generation: u64,
+    /// The latest `modified` date that was synced, used as `modified_after` by the next sync.
+    pub last_modified: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct SyncRecord {
+    pub id: i64,
+    pub modified: String,
+    /// The record as JSON. Tombstones keep the last synced version of the record.
+    pub json: String,
+    pub deleted: bool,
+    /// The generation of the sync that last changed the record.
+    pub generation: u64,
+}
+
+#[derive(Debug, uniffi::Record)]
+pub struct SyncSummary {
+    pub generation: u64,
+    pub upserted_count: u64,
+    pub deleted_count: u64,
+}
+
+#[derive(Debug, uniffi::Record)]
+pub struct PostSyncChange {
+    pub post_id: PostId,
+    /// The synced post, or `None` if it was deleted.
+    pub post: Option<PostWithEditContext>,
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error, uniffi::Error)]
+pub enum SyncStoreError {
+    #[error("Sync store failed: {}", reason)]
+    StoreFailed { reason: String },
+}
+
+impl From<uniffi::UnexpectedUniFFICallbackError> for SyncStoreError {
+    fn from(value: uniffi::UnexpectedUniFFICallbackError) -> Self {
+        Self::StoreFailed {
+            reason: value.reason,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum SyncError {
+    #[error("Sync request failed: {}", inner)]
+    RequestFailed { inner: WpApiError },
+    #[error("Sync store failed: {}", reason)]
+    StoreFailed { reason: String },
+    #[error("Synced record couldn't be parsed: {}", reason)]
+    RecordParsingError { reason: String },
+}
+
+impl From<WpApiError> for SyncError {
+    fn from(value: WpApiError) -> Self {
+        Self::RequestFailed { inner: value }
+    }
+}
+
+impl From<SyncStoreError> for SyncError {
+    fn from(value: SyncStoreError) -> Self {
+        match value {
+            SyncStoreError::StoreFailed { reason } => Self::StoreFailed { reason },
+        }
+    }
+}
+
+#[derive(Debug, uniffi::Object)]
+pub struct PostsSyncEngine {
+    api_client: WpApiClient,
+    store: Arc<dyn SyncStore>,
+}
+
+#[uniffi::export]
+impl PostsSyncEngine {
+    #[uniffi::constructor]
+    pub fn new(
+        site_url: Arc<ParsedUrl>,
+        authentication: WpAuthentication,
+        request_executor: Arc<dyn RequestExecutor>,
+        store: Arc<dyn SyncStore>,
+    ) -> Self {
+        Self {
+            api_client: WpApiClient::new(site_url, authentication, request_executor),
+            store,
+        }
+    }
+
+    /// Pulls the posts that were modified since the last sync, and turns the posts that are no
+    /// longer listed by the site into tombstones.
+    pub async fn sync(&self) -> Result<SyncSummary, SyncError> {
+        let collection = POSTS_SYNC_COLLECTION.to_string();
+        let state = self
+            .store
+            .load_state(collection.clone())?
+            .unwrap_or_default();
+        let generation = state.generation + 1;
+
+        let mut last_modified = state.last_modified.clone();
+        let mut upserted_count = 0;
+        for page in 1.. {
+            let params = PostListParams {
+                page: Some(page),
+                per_page: Some(SYNC_PER_PAGE),
+                modified_after: state.last_modified.clone(),
+                orderby: Some(WpApiParamPostsOrderBy::Modified),
+                order: Some(WpApiParamOrder::Asc),
+                status: SYNCED_POST_STATUSES.to_vec(),
+                ..Default::default()
+            };
+            let Some(posts) = self.list_page(&params).await? else {
+                break;
+            };
+            let post_count = posts.len();
+            let records = posts
+                .into_iter()
+                .map(|post| {
+                    if last_modified.as_ref().map_or(true, |m| post.modified > *m) {
+                        last_modified = Some(post.modified.clone());
+                    }
+                    post_record(post, generation)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            upserted_count += records.len() as u64;
+            self.store.upsert_records(collection.clone(), records)?;
+            if post_count < SYNC_PER_PAGE as usize {
+                break;
+            }
+        }
+
+        let remote_ids = self.remote_post_ids().await?;
+        let deleted_ids = self
+            .store
+            .record_ids(collection.clone())?
+            .into_iter()
+            .filter(|id| !remote_ids.contains(id))
+            .collect::<Vec<_>>();
+        let deleted_count = deleted_ids.len() as u64;
+        if !deleted_ids.is_empty() {
+            self.store
+                .mark_deleted(collection.clone(), deleted_ids, generation)?;
+        }
+
+        self.store.save_state(
+            collection,
+            SyncState {
+                generation,
+                last_modified,
+            },
+        )?;
+        Ok(SyncSummary {
+            generation,
+            upserted_count,
+            deleted_count,
+        })
+    }
+
+    pub fn last_sync(&self) -> Result<Option<SyncState>, SyncError> {
+        Ok(self.store.load_state(POSTS_SYNC_COLLECTION.to_string())?)
+    }
+
+    /// Returns the posts that were changed or deleted after the given generation. Use `0` to
+    /// get all the synced posts.
+    pub fn changes_since(&self, generation: u64) -> Result<Vec<PostSyncChange>, SyncError> {
+        self.store
+            .changed_since(POSTS_SYNC_COLLECTION.to_string(), generation)?
+            .into_iter()
+            .map(|record| {
+                let post = if record.deleted {
+                    None
+                } else {
+                    serde_json::from_str(&record.json).map_err(|e| {
+                        SyncError::RecordParsingError {
+                            reason: e.to_string(),
+                        }
+                    })?
+                };
+                Ok(PostSyncChange {
+                    post_id: PostId(record.id as i32),
+                    post,
+                })
+            })
+            .collect()
+    }
+}
+
+impl PostsSyncEngine {
+    // Returns `None` once the page is past the last one. WordPress responds with
+    // `rest_post_invalid_page_number` in that case, unless the collection is empty.
+    async fn list_page(
+        &self,
+        params: &PostListParams,
+    ) -> Result<Option<Vec<PostWithEditContext>>, WpApiError> {
+        match self.api_client.posts().list_with_edit_context(params).await {
+            Ok(posts) if posts.is_empty() => Ok(None),
+            Ok(posts) => Ok(Some(posts)),
+            Err(WpApiError::WpError {
+                error_code: WpErrorCode::CustomError(code),
+                ..
+            }) if code == "rest_post_invalid_page_number" => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn remote_post_ids(&self) -> Result<HashSet<i64>, WpApiError> {
+        let mut ids = HashSet::new();
+        for page in 1.. {
+            let params = PostListParams {
+                page: Some(page),
+                per_page: Some(SYNC_PER_PAGE),
+                status: SYNCED_POST_STATUSES.to_vec(),
+                ..Default::default()
+            };
+            let result = self
+                .api_client
+                .posts()
+                .filter_list_with_edit_context(&params, &[SparsePostFieldWithEditContext::Id])
+                .await;
+            let posts = match result {
+                Ok(posts) => posts,
+                Err(WpApiError::WpError {
+                    error_code: WpErrorCode::CustomError(code),
+                    ..
+                }) if code == "rest_post_invalid_page_number" => break,
+                Err(e) => return Err(e),
+            };
+            let post_count = posts.len();
+            ids.extend(posts.into_iter().filter_map(|p| p.id.map(|id| id.0 as i64)));
+            if post_count < SYNC_PER_PAGE as usize {
+                break;
+            }
+        }
+        Ok(ids)
+    }
+}
+
+fn post_record(post: PostWithEditContext, generation: u64) -> Result<SyncRecord, SyncError> {
+    let json = serde_json::to_string(&post).map_err(|e| SyncError::RecordParsingError {
+        reason: e.to_string(),
+    })?;
+    Ok(SyncRecord {
+        id: post.id.0 as i64,
+        modified: post.modified,
+        json,
+        deleted: false,
+        generation,
+    })
+}
+
+#[derive(Debug, Default, uniffi::Object)]
+pub struct InMemorySyncStore {
+    collections: Mutex<HashMap<String, InMemoryCollection>>,
+    mutations: Mutex<InMemoryMutations>,
+}
+
+#[derive(Debug, Default)]
+struct InMemoryCollection {
+    state: Option<SyncState>,
+    records: BTreeMap<i64, SyncRecord>,
+}
+
+#[derive(Debug, Default)]
+struct InMemoryMutations {
+    last_id: i64,
+    mutations: BTreeMap<i64, QueuedMutation>,
+}
+
+#[uniffi::export]
+impl InMemorySyncStore {
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl InMemorySyncStore {
+    fn with_collection<T>(
+        &self,
+        collection: String,
+        f: impl FnOnce(&mut InMemoryCollection) -> T,
+    ) -> Result<T, SyncStoreError> {
+        let mut collections = self
+            .collections
+            .lock()
+            .map_err(|e| SyncStoreError::StoreFailed {
+                reason: e.to_string(),
+            })?;
+        Ok(f(collections.entry(collection).or_default()))
+    }
+
+    fn with_mutations<T>(
+        &self,
+        f: impl FnOnce(&mut InMemoryMutations) -> T,
+    ) -> Result<T, SyncStoreError> {
+        let mut mutations = self
+            .mutations
+            .lock()
+            .map_err(|e| SyncStoreError::StoreFailed {
+                reason: e.to_string(),
+            })?;
+        Ok(f(&mut mutations))
+    }
+}
+
+impl SyncStore for InMemorySyncStore {
+    fn load_state(&self, collection: String) -> Result<Option<SyncState>, SyncStoreError> {
+        self.with_collection(collection, |c| c.state.clone())
+    }
+
+    fn save_state(&self, collection: String, state: SyncState) -> Result<(), SyncStoreError> {
+        self.with_collection(collection, |c| c.state = Some(state))
+    }
+
+    fn upsert_records(
+        &self,
+        collection: String,
+        records: Vec<SyncRecord>,
+    ) -> Result<(), SyncStoreError> {
+        self.with_collection(collection, |c| {
+            c.records
+                .extend(records.into_iter().map(|record| (record.id, record)))
+        })
+    }
+
+    fn record_ids(&self, collection: String) -> Result<Vec<i64>, SyncStoreError> {
+        self.with_collection(collection, |c| {
+            c.records
+                .values()
+                .filter(|r| !r.deleted)
+                .map(|r| r.id)
+                .collect()
+        })
+    }
+
+    fn mark_deleted(
+        &self,
+        collection: String,
+        ids: Vec<i64>,
+        generation: u64,
+    ) -> Result<(), SyncStoreError> {
+        self.with_collection(collection, |c| {
+            for id in ids {
+                if let Some(record) = c.records.get_mut(&id) {
+                    record.deleted = true;
+                    record.generation = generation;
+                }
+            }
+        })
+    }
+
+    fn changed_since(
+        &self,
+        collection: String,
+        generation: u64,
+    ) -> Result<Vec<SyncRecord>, SyncStoreError> {
+        self.with_collection(collection, |c| {
+            c.records
+                .values()
+                .filter(|r| r.generation > generation)
+                .cloned()
+                .collect()
+        })
+    }
+}
+
+impl MutationQueueStore for InMemorySyncStore {
+    fn push_mutation(&self, mutation: QueuedMutation) -> Result<QueuedMutation, SyncStoreError> {
+        self.with_mutations(|m| {
+            m.last_id += 1;
+            let mutation = QueuedMutation {
+                id: m.last_id,
+                ..mutation
+            };
+            m.mutations.insert(mutation.id, mutation.clone());
+            mutation
+        })
+    }
+
+    fn pending_mutations(&self) -> Result<Vec<QueuedMutation>, SyncStoreError> {
+        self.with_mutations(|m| m.mutations.values().cloned().collect())
+    }
+
+    fn update_mutation(&self, mutation: QueuedMutation) -> Result<(), SyncStoreError> {
+        self.with_mutations(|m| {
+            if let Some(existing) = m.mutations.get_mut(&mutation.id) {
+                *existing = mutation;
+            }
+        })
+    }
+
+    fn remove_mutation(&self, id: i64) -> Result<(), SyncStoreError> {
+        self.with_mutations(|m| {
+            m.mutations.remove(&id);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    fn record(id: i64, generation: u64) -> SyncRecord {
+        SyncRecord {
+            id,
+            modified: format!("2024-01-{:02}T10:00:00", id),
+            json: format!("{{\"id\":{}}}", id),
+            deleted: false,
+            generation,
+        }
+    }
+
+    #[fixture]
+    fn in_memory_store() -> Arc<dyn SyncStore> {
+        Arc::new(InMemorySyncStore::new())
+    }
+
+    #[cfg(feature = "sync-sqlite")]
+    #[fixture]
+    fn sqlite_store() -> Arc<dyn SyncStore> {
+        Arc::new(SqliteSyncStore::open_in_memory().unwrap())
+    }
+
+    #[rstest]
+    #[case(in_memory_store())]
+    #[cfg_attr(feature = "sync-sqlite", case(sqlite_store()))]
+    fn test_store_state(#[case] store: Arc<dyn SyncStore>) {
+        assert_eq!(store.load_state("posts".to_string()), Ok(None));
+        let state = SyncState {
+            generation: 2,
+            last_modified: Some("2024-01-01T10:00:00".to_string()),
+        };
+        store
+            .save_state("posts".to_string(), state.clone())
+            .unwrap();
+        assert_eq!(store.load_state("posts".to_string()), Ok(Some(state)));
+        assert_eq!(store.load_state("pages".to_string()), Ok(None));
+    }
+
+    #[rstest]
+    #[case(in_memory_store())]
+    #[cfg_attr(feature = "sync-sqlite", case(sqlite_store()))]
+    fn test_store_upsert_records(#[case] store: Arc<dyn SyncStore>) {
+        let collection = || "posts".to_string();
+        store
+            .upsert_records(collection(), vec![record(1, 1), record(2, 1)])
+            .unwrap();
+        store
+            .upsert_records(collection(), vec![record(2, 2), record(3, 2)])
+            .unwrap();
+        assert_eq!(store.record_ids(collection()), Ok(vec![1, 2, 3]));
+        assert_eq!(
+            store.changed_since(collection(), 1),
+            Ok(vec![record(2, 2), record(3, 2)])
+        );
+        assert_eq!(store.record_ids("pages".to_string()), Ok(vec![]));
+    }
+
+    #[rstest]
+    #[case(in_memory_store())]
+    #[cfg_attr(feature = "sync-sqlite", case(sqlite_store()))]
+    fn test_store_mark_deleted(#[case] store: Arc<dyn SyncStore>) {
+        let collection = || "posts".to_string();
+        store
+            .upsert_records(collection(), vec![record(1, 1), record(2, 1)])
+            .unwrap();
+        store.mark_deleted(collection(), vec![1], 2).unwrap();
+        assert_eq!(store.record_ids(collection()), Ok(vec![2]));
+        assert_eq!(
+            store.changed_since(collection(), 1),
+            Ok(vec![SyncRecord {
+                deleted: true,
+                ..record(1, 2)
+            }])
+        );
+    }
+
+    #[rstest]
+    #[case(Arc::new(InMemorySyncStore::new()))]
+    #[cfg_attr(
+        feature = "sync-sqlite",
+        case(Arc::new(SqliteSyncStore::open_in_memory().unwrap()))
+    )]
+    fn test_mutation_queue_store(#[case] store: Arc<dyn MutationQueueStore>) {
+        let mutation = |url: &str| QueuedMutation {
+            id: 0,
+            method: crate::request::RequestMethod::POST,
+            url: url.to_string(),
+            headers: HashMap::from([("accept".to_string(), vec!["application/json".to_string()])]),
+            body: Some("{}".to_string()),
+            temporary_id: Some(-1),
+            attempt_count: 0,
+        };
+        let first = store.push_mutation(mutation("/1")).unwrap();
+        let second = store.push_mutation(mutation("/2")).unwrap();
+        assert!(first.id < second.id);
+        store.remove_mutation(first.id).unwrap();
+        let third = store.push_mutation(mutation("/3")).unwrap();
+        assert!(second.id < third.id);
+
+        let updated = QueuedMutation {
+            attempt_count: 2,
+            ..second
+        };
+        store.update_mutation(updated.clone()).unwrap();
+        assert_eq!(store.pending_mutations(), Ok(vec![updated, third]));
+    }
+}
@@ -0,0 +1,682 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    sync::Arc,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use url::Url;
+
+use crate::{
+    request::{
+        endpoint::WpEndpointUrl, insert_authorization_header, RequestExecutor, RequestMethod,
+        WpNetworkHeaderMap, WpNetworkRequest, WpNetworkRequestBody, WpNetworkResponse,
+    },
+    WpApiError, WpAuthentication,
+};
+
+use super::{SyncError, SyncStoreError};
+
+/// A write that is waiting to be sent to the site.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+pub struct QueuedMutation {
+    /// Assigned by the store when the mutation is pushed.
+    pub id: i64,
+    pub method: RequestMethod,
+    pub url: String,
+    /// The request headers without `Authorization`, which is added back when the mutation is
+    /// replayed, so credentials are never persisted.
+    pub headers: HashMap<String, Vec<String>>,
+    pub body: Option<String>,
+    /// The temporary ID of the object this mutation creates.
+    pub temporary_id: Option<i64>,
+    /// The number of times the mutation failed with a retryable error.
+    pub attempt_count: u32,
+}
+
+/// Persists the mutations of a [`MutationQueue`], so they survive the app being terminated.
+#[uniffi::export(with_foreign)]
+pub trait MutationQueueStore: Send + Sync + Debug {
+    /// Appends the mutation to the end of the queue, and returns it with its assigned `id`.
+    fn push_mutation(&self, mutation: QueuedMutation) -> Result<QueuedMutation, SyncStoreError>;
+
+    /// Returns the mutations in the order they were pushed.
+    fn pending_mutations(&self) -> Result<Vec<QueuedMutation>, SyncStoreError>;
+
+    /// Replaces the mutation with the same `id`.
+    fn update_mutation(&self, mutation: QueuedMutation) -> Result<(), SyncStoreError>;
+
+    fn remove_mutation(&self, id: i64) -> Result<(), SyncStoreError>;
+}
+
+#[derive(Debug, uniffi::Record)]
+pub struct MutationFailure {
+    pub mutation: QueuedMutation,
+    pub error: WpApiError,
+}
+
+#[derive(Debug, Default, uniffi::Record)]
+pub struct MutationReplaySummary {
+    pub replayed_count: u64,
+    /// The mutations that failed permanently & were removed from the queue.
+    pub failures: Vec<MutationFailure>,
+    /// The mutations that referred to the temporary ID of a create that failed permanently. They
+    /// were removed from the queue without being sent.
+    pub dropped_dependents: Vec<QueuedMutation>,
+    /// The number of mutations that are still queued, because a retryable failure stopped the
+    /// replay.
+    pub remaining_count: u64,
+}
+
+/// A durable queue of writes, such as post edits, that were made while offline.
+///
+/// Mutations are replayed in the order they were enqueued. A retryable failure, such as a
+/// connection error or a `5xx` response, stops the replay so the order is kept, and the mutation
+/// is tried again by the next replay until it fails `max_attempts` times. Any other failure is
+/// permanent: the mutation is removed from the queue and its error is returned in the summary.
+///
+/// Objects that are created offline can be referred to by later mutations using a temporary ID.
+/// Once the create is replayed, the temporary ID is replaced by the server's ID in the url & the
+/// ID fields of the JSON body, such as `parent` or `tags`, of the queued mutations. If the create
+/// fails permanently, the mutations that refer to its temporary ID are dropped. Temporary IDs
+/// have to be negative, so they can't be mistaken for server IDs.
+#[derive(Debug, uniffi::Object)]
+pub struct MutationQueue {
+    store: Arc<dyn MutationQueueStore>,
+    authentication: WpAuthentication,
+    request_executor: Arc<dyn RequestExecutor>,
+    max_attempts: u32,
+}
+
+#[uniffi::export]
+impl MutationQueue {
+    #[uniffi::constructor]
+    pub fn new(
+        store: Arc<dyn MutationQueueStore>,
+        authentication: WpAuthentication,
+        request_executor: Arc<dyn RequestExecutor>,
+        max_attempts: u32,
+    ) -> Self {
+        Self {
+            store,
+            authentication,
+            request_executor,
+            max_attempts,
+        }
+    }
+
+    /// Adds the request to the end of the queue. `temporary_id` is the ID that later mutations
+    /// use to refer to the object this request creates.
+    pub fn enqueue(
+        &self,
+        request: Arc<WpNetworkRequest>,
+        temporary_id: Option<i64>,
+    ) -> Result<QueuedMutation, SyncError> {
+        let mut headers = request.header_map.to_map();
+        headers.remove(http::header::AUTHORIZATION.as_str());
+        Ok(self.store.push_mutation(QueuedMutation {
+            id: 0,
+            method: request.method(),
+            url: request.url.0.clone(),
+            headers,
+            body: request.body_as_string(),
+            temporary_id,
+            attempt_count: 0,
+        })?)
+    }
+
+    pub fn pending(&self) -> Result<Vec<QueuedMutation>, SyncError> {
+        Ok(self.store.pending_mutations()?)
+    }
+
+    pub async fn replay(&self) -> Result<MutationReplaySummary, SyncError> {
+        let mut summary = MutationReplaySummary::default();
+        while let Some(mut mutation) = self.store.pending_mutations()?.into_iter().next() {
+            let error = match self.execute(&mutation).await? {
+                Ok(response) => {
+                    self.store.remove_mutation(mutation.id)?;
+                    summary.replayed_count += 1;
+                    if let Some(temporary_id) = mutation.temporary_id {
+                        self.replace_temporary_id(temporary_id, &response)?;
+                    }
+                    continue;
+                }
+                Err(error) => error,
+            };
+            mutation.attempt_count += 1;
+            if is_retryable(&error) && mutation.attempt_count < self.max_attempts {
+                self.store.update_mutation(mutation)?;
+                summary.remaining_count = self.store.pending_mutations()?.len() as u64;
+                break;
+            }
+            self.store.remove_mutation(mutation.id)?;
+            if let Some(temporary_id) = mutation.temporary_id {
+                summary
+                    .dropped_dependents
+                    .extend(self.drop_dependents(temporary_id)?);
+            }
+            summary.failures.push(MutationFailure { mutation, error });
+        }
+        Ok(summary)
+    }
+}
+
+impl MutationQueue {
+    async fn execute(
+        &self,
+        mutation: &QueuedMutation,
+    ) -> Result<Result<WpNetworkResponse, WpApiError>, SyncError> {
+        let mut header_map = WpNetworkHeaderMap::from_multi_map(mutation.headers.clone())
+            .map_err(|e| SyncError::RecordParsingError {
+                reason: e.to_string(),
+            })?
+            .as_header_map();
+        insert_authorization_header(&mut header_map, &self.authentication);
+        let request = WpNetworkRequest {
+            method: mutation.method.clone(),
+            url: WpEndpointUrl(mutation.url.clone()),
+            header_map: WpNetworkHeaderMap::new(header_map).into(),
+            body: mutation
+                .body
+                .as_ref()
+                .map(|body| WpNetworkRequestBody::new(body.clone().into_bytes()).into()),
+            timeout_ms: None,
+        };
+        Ok(async {
+            let response = self.request_executor.execute(request.into()).await?;
+            response.parse_response_for_errors()?;
+            Ok(response)
+        }
+        .await)
+    }
+
+    fn replace_temporary_id(
+        &self,
+        temporary_id: i64,
+        response: &WpNetworkResponse,
+    ) -> Result<(), SyncError> {
+        let server_id = serde_json::from_slice::<Value>(&response.body)
+            .ok()
+            .and_then(|created| created["id"].as_i64())
+            .ok_or_else(|| SyncError::RecordParsingError {
+                reason: format!(
+                    "The response of the create for temporary ID '{}' doesn't have an 'id'",
+                    temporary_id
+                ),
+            })?;
+        for mut mutation in self.store.pending_mutations()? {
+            if replace_id(&mut mutation, temporary_id, server_id) {
+                self.store.update_mutation(mutation)?;
+            }
+        }
+        Ok(())
+    }
+
+    // Removes the mutations that refer to the temporary ID of a failed create. Dropped creates
+    // take their own dependents with them.
+    fn drop_dependents(&self, temporary_id: i64) -> Result<Vec<QueuedMutation>, SyncError> {
+        let mut failed_ids = HashSet::from([temporary_id]);
+        let mut dropped = Vec::new();
+        for mutation in self.store.pending_mutations()? {
+            if !failed_ids.iter().any(|id| refers_to_id(&mutation, *id)) {
+                continue;
+            }
+            self.store.remove_mutation(mutation.id)?;
+            failed_ids.extend(mutation.temporary_id);
+            dropped.push(mutation);
+        }
+        Ok(dropped)
+    }
+}
+
+// Connection errors, timeouts, rate limiting & server errors might succeed when they are retried
+fn is_retryable(error: &WpApiError) -> bool {
+    match error {
+        WpApiError::RequestExecutionFailed { .. } => true,
+        WpApiError::WpError { status_code, .. } | WpApiError::UnknownError { status_code, .. } => {
+            matches!(status_code, 408 | 429 | 500..=599)
+        }
+        _ => false,
+    }
+}
+
+// The JSON body fields that hold the ID, or the IDs, of another object
+const JSON_ID_FIELDS: [&str; 10] = [
+    "id",
+    "parent",
+    "post",
+    "author",
+    "featured_media",
+    "categories",
+    "tags",
+    "menus",
+    "object_id",
+    "wp_pattern_category",
+];
+
+// Replaces `id` in the path segments, the query values & the ID fields of the JSON body of the
+// mutation. Returns whether the mutation referred to `id`.
+//
+// Only the parts that refer to `id` are rebuilt, so the encoding of the rest of the mutation is
+// kept as is.
+fn replace_id(mutation: &mut QueuedMutation, id: i64, replacement: i64) -> bool {
+    let (id_str, replacement_str) = (id.to_string(), replacement.to_string());
+    let replace_str = |s: &str| {
+        s.split(',')
+            .map(|part| {
+                if part == id_str {
+                    &replacement_str
+                } else {
+                    part
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+    let mut replaced = false;
+    if let Ok(mut url) = Url::parse(&mutation.url) {
+        if url_path_refers_to_id(&url, &id_str) {
+            let path = url
+                .path_segments()
+                .map(|segments| segments.map(replace_str).collect::<Vec<_>>().join("/"));
+            if let Some(path) = path {
+                url.set_path(&path);
+            }
+            replaced = true;
+        }
+        if url_query_refers_to_id(&url, &id_str) {
+            let query_pairs = url
+                .query_pairs()
+                .map(|(k, v)| (k.into_owned(), replace_str(&v)))
+                .collect::<Vec<_>>();
+            url.query_pairs_mut().clear().extend_pairs(query_pairs);
+            replaced = true;
+        }
+        if replaced {
+            mutation.url = url.to_string();
+        }
+    }
+    if let Some(mut body) = mutation
+        .body
+        .as_ref()
+        .and_then(|body| serde_json::from_str::<Value>(body).ok())
+        .filter(|body| json_refers_to_id(body, id))
+    {
+        replace_json_id(&mut body, id, replacement);
+        mutation.body = Some(body.to_string());
+        replaced = true;
+    }
+    replaced
+}
+
+// Whether the path segments, the query values or the ID fields of the JSON body of the mutation
+// refer to `id`. The mutation is only read, so it's never considered a dependent because of how
+// it's encoded.
+fn refers_to_id(mutation: &QueuedMutation, id: i64) -> bool {
+    let id_str = id.to_string();
+    let url_refers_to_id = Url::parse(&mutation.url).is_ok_and(|url| {
+        url_path_refers_to_id(&url, &id_str) || url_query_refers_to_id(&url, &id_str)
+    });
+    url_refers_to_id
+        || mutation
+            .body
+            .as_ref()
+            .and_then(|body| serde_json::from_str::<Value>(body).ok())
+            .is_some_and(|body| json_refers_to_id(&body, id))
+}
+
+fn url_path_refers_to_id(url: &Url, id_str: &str) -> bool {
+    url.path_segments()
+        .is_some_and(|mut segments| segments.any(|segment| id_list_contains(segment, id_str)))
+}
+
+fn url_query_refers_to_id(url: &Url, id_str: &str) -> bool {
+    url.query_pairs()
+        .any(|(_, value)| id_list_contains(&value, id_str))
+}
+
+// IDs might be a comma separated list, i.e. `include=1,2`
+fn id_list_contains(s: &str, id_str: &str) -> bool {
+    s.split(',').any(|part| part == id_str)
+}
+
+fn json_refers_to_id(value: &Value, id: i64) -> bool {
+    match value {
+        Value::Array(values) => values.iter().any(|v| json_refers_to_id(v, id)),
+        Value::Object(map) => map.iter().any(|(key, v)| {
+            if JSON_ID_FIELDS.contains(&key.as_str()) {
+                json_id_value_is(v, id)
+            } else {
+                json_refers_to_id(v, id)
+            }
+        }),
+        _ => false,
+    }
+}
+
+fn json_id_value_is(value: &Value, id: i64) -> bool {
+    match value {
+        Value::Number(n) => n.as_i64() == Some(id),
+        Value::Array(values) => values.iter().any(|v| json_id_value_is(v, id)),
+        _ => false,
+    }
+}
+
+// Only the values of `JSON_ID_FIELDS` are replaced, so unrelated numbers that happen to be equal
+// to the temporary ID, such as `menu_order`, are kept.
+fn replace_json_id(value: &mut Value, id: i64, replacement: i64) {
+    match value {
+        Value::Array(values) => values
+            .iter_mut()
+            .for_each(|v| replace_json_id(v, id, replacement)),
+        Value::Object(map) => map.iter_mut().for_each(|(key, v)| {
+            if JSON_ID_FIELDS.contains(&key.as_str()) {
+                replace_json_id_value(v, id, replacement);
+            } else {
+                replace_json_id(v, id, replacement);
+            }
+        }),
+        _ => (),
+    }
+}
+
+fn replace_json_id_value(value: &mut Value, id: i64, replacement: i64) {
+    match value {
+        Value::Number(n) if n.as_i64() == Some(id) => *value = replacement.into(),
+        Value::Array(values) => values
+            .iter_mut()
+            .for_each(|v| replace_json_id_value(v, id, replacement)),
+        _ => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sync::InMemorySyncStore, RequestExecutionError};
+    use futures::executor::block_on;
+    use rstest::*;
+    use std::sync::Mutex;
+
+    // Responds with the queued results in order, and records the requests it executed
+    #[derive(Debug, Default)]
+    struct QueuedRequestExecutor {
+        results: Mutex<Vec<Result<WpNetworkResponse, RequestExecutionError>>>,
+        requests: Mutex<Vec<Arc<WpNetworkRequest>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl RequestExecutor for QueuedRequestExecutor {
+        async fn execute(
+            &self,
+            request: Arc<WpNetworkRequest>,
+        ) -> Result<WpNetworkResponse, RequestExecutionError> {
+            self.requests.lock().unwrap().push(request);
+            self.results.lock().unwrap().remove(0)
+        }
+    }
+
+    fn response(status_code: u16, body: &str) -> Result<WpNetworkResponse, RequestExecutionError> {
+        Ok(WpNetworkResponse {
+            body: body.as_bytes().to_vec(),
+            status_code,
+            header_map: WpNetworkHeaderMap::default().into(),
+        })
+    }
+
+    fn offline() -> Result<WpNetworkResponse, RequestExecutionError> {
+        Err(RequestExecutionError::RequestExecutionFailed {
+            status_code: None,
+            reason: "offline".to_string(),
+        })
+    }
+
+    fn request(method: RequestMethod, url: &str, body: Option<&str>) -> Arc<WpNetworkRequest> {
+        let mut header_map = http::HeaderMap::new();
+        insert_authorization_header(
+            &mut header_map,
+            &WpAuthentication::from_username_and_password("u".to_string(), "p".to_string()),
+        );
+        WpNetworkRequest {
+            method,
+            url: WpEndpointUrl(url.to_string()),
+            header_map: WpNetworkHeaderMap::new(header_map).into(),
+            body: body.map(|b| WpNetworkRequestBody::new(b.as_bytes().to_vec()).into()),
+            timeout_ms: None,
+        }
+        .into()
+    }
+
+    fn queue(
+        executor: &Arc<QueuedRequestExecutor>,
+        results: Vec<Result<WpNetworkResponse, RequestExecutionError>>,
+    ) -> MutationQueue {
+        *executor.results.lock().unwrap() = results;
+        MutationQueue::new(
+            Arc::new(InMemorySyncStore::new()),
+            WpAuthentication::from_username_and_password("u".to_string(), "p".to_string()),
+            executor.clone(),
+            3,
+        )
+    }
+
+    #[rstest]
+    #[case(
+        "https://example.com/wp-json/wp/v2/posts/-1",
+        "https://example.com/wp-json/wp/v2/posts/42"
+    )]
+    #[case(
+        "https://example.com/wp-json/wp/v2/posts?include=-1%2C5",
+        "https://example.com/wp-json/wp/v2/posts?include=42%2C5"
+    )]
+    #[case(
+        "https://example.com/wp-json/wp/v2/posts/-15",
+        "https://example.com/wp-json/wp/v2/posts/-15"
+    )]
+    #[case(
+        "https://example.com/wp-json/wp/v2/posts/-1?search=a+b&include=5,6",
+        "https://example.com/wp-json/wp/v2/posts/42?search=a+b&include=5,6"
+    )]
+    #[case(
+        "https://example.com/wp-json/wp/v2/posts?search=a+b&include=5,6",
+        "https://example.com/wp-json/wp/v2/posts?search=a+b&include=5,6"
+    )]
+    fn test_replace_id_in_url(#[case] url: &str, #[case] expected_url: &str) {
+        let queue = queue(&Default::default(), vec![]);
+        let mut mutation = queue
+            .enqueue(request(RequestMethod::POST, url, None), None)
+            .unwrap();
+        assert_eq!(replace_id(&mut mutation, -1, 42), url != expected_url);
+        assert_eq!(mutation.url, expected_url);
+    }
+
+    #[rstest]
+    fn test_replace_id_in_body() {
+        let queue = queue(&Default::default(), vec![]);
+        let mut mutation = queue
+            .enqueue(
+                request(
+                    RequestMethod::POST,
+                    "https://example.com/wp-json/wp/v2/posts/3",
+                    Some(r#"{"parent":-1,"tags":[-1,2],"title":"-1"}"#),
+                ),
+                None,
+            )
+            .unwrap();
+        assert!(replace_id(&mut mutation, -1, 42));
+        assert_eq!(
+            mutation.body,
+            Some(r#"{"parent":42,"tags":[42,2],"title":"-1"}"#.to_string())
+        );
+    }
+
+    #[rstest]
+    fn test_replace_id_in_body_keeps_non_id_fields() {
+        let queue = queue(&Default::default(), vec![]);
+        let body = r#"{"menu_order":-1,"meta":{"rating":-1},"sticky_order":[-1]}"#;
+        let mut mutation = queue
+            .enqueue(
+                request(
+                    RequestMethod::POST,
+                    "https://example.com/wp-json/wp/v2/posts/3",
+                    Some(body),
+                ),
+                None,
+            )
+            .unwrap();
+        assert!(!replace_id(&mut mutation, -1, 42));
+        assert_eq!(mutation.body, Some(body.to_string()));
+    }
+
+    #[rstest]
+    fn test_enqueue_does_not_persist_credentials() {
+        let queue = queue(&Default::default(), vec![]);
+        let mutation = queue
+            .enqueue(
+                request(RequestMethod::DELETE, "https://example.com/wp-json", None),
+                None,
+            )
+            .unwrap();
+        assert!(!mutation.headers.contains_key("authorization"));
+    }
+
+    #[rstest]
+    fn test_replay_stops_at_retryable_failure() {
+        let executor = Arc::new(QueuedRequestExecutor::default());
+        let queue = queue(&executor, vec![response(200, "{}"), offline()]);
+        for url in ["https://example.com/1", "https://example.com/2"] {
+            queue
+                .enqueue(request(RequestMethod::POST, url, Some("{}")), None)
+                .unwrap();
+        }
+        let summary = block_on(queue.replay()).unwrap();
+        assert_eq!(summary.replayed_count, 1);
+        assert_eq!(summary.remaining_count, 1);
+        assert!(summary.failures.is_empty());
+        let pending = queue.pending().unwrap();
+        assert_eq!(pending[0].url, "https://example.com/2");
+        assert_eq!(pending[0].attempt_count, 1);
+        // Credentials are added back when the mutation is replayed
+        assert!(executor.requests.lock().unwrap()[0]
+            .header_map
+            .as_header_map()
+            .contains_key(http::header::AUTHORIZATION));
+    }
+
+    #[rstest]
+    fn test_replay_drops_dependents_of_failed_create() {
+        let executor = Arc::new(QueuedRequestExecutor::default());
+        let queue = queue(
+            &executor,
+            vec![
+                response(400, r#"{"code":"rest_invalid_param","message":"Invalid"}"#),
+                response(200, "{}"),
+            ],
+        );
+        let mutations = [
+            (
+                "https://example.com/wp-json/wp/v2/posts",
+                Some(r#"{"title":"Parent"}"#),
+                Some(-1),
+            ),
+            (
+                "https://example.com/wp-json/wp/v2/posts",
+                Some(r#"{"parent":-1}"#),
+                Some(-2),
+            ),
+            ("https://example.com/wp-json/wp/v2/posts/-2", None, None),
+            (
+                "https://example.com/wp-json/wp/v2/posts/7",
+                Some(r#"{"menu_order":-1}"#),
+                None,
+            ),
+        ];
+        for (url, body, temporary_id) in mutations {
+            queue
+                .enqueue(request(RequestMethod::POST, url, body), temporary_id)
+                .unwrap();
+        }
+        let summary = block_on(queue.replay()).unwrap();
+        assert_eq!(summary.failures.len(), 1);
+        assert_eq!(
+            summary
+                .dropped_dependents
+                .iter()
+                .map(|m| (m.url.as_str(), m.temporary_id))
+                .collect::<Vec<_>>(),
+            vec![
+                ("https://example.com/wp-json/wp/v2/posts", Some(-2)),
+                ("https://example.com/wp-json/wp/v2/posts/-2", None)
+            ]
+        );
+        // The independent mutation is still replayed
+        assert_eq!(summary.replayed_count, 1);
+        assert_eq!(
+            executor.requests.lock().unwrap()[1].url.0,
+            "https://example.com/wp-json/wp/v2/posts/7"
+        );
+        assert!(queue.pending().unwrap().is_empty());
+    }
+
+    #[rstest]
+    fn test_replay_keeps_non_canonical_mutations_after_failed_create() {
+        let executor = Arc::new(QueuedRequestExecutor::default());
+        let queue = queue(
+            &executor,
+            vec![
+                response(400, r#"{"code":"rest_invalid_param","message":"Invalid"}"#),
+                response(200, "{}"),
+            ],
+        );
+        queue
+            .enqueue(
+                request(
+                    RequestMethod::POST,
+                    "https://example.com/wp-json/wp/v2/posts",
+                    Some(r#"{"title":"Parent"}"#),
+                ),
+                Some(-1),
+            )
+            .unwrap();
+        // Re-encoding either the URL or the body would change this mutation
+        let url = "https://example.com/wp-json/wp/v2/posts/7?search=caf%C3%A9+au+lait&include=5,6";
+        let body = "{\n  \"title\": \"Caf\\u00e9\",\n  \"parent\": 3\n}";
+        queue
+            .enqueue(request(RequestMethod::POST, url, Some(body)), None)
+            .unwrap();
+        let summary = block_on(queue.replay()).unwrap();
+        assert_eq!(summary.failures.len(), 1);
+        assert!(summary.dropped_dependents.is_empty());
+        assert_eq!(summary.replayed_count, 1);
+        let requests = executor.requests.lock().unwrap();
+        assert_eq!(requests[1].url.0, url);
+        assert_eq!(requests[1].body_as_string().unwrap(), body);
+    }
+
+    #[rstest]
+    fn test_replay_gives_up_after_max_attempts() {
+        let executor = Arc::new(QueuedRequestExecutor::default());
+        let queue = queue(&executor, vec![offline(), offline(), response(503, "")]);
+        queue
+            .enqueue(
+                request(RequestMethod::POST, "https://example.com/1", None),
+                None,
+            )
+            .unwrap();
+        for _ in 0..2 {
+            let summary = block_on(queue.replay()).unwrap();
+            assert_eq!(summary.remaining_count, 1);
+        }
+        let summary = block_on(queue.replay()).unwrap();
+        assert_eq!(summary.remaining_count, 0);
+        assert!(matches!(
+            summary.failures[0].error,
+            WpApiError::UnknownError {
+                status_code: 503,
+                ..
+            }
+        ));
+        assert!(queue.pending().unwrap().is_empty());
+    }
+}
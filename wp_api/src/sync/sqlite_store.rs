@@ -0,0 +1,252 @@
+use std::sync::Mutex;
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+use super::{MutationQueueStore, QueuedMutation, SyncRecord, SyncState, SyncStore, SyncStoreError};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS sync_state (
+    collection TEXT PRIMARY KEY NOT NULL,
+    generation INTEGER NOT NULL,
+    last_modified TEXT
+);
+CREATE TABLE IF NOT EXISTS sync_records (
+    collection TEXT NOT NULL,
+    id INTEGER NOT NULL,
+    modified TEXT NOT NULL,
+    json TEXT NOT NULL,
+    deleted INTEGER NOT NULL,
+    generation INTEGER NOT NULL,
+    PRIMARY KEY (collection, id)
+);
+CREATE INDEX IF NOT EXISTS sync_records_generation ON sync_records (collection, generation);
+CREATE TABLE IF NOT EXISTS sync_mutations (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    json TEXT NOT NULL
+);
+";
+
+/// A [`SyncStore`] backed by an embedded SQLite database.
+#[derive(Debug, uniffi::Object)]
+pub struct SqliteSyncStore {
+    connection: Mutex<Connection>,
+}
+
+#[uniffi::export]
+impl SqliteSyncStore {
+    /// Opens the database at `path`, creating it if it doesn't exist.
+    #[uniffi::constructor]
+    pub fn open(path: String) -> Result<Self, SyncStoreError> {
+        Self::from_connection(Connection::open(path).map_err(store_failed)?)
+    }
+
+    #[uniffi::constructor]
+    pub fn open_in_memory() -> Result<Self, SyncStoreError> {
+        Self::from_connection(Connection::open_in_memory().map_err(store_failed)?)
+    }
+}
+
+impl SqliteSyncStore {
+    fn from_connection(connection: Connection) -> Result<Self, SyncStoreError> {
+        connection.execute_batch(SCHEMA).map_err(store_failed)?;
+        Ok(Self {
+            connection: Mutex::new(connection),
+        })
+    }
+
+    fn with_connection<T>(
+        &self,
+        f: impl FnOnce(&mut Connection) -> rusqlite::Result<T>,
+    ) -> Result<T, SyncStoreError> {
+        let mut connection = self
+            .connection
+            .lock()
+            .map_err(|e| SyncStoreError::StoreFailed {
+                reason: e.to_string(),
+            })?;
+        f(&mut connection).map_err(store_failed)
+    }
+}
+
+impl SyncStore for SqliteSyncStore {
+    fn load_state(&self, collection: String) -> Result<Option<SyncState>, SyncStoreError> {
+        self.with_connection(|connection| {
+            connection
+                .query_row(
+                    "SELECT generation, last_modified FROM sync_state WHERE collection = ?1",
+                    params![collection],
+                    |row| {
+                        Ok(SyncState {
+                            generation: row.get(0)?,
+                            last_modified: row.get(1)?,
+                        })
+                    },
+                )
+                .optional()
+        })
+    }
+
+    fn save_state(&self, collection: String, state: SyncState) -> Result<(), SyncStoreError> {
+        self.with_connection(|connection| {
+            connection
+                .execute(
+                    "INSERT OR REPLACE INTO sync_state (collection, generation, last_modified)
+                    VALUES (?1, ?2, ?3)",
+                    params![collection, state.generation, state.last_modified],
+                )
+                .map(|_| ())
+        })
+    }
+
+    fn upsert_records(
+        &self,
+        collection: String,
+        records: Vec<SyncRecord>,
+    ) -> Result<(), SyncStoreError> {
+        self.with_connection(|connection| {
+            let transaction = connection.transaction()?;
+            {
+                let mut statement = transaction.prepare(
+                    "INSERT OR REPLACE INTO sync_records
+                    (collection, id, modified, json, deleted, generation)
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                )?;
+                for record in records {
+                    statement.execute(params![
+                        collection,
+                        record.id,
+                        record.modified,
+                        record.json,
+                        record.deleted,
+                        record.generation
+                    ])?;
+                }
+            }
+            transaction.commit()
+        })
+    }
+
+    fn record_ids(&self, collection: String) -> Result<Vec<i64>, SyncStoreError> {
+        self.with_connection(|connection| {
+            connection
+                .prepare(
+                    "SELECT id FROM sync_records WHERE collection = ?1 AND deleted = 0
+                    ORDER BY id",
+                )?
+                .query_map(params![collection], |row| row.get(0))?
+                .collect()
+        })
+    }
+
+    fn mark_deleted(
+        &self,
+        collection: String,
+        ids: Vec<i64>,
+        generation: u64,
+    ) -> Result<(), SyncStoreError> {
+        self.with_connection(|connection| {
+            let transaction = connection.transaction()?;
+            {
+                let mut statement = transaction.prepare(
+                    "UPDATE sync_records SET deleted = 1, generation = ?3
+                    WHERE collection = ?1 AND id = ?2",
+                )?;
+                for id in ids {
+                    statement.execute(params![collection, id, generation])?;
+                }
+            }
+            transaction.commit()
+        })
+    }
+
+    fn changed_since(
+        &self,
+        collection: String,
+        generation: u64,
+    ) -> Result<Vec<SyncRecord>, SyncStoreError> {
+        self.with_connection(|connection| {
+            connection
+                .prepare(
+                    "SELECT id, modified, json, deleted, generation FROM sync_records
+                    WHERE collection = ?1 AND generation > ?2 ORDER BY id",
+                )?
+                .query_map(params![collection, generation], |row| {
+                    Ok(SyncRecord {
+                        id: row.get(0)?,
+                        modified: row.get(1)?,
+                        json: row.get(2)?,
+                        deleted: row.get(3)?,
+                        generation: row.get(4)?,
+                    })
+                })?
+                .collect()
+        })
+    }
+}
+
+// Mutations are stored as JSON, with `id` as the column that keeps them in order
+impl MutationQueueStore for SqliteSyncStore {
+    fn push_mutation(&self, mutation: QueuedMutation) -> Result<QueuedMutation, SyncStoreError> {
+        let json = mutation_json(&mutation)?;
+        let id = self.with_connection(|connection| {
+            connection.execute(
+                "INSERT INTO sync_mutations (json) VALUES (?1)",
+                params![json],
+            )?;
+            Ok(connection.last_insert_rowid())
+        })?;
+        Ok(QueuedMutation { id, ..mutation })
+    }
+
+    fn pending_mutations(&self) -> Result<Vec<QueuedMutation>, SyncStoreError> {
+        let rows = self.with_connection(|connection| {
+            connection
+                .prepare("SELECT id, json FROM sync_mutations ORDER BY id")?
+                .query_map([], |row| {
+                    Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+                })?
+                .collect::<rusqlite::Result<Vec<_>>>()
+        })?;
+        rows.into_iter()
+            .map(|(id, json)| {
+                serde_json::from_str::<QueuedMutation>(&json)
+                    .map(|mutation| QueuedMutation { id, ..mutation })
+                    .map_err(|e| SyncStoreError::StoreFailed {
+                        reason: e.to_string(),
+                    })
+            })
+            .collect()
+    }
+
+    fn update_mutation(&self, mutation: QueuedMutation) -> Result<(), SyncStoreError> {
+        let json = mutation_json(&mutation)?;
+        self.with_connection(|connection| {
+            connection
+                .execute(
+                    "UPDATE sync_mutations SET json = ?2 WHERE id = ?1",
+                    params![mutation.id, json],
+                )
+                .map(|_| ())
+        })
+    }
+
+    fn remove_mutation(&self, id: i64) -> Result<(), SyncStoreError> {
+        self.with_connection(|connection| {
+            connection
+                .execute("DELETE FROM sync_mutations WHERE id = ?1", params![id])
+                .map(|_| ())
+        })
+    }
+}
+
+fn mutation_json(mutation: &QueuedMutation) -> Result<String, SyncStoreError> {
+    serde_json::to_string(mutation).map_err(|e| SyncStoreError::StoreFailed {
+        reason: e.to_string(),
+    })
+}
+
+fn store_failed(error: rusqlite::Error) -> SyncStoreError {
+    SyncStoreError::StoreFailed {
+        reason: error.to_string(),
+    }
+}
@@ -0,0 +1,396 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use wp_contextual::WpContextual;
+use wp_derive_request_builder::WpQueryParams;
+
+use crate::{
+    post_types::PostType,
+    posts::{PostId, PostStatus},
+    query_params::impl_query_param_value_via_as_str,
+    users::UserId,
+};
+
+#[derive(Debug, Default, PartialEq, Eq, uniffi::Record, WpQueryParams)]
+pub struct TemplateListParams {
+    /// Limit to the specified post id.
+    #[uniffi(default = None)]
+    pub wp_id: Option<PostId>,
+    /// Post type to get the templates for.
+    #[uniffi(default = None)]
+    pub post_type: Option<PostType>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, uniffi::Record, WpQueryParams)]
+pub struct TemplatePartListParams {
+    /// Limit to the specified post id.
+    #[uniffi(default = None)]
+    pub wp_id: Option<PostId>,
+    /// Limit to the specified template part area.
+    #[uniffi(default = None)]
+    pub area: Option<TemplatePartArea>,
+}
+
+/// Finds the template WordPress would fall back to for a template that doesn't exist yet, i.e.
+/// `single` for `single-post-hello-world`.
+#[derive(Debug, PartialEq, Eq, uniffi::Record)]
+pub struct TemplateLookupParams {
+    /// The slug of the template to get the fallback for.
+    pub slug: String,
+    /// Indicates if a template is custom or part of the template hierarchy.
+    #[uniffi(default = None)]
+    pub is_custom: Option<bool>,
+    /// The template prefix for the created template, used to extract the main template type,
+    /// i.e. in `taxonomy-books` the `taxonomy` is extracted.
+    #[uniffi(default = None)]
+    pub template_prefix: Option<String>,
+}
+
+impl TemplateLookupParams {
+    pub fn new(slug: String) -> Self {
+        Self {
+            slug,
+            is_custom: None,
+            template_prefix: None,
+        }
+    }
+
+    // `slug` is required, which `WpQueryParams` doesn't support
+    pub fn query_pairs(&self) -> impl IntoIterator<Item = (&str, String)> {
+        [
+            Some(("slug", self.slug.clone())),
+            self.is_custom.map(|v| ("is_custom", v.to_string())),
+            self.template_prefix
+                .as_ref()
+                .map(|v| ("template_prefix", v.clone())),
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+#[derive(Debug, Serialize, uniffi::Record)]
+pub struct TemplateCreateParams {
+    /// Unique slug identifying the template.
+    pub slug: String,
+    /// Theme identifier for the template.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme: Option<String>,
+    /// Content of template, in the block grammar.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Title of template.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Description of template.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Status of template.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<PostStatus>,
+    /// The ID for the author of the template.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<UserId>,
+    /// Where the template part is intended for use. Only used by template parts.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area: Option<TemplatePartArea>,
+}
+
+impl TemplateCreateParams {
+    pub fn new(slug: String) -> Self {
+        Self {
+            slug,
+            theme: None,
+            content: None,
+            title: None,
+            description: None,
+            status: None,
+            author: None,
+            area: None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct TemplateUpdateParams {
+    /// Unique slug identifying the template.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    /// Content of template, in the block grammar.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Title of template.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Description of template.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Status of template.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<PostStatus>,
+    /// The ID for the author of the template.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<UserId>,
+    /// Where the template part is intended for use. Only used by template parts.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area: Option<TemplatePartArea>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
+pub struct TemplateDeleteResponse {
+    pub deleted: bool,
+    pub previous: TemplateWithEditContext,
+}
+
+// Templates & template parts share the same fields, other than `is_custom` which is only
+// returned for templates & `area` which is only returned for template parts.
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseTemplate {
+    #[WpContext(edit, embed, view)]
+    pub id: Option<TemplateId>,
+    #[WpContext(edit, embed, view)]
+    pub slug: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub theme: Option<String>,
+    #[serde(rename = "type")]
+    #[WpContext(edit, embed, view)]
+    pub template_type: Option<PostType>,
+    #[WpContext(edit, embed, view)]
+    pub source: Option<TemplateSource>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub origin: Option<TemplateSource>,
+    // `view` & `embed` contexts return an empty `content` object, so it's left out
+    #[WpContext(edit)]
+    #[WpContextualField]
+    pub content: Option<SparseTemplateContent>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualField]
+    pub title: Option<SparseTemplateTitle>,
+    #[WpContext(edit, embed, view)]
+    pub description: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub status: Option<PostStatus>,
+    /// The ID of the post that stores the template, if it's not only a theme file.
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub wp_id: Option<PostId>,
+    #[WpContext(edit, embed, view)]
+    pub has_theme_file: Option<bool>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub author: Option<UserId>,
+    #[WpContext(edit, view)]
+    #[WpContextualOption]
+    pub modified: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub author_text: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub original_source: Option<TemplateOriginalSource>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub is_custom: Option<bool>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub area: Option<TemplatePartArea>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseTemplateContent {
+    #[WpContext(edit)]
+    pub raw: Option<String>,
+    #[WpContext(edit)]
+    pub block_version: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseTemplateTitle {
+    #[WpContext(edit, embed, view)]
+    pub raw: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub rendered: Option<String>,
+}
+
+/// The ID of a template, in the `theme//slug` format, i.e. `twentytwentyfour//home`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, uniffi::Record)]
+#[serde(transparent)]
+pub struct TemplateId {
+    pub id: String,
+}
+
+impl TemplateId {
+    pub fn new(id: String) -> Self {
+        Self { id }
+    }
+
+    pub fn from_theme_and_slug(theme: &str, slug: &str) -> Self {
+        Self {
+            id: format!("{}//{}", theme, slug),
+        }
+    }
+
+    pub fn theme(&self) -> Option<&str> {
+        self.id.split_once("//").map(|(theme, _)| theme)
+    }
+
+    pub fn slug(&self) -> &str {
+        self.id
+            .split_once("//")
+            .map(|(_, slug)| slug)
+            .unwrap_or(&self.id)
+    }
+}
+
+impl From<&str> for TemplateId {
+    fn from(value: &str) -> Self {
+        Self {
+            id: value.to_string(),
+        }
+    }
+}
+
+impl Display for TemplateId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id)
+    }
+}
+
+/// Where a template comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, uniffi::Enum)]
+#[serde(rename_all = "snake_case")]
+pub enum TemplateSource {
+    /// A file of the theme.
+    Theme,
+    /// A plugin that registered the template.
+    Plugin,
+    /// A post, created or customized by a user.
+    Custom,
+}
+
+/// Where a template originally comes from, to show in the site editor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, uniffi::Enum)]
+#[serde(rename_all = "snake_case")]
+pub enum TemplateOriginalSource {
+    Theme,
+    Plugin,
+    Site,
+    User,
+}
+
+/// Where a template part is intended for use. Themes & plugins can register their own areas.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Enum)]
+#[serde(rename_all = "snake_case")]
+pub enum TemplatePartArea {
+    Header,
+    Footer,
+    Uncategorized,
+    #[serde(untagged)]
+    Custom(String),
+}
+
+impl TemplatePartArea {
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Header => "header",
+            Self::Footer => "footer",
+            Self::Uncategorized => "uncategorized",
+            Self::Custom(area) => area.as_str(),
+        }
+    }
+}
+
+impl_query_param_value_via_as_str!(TemplatePartArea, [Header, Footer, Uncategorized], Custom);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, unit_test_common::assert_expected_query_pairs};
+    use rstest::*;
+
+    #[rstest]
+    #[case(TemplateListParams::default(), &[])]
+    #[case(generate!(TemplateListParams, (wp_id, Some(PostId(3)))), &[("wp_id", "3")])]
+    #[case(generate!(TemplateListParams, (post_type, Some(PostType::Page))), &[("post_type", "page")])]
+    #[trace]
+    fn test_template_list_params(
+        #[case] params: TemplateListParams,
+        #[case] expected_pairs: &[(&str, &str)],
+    ) {
+        assert_expected_query_pairs(params.query_pairs(), expected_pairs);
+    }
+
+    #[rstest]
+    #[case(TemplatePartListParams::default(), &[])]
+    #[case(generate!(TemplatePartListParams, (area, Some(TemplatePartArea::Header))), &[("area", "header")])]
+    #[case(generate!(TemplatePartListParams, (area, Some(TemplatePartArea::Custom("menu".to_string())))), &[("area", "menu")])]
+    #[trace]
+    fn test_template_part_list_params(
+        #[case] params: TemplatePartListParams,
+        #[case] expected_pairs: &[(&str, &str)],
+    ) {
+        assert_expected_query_pairs(params.query_pairs(), expected_pairs);
+    }
+
+    #[rstest]
+    #[case(TemplateLookupParams::new("single-post".to_string()), &[("slug", "single-post")])]
+    #[case(
+        TemplateLookupParams { slug: "taxonomy-books".to_string(), is_custom: Some(false), template_prefix: Some("taxonomy".to_string()) },
+        &[("slug", "taxonomy-books"), ("is_custom", "false"), ("template_prefix", "taxonomy")]
+    )]
+    #[trace]
+    fn test_template_lookup_params(
+        #[case] params: TemplateLookupParams,
+        #[case] expected_pairs: &[(&str, &str)],
+    ) {
+        assert_expected_query_pairs(params.query_pairs(), expected_pairs);
+    }
+
+    #[rstest]
+    #[case("twentytwentyfour//home", Some("twentytwentyfour"), "home")]
+    #[case("home", None, "home")]
+    fn test_template_id(
+        #[case] id: &str,
+        #[case] expected_theme: Option<&str>,
+        #[case] expected_slug: &str,
+    ) {
+        let id = TemplateId::from(id);
+        assert_eq!(id.theme(), expected_theme);
+        assert_eq!(id.slug(), expected_slug);
+    }
+
+    #[test]
+    fn template_id_from_theme_and_slug() {
+        assert_eq!(
+            TemplateId::from_theme_and_slug("twentytwentyfour", "home"),
+            TemplateId::from("twentytwentyfour//home")
+        );
+    }
+
+    #[rstest]
+    #[case(TemplatePartArea::Header, "\"header\"")]
+    #[case(TemplatePartArea::Custom("menu".to_string()), "\"menu\"")]
+    fn test_template_part_area_serialization(
+        #[case] area: TemplatePartArea,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(serde_json::to_string(&area).unwrap(), expected);
+        assert_eq!(
+            serde_json::from_str::<TemplatePartArea>(expected).unwrap(),
+            area
+        );
+    }
+}
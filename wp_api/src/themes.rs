@@ -0,0 +1,267 @@
+use std::{collections::HashMap, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use wp_contextual::WpContextual;
+use wp_derive_request_builder::WpQueryParams;
+
+use crate::{posts::PostFormat, query_params::impl_query_param_value_via_as_str, JsonValue};
+
+#[derive(Debug, Default, PartialEq, Eq, uniffi::Record, WpQueryParams)]
+pub struct ThemeListParams {
+    /// Limit results to themes with one or more statuses.
+    #[uniffi(default = [])]
+    pub status: Vec<ThemeStatus>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseTheme {
+    #[WpContext(edit, embed, view)]
+    pub stylesheet: Option<ThemeStylesheet>,
+    #[WpContext(edit, embed, view)]
+    pub stylesheet_uri: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub template: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub template_uri: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub status: Option<ThemeStatus>,
+    #[WpContext(edit, embed, view)]
+    pub name: Option<ThemeRenderedField>,
+    #[WpContext(edit, embed, view)]
+    pub description: Option<ThemeRenderedField>,
+    #[WpContext(edit, embed, view)]
+    pub author: Option<ThemeRenderedField>,
+    #[WpContext(edit, embed, view)]
+    pub author_uri: Option<ThemeRenderedField>,
+    #[WpContext(edit, embed, view)]
+    pub theme_uri: Option<ThemeRenderedField>,
+    #[WpContext(edit, embed, view)]
+    pub tags: Option<ThemeTags>,
+    #[WpContext(edit, embed, view)]
+    pub version: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub requires_wp: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub requires_php: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub textdomain: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub screenshot: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub is_block_theme: Option<bool>,
+    /// The features the theme supports, keyed by the name of the feature, i.e. `post-formats`.
+    /// WordPress only includes them for the active theme, and they can be inspected with
+    /// `ThemeSupports`.
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub theme_supports: Option<HashMap<String, JsonValue>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, uniffi::Record)]
+#[serde(transparent)]
+pub struct ThemeStylesheet {
+    pub stylesheet: String,
+}
+
+impl ThemeStylesheet {
+    pub fn new(stylesheet: String) -> Self {
+        Self { stylesheet }
+    }
+}
+
+impl From<&str> for ThemeStylesheet {
+    fn from(value: &str) -> Self {
+        Self {
+            stylesheet: value.to_string(),
+        }
+    }
+}
+
+impl Display for ThemeStylesheet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.stylesheet)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, uniffi::Enum)]
+pub enum ThemeStatus {
+    #[serde(rename = "active")]
+    Active,
+    #[serde(rename = "inactive")]
+    Inactive,
+}
+
+impl ThemeStatus {
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Active => "active",
+            Self::Inactive => "inactive",
+        }
+    }
+}
+
+impl_query_param_value_via_as_str!(ThemeStatus, [Active, Inactive]);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+pub struct ThemeRenderedField {
+    pub raw: String,
+    pub rendered: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+pub struct ThemeTags {
+    pub raw: Vec<String>,
+    pub rendered: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+pub struct ThemeColor {
+    pub name: String,
+    pub slug: String,
+    pub color: String,
+}
+
+/// Answers whether the active theme of a site supports a feature, so the UI can adapt to it.
+///
+/// Most features are either a boolean or a list of options, where `false` means that the
+/// theme doesn't support the feature.
+#[derive(Debug, Default, Clone, PartialEq, uniffi::Object)]
+pub struct ThemeSupports {
+    features: HashMap<String, Value>,
+}
+
+#[uniffi::export]
+impl ThemeSupports {
+    #[uniffi::constructor]
+    pub fn new(theme_supports: HashMap<String, JsonValue>) -> Self {
+        Self {
+            features: theme_supports
+                .into_iter()
+                .map(|(feature, value)| (feature, value.0))
+                .collect(),
+        }
+    }
+
+    /// Whether the theme supports the given feature, i.e. `align-wide`.
+    pub fn supports(&self, feature: &str) -> bool {
+        match self.features.get(feature) {
+            None | Some(Value::Null) | Some(Value::Bool(false)) => false,
+            Some(Value::Array(options)) => !options.is_empty(),
+            Some(_) => true,
+        }
+    }
+
+    /// The post formats the theme supports. `standard` is always included.
+    pub fn post_formats(&self) -> Vec<PostFormat> {
+        self.features
+            .get("formats")
+            .and_then(|formats| serde_json::from_value(formats.clone()).ok())
+            .unwrap_or_else(|| vec![PostFormat::Standard])
+    }
+
+    /// Whether the theme supports any post format other than `standard`.
+    pub fn supports_post_formats(&self) -> bool {
+        self.post_formats()
+            .iter()
+            .any(|format| *format != PostFormat::Standard)
+    }
+
+    /// The colors the theme adds to the editor, if it has a custom palette.
+    pub fn editor_color_palette(&self) -> Vec<ThemeColor> {
+        self.features
+            .get("editor-color-palette")
+            .and_then(|palette| serde_json::from_value(palette.clone()).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn supports_editor_color_palette(&self) -> bool {
+        !self.editor_color_palette().is_empty()
+    }
+
+    /// Whether templates can be edited with blocks.
+    pub fn supports_block_templates(&self) -> bool {
+        self.supports("block-templates")
+    }
+}
+
+impl From<&HashMap<String, JsonValue>> for ThemeSupports {
+    fn from(value: &HashMap<String, JsonValue>) -> Self {
+        Self::new(value.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, unit_test_common::assert_expected_query_pairs};
+    use rstest::*;
+    use serde_json::json;
+
+    #[rstest]
+    #[case(ThemeListParams::default(), &[])]
+    #[case(generate!(ThemeListParams, (status, vec![ThemeStatus::Active])), &[("status", "active")])]
+    #[case(generate!(ThemeListParams, (status, vec![ThemeStatus::Active, ThemeStatus::Inactive])), &[("status", "active,inactive")])]
+    #[trace]
+    fn test_theme_list_params(
+        #[case] params: ThemeListParams,
+        #[case] expected_pairs: &[(&str, &str)],
+    ) {
+        assert_expected_query_pairs(params.query_pairs(), expected_pairs);
+    }
+
+    fn theme_supports(value: serde_json::Value) -> ThemeSupports {
+        ThemeSupports::new(serde_json::from_value(value).unwrap())
+    }
+
+    #[test]
+    fn theme_supports_post_formats() {
+        let supports = theme_supports(json!({ "formats": ["standard", "aside", "gallery"] }));
+        assert!(supports.supports_post_formats());
+        assert_eq!(
+            supports.post_formats(),
+            vec![PostFormat::Standard, PostFormat::Aside, PostFormat::Gallery]
+        );
+
+        let supports = theme_supports(json!({ "formats": ["standard"] }));
+        assert!(!supports.supports_post_formats());
+        assert!(!theme_supports(json!({})).supports_post_formats());
+    }
+
+    #[test]
+    fn theme_supports_editor_color_palette() {
+        let supports = theme_supports(json!({
+            "editor-color-palette": [{ "name": "Black", "slug": "black", "color": "#000000" }]
+        }));
+        assert!(supports.supports_editor_color_palette());
+        assert_eq!(
+            supports.editor_color_palette(),
+            vec![ThemeColor {
+                name: "Black".to_string(),
+                slug: "black".to_string(),
+                color: "#000000".to_string(),
+            }]
+        );
+        assert!(!theme_supports(json!({ "editor-color-palette": false }))
+            .supports_editor_color_palette());
+    }
+
+    #[rstest]
+    #[case(json!({ "block-templates": true }), true)]
+    #[case(json!({ "block-templates": false }), false)]
+    #[case(json!({}), false)]
+    fn theme_supports_block_templates(#[case] value: serde_json::Value, #[case] expected: bool) {
+        assert_eq!(theme_supports(value).supports_block_templates(), expected);
+    }
+
+    #[rstest]
+    #[case(json!({ "align-wide": true }), true)]
+    #[case(json!({ "html5": ["gallery", "caption"] }), true)]
+    #[case(json!({ "custom-logo": { "width": 100 } }), true)]
+    #[case(json!({ "html5": [] }), false)]
+    #[case(json!({ "align-wide": false }), false)]
+    fn theme_supports_feature(#[case] value: serde_json::Value, #[case] expected: bool) {
+        let feature = value.as_object().unwrap().keys().next().unwrap().clone();
+        assert_eq!(theme_supports(value).supports(&feature), expected);
+    }
+}
@@ -3,7 +3,14 @@ use std::{collections::HashMap, fmt::Display};
 use serde::{Deserialize, Serialize};
 use wp_contextual::WpContextual;
 
-use crate::WpApiParamOrder;
+use wp_derive_request_builder::WpQueryParams;
+
+use crate::{
+    query_params::{
+        impl_query_param_value_for_newtype, impl_query_param_value_via_as_str, QueryParamValue,
+    },
+    WpApiParamOrder,
+};
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
 pub enum WpApiParamUsersOrderBy {
@@ -33,6 +40,20 @@ impl WpApiParamUsersOrderBy {
     }
 }
 
+impl_query_param_value_via_as_str!(
+    WpApiParamUsersOrderBy,
+    [
+        Id,
+        Include,
+        Name,
+        RegisteredDate,
+        Slug,
+        IncludeSlugs,
+        Email,
+        Url
+    ]
+);
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
 pub enum WpApiParamUsersWho {
     #[default]
@@ -50,6 +71,16 @@ impl WpApiParamUsersWho {
     }
 }
 
+impl QueryParamValue for WpApiParamUsersWho {
+    fn to_query_value(&self) -> Option<String> {
+        self.as_str().map(|s| s.to_string())
+    }
+
+    fn from_query_value(value: &str) -> Option<Self> {
+        (value == "authors").then_some(Self::Authors)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, uniffi::Enum)]
 pub enum WpApiParamUsersHasPublishedPosts {
     True,
@@ -71,7 +102,23 @@ impl Display for WpApiParamUsersHasPublishedPosts {
     }
 }
 
-#[derive(Debug, Default, uniffi::Record)]
+impl QueryParamValue for WpApiParamUsersHasPublishedPosts {
+    fn to_query_value(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+
+    fn from_query_value(value: &str) -> Option<Self> {
+        match value {
+            "true" => Some(Self::True),
+            "false" => Some(Self::False),
+            _ => Some(Self::PostTypes(
+                value.split(',').map(|s| s.trim().to_string()).collect(),
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, uniffi::Record, WpQueryParams)]
 pub struct UserListParams {
     /// Current page of the collection.
     /// Default: `1`
@@ -121,62 +168,6 @@ pub struct UserListParams {
     pub has_published_posts: Option<WpApiParamUsersHasPublishedPosts>,
 }
 
-impl UserListParams {
-    pub fn query_pairs(&self) -> impl IntoIterator<Item = (&str, String)> {
-        [
-            ("page", self.page.map(|x| x.to_string())),
-            ("per_page", self.per_page.map(|x| x.to_string())),
-            ("search", self.search.clone()),
-            (
-                "exclude",
-                (!self.exclude.is_empty()).then_some(
-                    self.exclude
-                        .iter()
-                        .map(|x| x.to_string())
-                        .collect::<Vec<String>>()
-                        .join(","),
-                ),
-            ),
-            (
-                "include",
-                (!self.include.is_empty()).then_some(
-                    self.include
-                        .iter()
-                        .map(|x| x.to_string())
-                        .collect::<Vec<String>>()
-                        .join(","),
-                ),
-            ),
-            ("offset", self.offset.map(|x| x.to_string())),
-            ("order", self.order.map(|x| x.as_str().to_string())),
-            ("orderby", self.orderby.map(|x| x.as_str().to_string())),
-            (
-                "slug",
-                (!self.slug.is_empty()).then_some(self.slug.join(",")),
-            ),
-            (
-                "roles",
-                (!self.roles.is_empty()).then_some(self.roles.join(",")),
-            ),
-            (
-                "capabilities",
-                (!self.capabilities.is_empty()).then_some(self.capabilities.join(",")),
-            ),
-            (
-                "who",
-                self.who.and_then(|x| x.as_str().map(|s| s.to_string())),
-            ),
-            (
-                "has_published_posts",
-                self.has_published_posts.as_ref().map(|x| x.to_string()),
-            ),
-        ]
-        .into_iter()
-        // Remove `None` values
-        .filter_map(|(k, opt_v)| opt_v.map(|v| (k, v)))
-    }
-}
-
 #[derive(Debug, Serialize, uniffi::Record)]
 pub struct UserCreateParams {
     /// Login name for the user.
@@ -341,6 +332,8 @@ impl std::fmt::Display for UserId {
     }
 }
 
+impl_query_param_value_for_newtype!(UserId);
+
 #[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
 pub struct SparseUser {
     #[WpContext(edit, embed, view)]
@@ -416,6 +409,30 @@ mod tests {
         assert_expected_query_pairs(params.query_pairs(), expected_pairs);
     }
 
+    #[rstest]
+    #[case("", UserListParams::default())]
+    #[case("page=2&per_page=5", generate!(UserListParams, (page, Some(2)), (per_page, Some(5))))]
+    #[case("exclude=1,2&include=3", generate!(UserListParams, (exclude, vec![UserId(1), UserId(2)]), (include, vec![UserId(3)])))]
+    #[case("order=desc&orderby=registered_date", generate!(UserListParams, (order, Some(WpApiParamOrder::Desc)), (orderby, Some(WpApiParamUsersOrderBy::RegisteredDate))))]
+    #[case("who=authors", generate!(UserListParams, (who, Some(WpApiParamUsersWho::Authors))))]
+    #[case("has_published_posts=post,page", generate!(UserListParams, (has_published_posts, Some(WpApiParamUsersHasPublishedPosts::PostTypes(vec!["post".to_string(), "page".to_string()])))))]
+    #[case::unknown_values_are_ignored("orderby=foo&bar=baz", UserListParams::default())]
+    #[trace]
+    fn test_user_list_params_from_query_pairs(
+        #[case] query: &str,
+        #[case] expected_params: UserListParams,
+    ) {
+        let url = url::Url::parse(&format!(
+            "https://example.com/wp-json/wp/v2/users?{}",
+            query
+        ))
+        .unwrap();
+        assert_eq!(
+            UserListParams::from_query_pairs(url.query_pairs()),
+            expected_params
+        );
+    }
+
     #[test]
     fn test_user_delete_params() {
         let params = UserDeleteParams::new(UserId(987));
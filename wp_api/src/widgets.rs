@@ -0,0 +1,357 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use wp_contextual::WpContextual;
+use wp_derive_request_builder::WpQueryParams;
+
+use crate::{query_params::QueryParamValue, JsonValue};
+
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct SidebarUpdateParams {
+    /// The widgets of the sidebar, in the order they're displayed in.
+    ///
+    /// Widgets that are left out are moved to the inactive widgets, so this is how widgets are
+    /// reordered within a sidebar.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub widgets: Option<Vec<WidgetId>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseSidebar {
+    #[WpContext(edit, embed, view)]
+    pub id: Option<SidebarId>,
+    #[WpContext(edit, embed, view)]
+    pub name: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub description: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub class: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub before_widget: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub after_widget: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub before_title: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub after_title: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub status: Option<SidebarStatus>,
+    #[WpContext(edit, embed, view)]
+    pub widgets: Option<Vec<WidgetId>>,
+}
+
+/// The ID of a sidebar registered by the theme, i.e. `sidebar-1`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, uniffi::Record)]
+#[serde(transparent)]
+pub struct SidebarId {
+    pub id: String,
+}
+
+impl SidebarId {
+    pub fn new(id: String) -> Self {
+        Self { id }
+    }
+
+    /// The sidebar that holds the widgets that aren't shown in any of the theme's sidebars.
+    pub fn inactive_widgets() -> Self {
+        Self::from("wp_inactive_widgets")
+    }
+}
+
+impl From<&str> for SidebarId {
+    fn from(value: &str) -> Self {
+        Self {
+            id: value.to_string(),
+        }
+    }
+}
+
+impl Display for SidebarId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id)
+    }
+}
+
+impl QueryParamValue for SidebarId {
+    fn to_query_value(&self) -> Option<String> {
+        Some(self.id.clone())
+    }
+
+    fn from_query_value(value: &str) -> Option<Self> {
+        Some(Self::from(value))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, uniffi::Enum)]
+#[serde(rename_all = "snake_case")]
+pub enum SidebarStatus {
+    /// The sidebar is registered by the active theme.
+    Active,
+    /// The sidebar only holds widgets that aren't shown, such as `wp_inactive_widgets`.
+    Inactive,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, uniffi::Record, WpQueryParams)]
+pub struct WidgetListParams {
+    /// The sidebar to return widgets for.
+    #[uniffi(default = None)]
+    pub sidebar: Option<SidebarId>,
+}
+
+#[derive(Debug, Serialize, uniffi::Record)]
+pub struct WidgetCreateParams {
+    /// The type of the widget, i.e. `block`.
+    pub id_base: WidgetTypeId,
+    /// The sidebar the widget is added to, as its last widget.
+    pub sidebar: SidebarId,
+    /// The settings of the widget.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<WidgetInstance>,
+    /// URL-encoded form data from the widget admin form, for widgets that don't support
+    /// `instance`.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_data: Option<String>,
+}
+
+impl WidgetCreateParams {
+    pub fn new(id_base: WidgetTypeId, sidebar: SidebarId) -> Self {
+        Self {
+            id_base,
+            sidebar,
+            instance: None,
+            form_data: None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct WidgetUpdateParams {
+    /// Moves the widget to the end of the given sidebar.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sidebar: Option<SidebarId>,
+    /// The settings of the widget.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<WidgetInstance>,
+    /// URL-encoded form data from the widget admin form, for widgets that don't support
+    /// `instance`.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_data: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
+pub struct WidgetDeleteResponse {
+    pub deleted: bool,
+    pub previous: WidgetWithEditContext,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseWidget {
+    #[WpContext(edit, embed, view)]
+    pub id: Option<WidgetId>,
+    #[WpContext(edit, embed, view)]
+    pub id_base: Option<WidgetTypeId>,
+    #[WpContext(edit, embed, view)]
+    pub sidebar: Option<SidebarId>,
+    #[WpContext(edit, embed, view)]
+    pub rendered: Option<String>,
+    /// The HTML of the widget's admin form, or `None` if the widget isn't registered.
+    #[WpContext(edit)]
+    #[WpContextualOption]
+    pub rendered_form: Option<String>,
+    /// `None` if the widget isn't registered.
+    #[WpContext(edit)]
+    #[WpContextualOption]
+    pub instance: Option<WidgetInstance>,
+}
+
+/// The settings of a widget.
+///
+/// WordPress returns them encoded, along with a hash to verify them, & only includes the `raw`
+/// settings for widget types that allow it, such as the `block` widget. To update a widget,
+/// either `raw` or `encoded` & `hash` can be sent.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, uniffi::Record)]
+pub struct WidgetInstance {
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoded: Option<String>,
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw: Option<JsonValue>,
+}
+
+impl WidgetInstance {
+    pub fn raw(raw: JsonValue) -> Self {
+        Self {
+            encoded: None,
+            hash: None,
+            raw: Some(raw),
+        }
+    }
+}
+
+/// The ID of a widget, made of its type & number, i.e. `block-2`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, uniffi::Record)]
+#[serde(transparent)]
+pub struct WidgetId {
+    pub id: String,
+}
+
+impl WidgetId {
+    pub fn new(id: String) -> Self {
+        Self { id }
+    }
+}
+
+impl From<&str> for WidgetId {
+    fn from(value: &str) -> Self {
+        Self {
+            id: value.to_string(),
+        }
+    }
+}
+
+impl Display for WidgetId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseWidgetType {
+    #[WpContext(edit, embed, view)]
+    pub id: Option<WidgetTypeId>,
+    #[WpContext(edit, embed, view)]
+    pub name: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub description: Option<String>,
+    /// Whether the widget type supports multiple instances.
+    #[WpContext(edit, embed, view)]
+    pub is_multi: Option<bool>,
+    #[WpContext(edit, embed, view)]
+    pub classname: Option<String>,
+}
+
+/// The ID of a widget type, i.e. `block` or `search`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, uniffi::Record)]
+#[serde(transparent)]
+pub struct WidgetTypeId {
+    pub id: String,
+}
+
+impl WidgetTypeId {
+    pub fn new(id: String) -> Self {
+        Self { id }
+    }
+}
+
+impl From<&str> for WidgetTypeId {
+    fn from(value: &str) -> Self {
+        Self {
+            id: value.to_string(),
+        }
+    }
+}
+
+impl Display for WidgetTypeId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id)
+    }
+}
+
+/// Converts the admin form data of a widget to an `instance`, & renders the widget's form &
+/// preview for it.
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct WidgetTypeEncodeParams {
+    /// The number of the widget, for widget types that support multiple instances.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<u32>,
+    /// The current settings of the widget.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<WidgetInstance>,
+    /// URL-encoded form data to apply to the current settings.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_data: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
+pub struct WidgetTypeEncodeResponse {
+    pub form: String,
+    pub preview: String,
+    pub instance: WidgetInstance,
+}
+
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct WidgetTypeRenderParams {
+    /// The settings of the widget to render.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<WidgetInstance>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
+pub struct WidgetTypeRenderResponse {
+    pub preview: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, unit_test_common::assert_expected_query_pairs};
+    use rstest::*;
+    use serde_json::json;
+
+    #[rstest]
+    #[case(WidgetListParams::default(), &[])]
+    #[case(generate!(WidgetListParams, (sidebar, Some(SidebarId::from("sidebar-1")))), &[("sidebar", "sidebar-1")])]
+    #[trace]
+    fn test_widget_list_params(
+        #[case] params: WidgetListParams,
+        #[case] expected_pairs: &[(&str, &str)],
+    ) {
+        assert_expected_query_pairs(params.query_pairs(), expected_pairs);
+    }
+
+    #[test]
+    fn widget_update_params_only_include_given_fields() {
+        let params = WidgetUpdateParams {
+            sidebar: Some(SidebarId::inactive_widgets()),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            json!({ "sidebar": "wp_inactive_widgets" })
+        );
+    }
+
+    #[test]
+    fn widget_create_params_with_raw_instance() {
+        let params = WidgetCreateParams {
+            instance: Some(WidgetInstance::raw(JsonValue(
+                json!({ "content": "<!-- wp:paragraph --><p>Hi</p><!-- /wp:paragraph -->" }),
+            ))),
+            ..WidgetCreateParams::new(WidgetTypeId::from("block"), SidebarId::from("sidebar-1"))
+        };
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            json!({
+                "id_base": "block",
+                "sidebar": "sidebar-1",
+                "instance": {
+                    "raw": { "content": "<!-- wp:paragraph --><p>Hi</p><!-- /wp:paragraph -->" }
+                }
+            })
+        );
+    }
+}
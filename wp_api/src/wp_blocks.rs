@@ -0,0 +1,403 @@
+use serde::{Deserialize, Serialize, Serializer};
+use wp_contextual::WpContextual;
+use wp_derive_request_builder::WpQueryParams;
+
+use crate::{
+    posts::{PostId, PostStatus, WpApiParamPostsOrderBy},
+    query_params::impl_query_param_value_for_newtype,
+    WpApiParamOrder,
+};
+
+#[derive(Debug, Default, PartialEq, Eq, uniffi::Record, WpQueryParams)]
+pub struct WpBlockListParams {
+    /// Current page of the collection.
+    /// Default: `1`
+    #[uniffi(default = None)]
+    pub page: Option<u32>,
+    /// Maximum number of items to be returned in result set.
+    /// Default: `10`
+    #[uniffi(default = None)]
+    pub per_page: Option<u32>,
+    /// Limit results to those matching a string.
+    #[uniffi(default = None)]
+    pub search: Option<String>,
+    /// Ensure result set excludes specific IDs.
+    #[uniffi(default = [])]
+    pub exclude: Vec<PostId>,
+    /// Limit result set to specific IDs.
+    #[uniffi(default = [])]
+    pub include: Vec<PostId>,
+    /// Order sort attribute ascending or descending.
+    /// Default: desc
+    /// One of: asc, desc
+    #[uniffi(default = None)]
+    pub order: Option<WpApiParamOrder>,
+    /// Sort collection by post attribute.
+    /// Default: date
+    #[uniffi(default = None)]
+    pub orderby: Option<WpApiParamPostsOrderBy>,
+    /// Limit result set to blocks with one or more specific slugs.
+    #[uniffi(default = [])]
+    pub slug: Vec<String>,
+    /// Limit result set to blocks assigned one or more statuses.
+    /// Default: publish
+    #[uniffi(default = [])]
+    pub status: Vec<PostStatus>,
+    /// Limit result set to blocks assigned to specific pattern categories.
+    #[uniffi(default = [])]
+    pub wp_pattern_category: Vec<WpPatternCategoryId>,
+}
+
+#[derive(Debug, Serialize, uniffi::Record)]
+pub struct WpBlockCreateParams {
+    /// The title for the block.
+    pub title: String,
+    /// The content of the block, in the block grammar.
+    pub content: String,
+    /// A named status for the block.
+    /// One of: publish, future, draft, pending, private
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<PostStatus>,
+    /// An alphanumeric identifier for the block unique to its type.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    /// The excerpt for the block, which is shown as the description of the pattern.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excerpt: Option<String>,
+    /// Whether the instances of the pattern are kept in sync with it. It's sent as a meta field.
+    #[uniffi(default = None)]
+    #[serde(
+        rename = "meta",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_as_block_meta"
+    )]
+    pub wp_pattern_sync_status: Option<WpPatternSyncStatus>,
+    /// The terms assigned to the block in the wp_pattern_category taxonomy.
+    #[uniffi(default = [])]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub wp_pattern_category: Vec<WpPatternCategoryId>,
+}
+
+impl WpBlockCreateParams {
+    pub fn new(title: String, content: String) -> Self {
+        Self {
+            title,
+            content,
+            status: None,
+            slug: None,
+            excerpt: None,
+            wp_pattern_sync_status: None,
+            wp_pattern_category: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct WpBlockUpdateParams {
+    /// The title for the block.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The content of the block, in the block grammar.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// A named status for the block.
+    /// One of: publish, future, draft, pending, private
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<PostStatus>,
+    /// An alphanumeric identifier for the block unique to its type.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    /// The excerpt for the block, which is shown as the description of the pattern.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excerpt: Option<String>,
+    /// Whether the instances of the pattern are kept in sync with it. It's sent as a meta field.
+    #[uniffi(default = None)]
+    #[serde(
+        rename = "meta",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_as_block_meta"
+    )]
+    pub wp_pattern_sync_status: Option<WpPatternSyncStatus>,
+    /// The terms assigned to the block in the wp_pattern_category taxonomy. An empty list leaves
+    /// the terms unchanged.
+    #[uniffi(default = [])]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub wp_pattern_category: Vec<WpPatternCategoryId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
+pub struct WpBlockDeleteResponse {
+    pub deleted: bool,
+    pub previous: WpBlockWithEditContext,
+}
+
+// Reusable blocks are posts of the `wp_block` type. WordPress always returns their raw title &
+// content, even in `view` context, and never renders them.
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseWpBlock {
+    #[WpContext(edit, embed, view)]
+    pub id: Option<PostId>,
+    #[WpContext(edit, view)]
+    pub date: Option<String>,
+    #[WpContext(edit, view)]
+    pub date_gmt: Option<String>,
+    #[WpContext(edit, view)]
+    #[WpContextualField]
+    pub guid: Option<crate::posts::SparsePostGuid>,
+    #[WpContext(edit, embed, view)]
+    pub link: Option<String>,
+    #[WpContext(edit, view)]
+    pub modified: Option<String>,
+    #[WpContext(edit, view)]
+    pub modified_gmt: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub slug: Option<String>,
+    #[WpContext(edit, view)]
+    pub status: Option<PostStatus>,
+    #[serde(rename = "type")]
+    #[WpContext(edit, embed, view)]
+    pub post_type: Option<String>,
+    #[WpContext(edit)]
+    pub password: Option<String>,
+    // `embed` context returns an empty `title` object, so it's left out
+    #[WpContext(edit, view)]
+    #[WpContextualField]
+    pub title: Option<SparseWpBlockTitle>,
+    #[WpContext(edit, view)]
+    #[WpContextualField]
+    pub content: Option<SparseWpBlockContent>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualField]
+    pub excerpt: Option<crate::posts::SparsePostExcerpt>,
+    #[WpContext(edit, view)]
+    pub meta: Option<WpBlockMeta>,
+    // Older sites only have it in `meta`, see `wp_block_pattern_sync_status`
+    #[WpContext(edit, view)]
+    #[WpContextualOption]
+    pub wp_pattern_sync_status: Option<WpPatternSyncStatus>,
+    #[WpContext(edit, view)]
+    pub wp_pattern_category: Option<Vec<WpPatternCategoryId>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseWpBlockTitle {
+    #[WpContext(edit, view)]
+    pub raw: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseWpBlockContent {
+    #[WpContext(edit, view)]
+    pub raw: Option<String>,
+    #[WpContext(edit, view)]
+    pub protected: Option<bool>,
+    #[WpContext(edit)]
+    pub block_version: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+pub struct WpBlockMeta {
+    #[serde(default)]
+    pub wp_pattern_sync_status: WpPatternSyncStatus,
+}
+
+/// Returns the sync status of the block, falling back to its `meta` on sites that don't include it
+/// in the response.
+#[uniffi::export]
+pub fn wp_block_pattern_sync_status(block: &WpBlockWithEditContext) -> WpPatternSyncStatus {
+    block
+        .wp_pattern_sync_status
+        .unwrap_or(block.meta.wp_pattern_sync_status)
+}
+
+fn serialize_as_block_meta<S>(
+    wp_pattern_sync_status: &Option<WpPatternSyncStatus>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    wp_pattern_sync_status
+        .map(|wp_pattern_sync_status| WpBlockMeta {
+            wp_pattern_sync_status,
+        })
+        .serialize(serializer)
+}
+
+/// Whether the instances of a pattern are kept in sync with it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, uniffi::Enum)]
+pub enum WpPatternSyncStatus {
+    /// Every instance is updated when the pattern is changed. This is the default.
+    #[default]
+    #[serde(rename = "")]
+    Synced,
+    /// Only the overridable parts of the pattern can differ between its instances.
+    #[serde(rename = "partial")]
+    Partial,
+    /// The pattern is copied into the post when it's inserted, so it's a regular pattern.
+    #[serde(rename = "unsynced")]
+    Unsynced,
+}
+
+uniffi::custom_newtype!(WpPatternCategoryId, i32);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WpPatternCategoryId(pub i32);
+
+impl std::fmt::Display for WpPatternCategoryId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl_query_param_value_for_newtype!(WpPatternCategoryId);
+
+#[derive(Debug, Default, PartialEq, Eq, uniffi::Record, WpQueryParams)]
+pub struct WpPatternCategoryListParams {
+    /// Current page of the collection.
+    /// Default: `1`
+    #[uniffi(default = None)]
+    pub page: Option<u32>,
+    /// Maximum number of items to be returned in result set.
+    /// Default: `10`
+    #[uniffi(default = None)]
+    pub per_page: Option<u32>,
+    /// Limit results to those matching a string.
+    #[uniffi(default = None)]
+    pub search: Option<String>,
+    /// Whether to hide categories that aren't assigned to any patterns.
+    #[uniffi(default = None)]
+    pub hide_empty: Option<bool>,
+    /// Limit result set to categories with one or more specific slugs.
+    #[uniffi(default = [])]
+    pub slug: Vec<String>,
+}
+
+#[derive(Debug, Serialize, uniffi::Record)]
+pub struct WpPatternCategoryCreateParams {
+    /// HTML title for the category.
+    pub name: String,
+    /// HTML description of the category.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// An alphanumeric identifier for the category unique to its type.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseWpPatternCategory {
+    #[WpContext(edit, embed, view)]
+    pub id: Option<WpPatternCategoryId>,
+    #[WpContext(edit, view)]
+    pub count: Option<u32>,
+    #[WpContext(edit, view)]
+    pub description: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub link: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub name: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub slug: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub taxonomy: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, unit_test_common::assert_expected_query_pairs};
+    use rstest::*;
+    use serde_json::json;
+
+    #[rstest]
+    #[case(WpBlockListParams::default(), &[])]
+    #[case(generate!(WpBlockListParams, (search, Some("foo".to_string()))), &[("search", "foo")])]
+    #[case(generate!(WpBlockListParams, (status, vec![PostStatus::Publish, PostStatus::Draft])), &[("status", "publish,draft")])]
+    #[case(generate!(WpBlockListParams, (wp_pattern_category, vec![WpPatternCategoryId(3), WpPatternCategoryId(4)])), &[("wp_pattern_category", "3,4")])]
+    #[trace]
+    fn test_wp_block_list_params(
+        #[case] params: WpBlockListParams,
+        #[case] expected_pairs: &[(&str, &str)],
+    ) {
+        assert_expected_query_pairs(params.query_pairs(), expected_pairs);
+    }
+
+    #[rstest]
+    #[case(WpPatternSyncStatus::Synced, json!(""))]
+    #[case(WpPatternSyncStatus::Partial, json!("partial"))]
+    #[case(WpPatternSyncStatus::Unsynced, json!("unsynced"))]
+    fn wp_pattern_sync_status(
+        #[case] status: WpPatternSyncStatus,
+        #[case] expected_json: serde_json::Value,
+    ) {
+        assert_eq!(serde_json::to_value(status).unwrap(), expected_json);
+        assert_eq!(
+            serde_json::from_value::<WpPatternSyncStatus>(expected_json).unwrap(),
+            status
+        );
+    }
+
+    #[test]
+    fn wp_block_create_params() {
+        let params = WpBlockCreateParams {
+            wp_pattern_sync_status: Some(WpPatternSyncStatus::Unsynced),
+            wp_pattern_category: vec![WpPatternCategoryId(2)],
+            ..WpBlockCreateParams::new(
+                "Footer".to_string(),
+                "<!-- wp:paragraph --><p>Hi</p><!-- /wp:paragraph -->".to_string(),
+            )
+        };
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            json!({
+                "title": "Footer",
+                "content": "<!-- wp:paragraph --><p>Hi</p><!-- /wp:paragraph -->",
+                "meta": { "wp_pattern_sync_status": "unsynced" },
+                "wp_pattern_category": [2],
+            })
+        );
+    }
+
+    #[rstest]
+    #[case(json!({ "meta": { "wp_pattern_sync_status": "" }, "wp_pattern_sync_status": "unsynced" }), WpPatternSyncStatus::Unsynced)]
+    #[case(json!({ "meta": { "wp_pattern_sync_status": "partial" } }), WpPatternSyncStatus::Partial)]
+    #[case(json!({ "meta": {} }), WpPatternSyncStatus::Synced)]
+    fn test_wp_block_pattern_sync_status(
+        #[case] fields: serde_json::Value,
+        #[case] expected_status: WpPatternSyncStatus,
+    ) {
+        let mut block = json!({
+            "id": 1,
+            "date": "2024-01-01T00:00:00",
+            "date_gmt": "2024-01-01T00:00:00",
+            "guid": { "raw": "", "rendered": "" },
+            "link": "",
+            "modified": "2024-01-01T00:00:00",
+            "modified_gmt": "2024-01-01T00:00:00",
+            "slug": "footer",
+            "status": "publish",
+            "type": "wp_block",
+            "password": "",
+            "title": { "raw": "Footer" },
+            "content": { "raw": "", "protected": false, "block_version": 0 },
+            "excerpt": { "raw": "", "rendered": "", "protected": false },
+            "wp_pattern_category": [],
+        });
+        block
+            .as_object_mut()
+            .unwrap()
+            .extend(fields.as_object().unwrap().clone());
+        let block = serde_json::from_value::<WpBlockWithEditContext>(block).unwrap();
+        assert_eq!(wp_block_pattern_sync_status(&block), expected_status);
+    }
+}
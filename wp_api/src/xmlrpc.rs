@@ -0,0 +1,506 @@
+//! A fallback client for sites that block the REST API, for example through a security plugin,
+//! but still serve [XML-RPC](https://codex.wordpress.org/XML-RPC_WordPress_API).
+//!
+//! Responses are mapped into the same types the REST endpoints use, such as
+//! [`crate::posts::SparsePost`] and [`crate::site_settings::SparseSiteSettings`], so the
+//! consumers don't need a separate model layer for these sites.
+
+use std::collections::BTreeMap;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    posts::{
+        CategoryId, PostCommentStatus, PostFormat, PostId, PostPingStatus, PostStatus, SparsePost,
+        SparsePostContent, SparsePostExcerpt, SparsePostGuid, SparsePostTitle, TagId,
+    },
+    site_settings::SparseSiteSettings,
+    RequestExecutionError, UserId, WpApiParamOrder,
+};
+
+pub(crate) use xmlrpc_client::is_xmlrpc_available;
+pub use xmlrpc_client::WpXmlRpcClient;
+
+mod xmlrpc_client;
+pub(crate) mod xmlrpc_codec;
+
+use xmlrpc_codec::XmlRpcValue;
+
+const TAXONOMY_CATEGORY: &str = "category";
+const TAXONOMY_POST_TAG: &str = "post_tag";
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error, uniffi::Error)]
+pub enum XmlRpcError {
+    #[error(
+        "Request execution failed!\nStatus Code: '{:?}'.\nResponse: '{}'",
+        status_code,
+        reason
+    )]
+    RequestExecutionFailed {
+        status_code: Option<u16>,
+        reason: String,
+    },
+    #[error("XML-RPC fault ({}): {}", fault_code, fault_string)]
+    Fault {
+        fault_code: i64,
+        fault_string: String,
+    },
+    #[error("Error while parsing. \nReason: {}\nResponse: {}", reason, response)]
+    ResponseParsingError { reason: String, response: String },
+}
+
+impl From<RequestExecutionError> for XmlRpcError {
+    fn from(value: RequestExecutionError) -> Self {
+        match value {
+            RequestExecutionError::RequestExecutionFailed {
+                status_code,
+                reason,
+            } => Self::RequestExecutionFailed {
+                status_code,
+                reason,
+            },
+        }
+    }
+}
+
+/// A site the authenticated user belongs to, as returned by `wp.getUsersBlogs`.
+#[derive(Debug, PartialEq, Eq, uniffi::Record)]
+pub struct XmlRpcUserBlog {
+    pub blog_id: String,
+    pub blog_name: String,
+    pub url: String,
+    pub xmlrpc: String,
+    pub is_admin: bool,
+}
+
+impl XmlRpcUserBlog {
+    fn from_xmlrpc_value(value: &XmlRpcValue) -> Option<Self> {
+        let string = |name| {
+            value
+                .member(name)
+                .and_then(|v| v.as_str())
+                .map(|v| v.to_string())
+        };
+        Some(Self {
+            // `blogid` is documented as a `string`, but some hosts return an `int`
+            blog_id: string("blogid")
+                .or_else(|| value.member("blogid")?.as_i64().map(|i| i.to_string()))?,
+            blog_name: string("blogName").unwrap_or_default(),
+            url: string("url")?,
+            xmlrpc: string("xmlrpc")?,
+            is_admin: value
+                .member("isAdmin")
+                .and_then(|v| v.as_bool())
+                .unwrap_or_default(),
+        })
+    }
+}
+
+/// Filter for `wp.getPosts`.
+#[derive(Debug, Default, uniffi::Record)]
+pub struct XmlRpcPostListParams {
+    /// Default: `post`
+    #[uniffi(default = None)]
+    pub post_type: Option<String>,
+    #[uniffi(default = None)]
+    pub post_status: Option<PostStatus>,
+    /// Maximum number of posts to be returned.
+    /// Default: `10`
+    #[uniffi(default = None)]
+    pub number: Option<u32>,
+    #[uniffi(default = None)]
+    pub offset: Option<u32>,
+    /// Any `orderby` value supported by `WP_Query`, for example `date` or `modified`.
+    #[uniffi(default = None)]
+    pub orderby: Option<String>,
+    #[uniffi(default = None)]
+    pub order: Option<WpApiParamOrder>,
+}
+
+impl XmlRpcPostListParams {
+    fn to_xmlrpc_value(&self) -> XmlRpcValue {
+        let members = [
+            ("post_type", self.post_type.clone().map(XmlRpcValue::from)),
+            (
+                "post_status",
+                self.post_status.as_ref().and_then(serialized_string),
+            ),
+            ("number", self.number.map(|x| XmlRpcValue::Int(x.into()))),
+            ("offset", self.offset.map(|x| XmlRpcValue::Int(x.into()))),
+            ("orderby", self.orderby.clone().map(XmlRpcValue::from)),
+            ("order", self.order.map(|x| x.as_str().into())),
+        ];
+        struct_from_optional_members(members)
+    }
+}
+
+/// Content struct for `wp.newPost` and `wp.editPost`. Fields that are `None` or empty are
+/// omitted, so an update only changes the given fields.
+#[derive(Debug, Default, uniffi::Record)]
+pub struct XmlRpcPostParams {
+    /// Default: `post`
+    #[uniffi(default = None)]
+    pub post_type: Option<String>,
+    #[uniffi(default = None)]
+    pub post_status: Option<PostStatus>,
+    #[uniffi(default = None)]
+    pub post_title: Option<String>,
+    #[uniffi(default = None)]
+    pub post_author: Option<UserId>,
+    #[uniffi(default = None)]
+    pub post_excerpt: Option<String>,
+    #[uniffi(default = None)]
+    pub post_content: Option<String>,
+    /// The post's slug.
+    #[uniffi(default = None)]
+    pub post_name: Option<String>,
+    #[uniffi(default = None)]
+    pub post_password: Option<String>,
+    #[uniffi(default = None)]
+    pub post_format: Option<PostFormat>,
+    #[uniffi(default = None)]
+    pub comment_status: Option<PostCommentStatus>,
+    #[uniffi(default = None)]
+    pub ping_status: Option<PostPingStatus>,
+    #[uniffi(default = None)]
+    pub sticky: Option<bool>,
+    #[uniffi(default = [])]
+    pub categories: Vec<CategoryId>,
+    #[uniffi(default = [])]
+    pub tags: Vec<TagId>,
+}
+
+impl XmlRpcPostParams {
+    fn to_xmlrpc_value(&self) -> XmlRpcValue {
+        let term_ids = |ids: Vec<i32>| {
+            (!ids.is_empty()).then(|| {
+                XmlRpcValue::Array(
+                    ids.into_iter()
+                        .map(|id| XmlRpcValue::Int(id.into()))
+                        .collect(),
+                )
+            })
+        };
+        let terms = struct_from_optional_members([
+            (
+                TAXONOMY_CATEGORY,
+                term_ids(self.categories.iter().map(|x| x.0).collect()),
+            ),
+            (
+                TAXONOMY_POST_TAG,
+                term_ids(self.tags.iter().map(|x| x.0).collect()),
+            ),
+        ]);
+        let members = [
+            ("post_type", self.post_type.clone().map(XmlRpcValue::from)),
+            (
+                "post_status",
+                self.post_status.as_ref().and_then(serialized_string),
+            ),
+            ("post_title", self.post_title.clone().map(XmlRpcValue::from)),
+            (
+                "post_author",
+                self.post_author.map(|x| XmlRpcValue::Int(x.0.into())),
+            ),
+            (
+                "post_excerpt",
+                self.post_excerpt.clone().map(XmlRpcValue::from),
+            ),
+            (
+                "post_content",
+                self.post_content.clone().map(XmlRpcValue::from),
+            ),
+            ("post_name", self.post_name.clone().map(XmlRpcValue::from)),
+            (
+                "post_password",
+                self.post_password.clone().map(XmlRpcValue::from),
+            ),
+            (
+                "post_format",
+                self.post_format.as_ref().and_then(serialized_string),
+            ),
+            (
+                "comment_status",
+                self.comment_status.as_ref().and_then(serialized_string),
+            ),
+            (
+                "ping_status",
+                self.ping_status.as_ref().and_then(serialized_string),
+            ),
+            ("sticky", self.sticky.map(XmlRpcValue::Bool)),
+            (
+                "terms",
+                terms
+                    .as_struct()
+                    .is_some_and(|t| !t.is_empty())
+                    .then_some(terms),
+            ),
+        ];
+        struct_from_optional_members(members)
+    }
+}
+
+// Option names requested from `wp.getOptions` and the `SparseSiteSettings` field they map to
+pub(crate) const XMLRPC_SITE_SETTINGS_OPTIONS: [&str; 7] = [
+    "blog_title",
+    "blog_tagline",
+    "blog_url",
+    "date_format",
+    "time_format",
+    "default_comment_status",
+    "default_ping_status",
+];
+
+// `wp.getOptions` returns `{ option_name: { desc, readonly, value } }`. Only a subset of the
+// REST settings are exposed through XML-RPC, the rest of the fields will be `None`.
+fn site_settings_from_xmlrpc_value(value: &XmlRpcValue) -> SparseSiteSettings {
+    let option = |name: &str| value.member(name).and_then(|o| o.member("value"));
+    let string = |name: &str| option(name).and_then(|v| v.as_str()).map(|v| v.to_string());
+    SparseSiteSettings {
+        title: string("blog_title"),
+        description: string("blog_tagline"),
+        url: string("blog_url"),
+        email: None,
+        timezone: None,
+        date_format: string("date_format"),
+        time_format: string("time_format"),
+        start_of_week: None,
+        language: None,
+        use_smilies: None,
+        default_category: None,
+        default_post_format: None,
+        posts_per_page: None,
+        show_on_front: None,
+        page_on_front: None,
+        page_for_posts: None,
+        default_ping_status: option("default_ping_status").and_then(deserialized_string),
+        default_comment_status: option("default_comment_status").and_then(deserialized_string),
+        site_logo: None,
+        site_icon: None,
+    }
+}
+
+// Maps a post struct returned from `wp.getPosts` to the same type used by the REST endpoints.
+//
+// XML-RPC only returns raw values, so `rendered` fields hold the raw content as well.
+fn post_from_xmlrpc_value(value: &XmlRpcValue) -> SparsePost {
+    let member = |name: &str| value.member(name);
+    let string = |name: &str| member(name).and_then(|v| v.as_str()).map(|v| v.to_string());
+    let date = |name: &str| string(name).map(|d| date_from_xmlrpc_date_time(&d));
+    let password = string("post_password");
+    let is_protected = password.as_ref().is_some_and(|p| !p.is_empty());
+    let term_ids = |taxonomy: &str| -> Option<Vec<i64>> {
+        member("terms")?.as_array().map(|terms| {
+            terms
+                .iter()
+                .filter(|t| t.member("taxonomy").and_then(|x| x.as_str()) == Some(taxonomy))
+                .filter_map(|t| t.member("term_id").and_then(|x| x.as_i64()))
+                .collect()
+        })
+    };
+    SparsePost {
+        id: member("post_id")
+            .and_then(|v| v.as_i64())
+            .and_then(|id| id.try_into().ok())
+            .map(PostId),
+        date: date("post_date"),
+        date_gmt: date("post_date_gmt"),
+        guid: string("guid").map(|raw| SparsePostGuid {
+            rendered: Some(raw.clone()),
+            raw: Some(raw),
+        }),
+        link: string("link"),
+        modified: date("post_modified"),
+        modified_gmt: date("post_modified_gmt"),
+        slug: string("post_name"),
+        status: member("post_status").and_then(deserialized_string),
+        post_type: string("post_type"),
+        password,
+        permalink_template: None,
+        generated_slug: None,
+        title: string("post_title").map(|raw| SparsePostTitle {
+            rendered: Some(raw.clone()),
+            raw: Some(raw),
+        }),
+        content: string("post_content").map(|raw| SparsePostContent {
+            rendered: Some(raw.clone()),
+            raw: Some(raw),
+            protected: Some(is_protected),
+            block_version: None,
+        }),
+        author: member("post_author")
+            .and_then(|v| v.as_i64())
+            .and_then(|id| id.try_into().ok())
+            .map(UserId),
+        excerpt: string("post_excerpt").map(|raw| SparsePostExcerpt {
+            rendered: Some(raw.clone()),
+            raw: Some(raw),
+            protected: Some(is_protected),
+        }),
+        // `post_thumbnail` is an empty array if there is no featured image
+        featured_media: member("post_thumbnail").map(|thumbnail| {
+            thumbnail
+                .member("attachment_id")
+                .and_then(|v| v.as_i64())
+                .unwrap_or_default()
+        }),
+        comment_status: member("comment_status").and_then(deserialized_string),
+        ping_status: member("ping_status").and_then(deserialized_string),
+        format: member("post_format").and_then(deserialized_string),
+        meta: None,
+        sticky: member("sticky").and_then(|v| v.as_bool()),
+        template: None,
+        categories: term_ids(TAXONOMY_CATEGORY),
+        tags: term_ids(TAXONOMY_POST_TAG).map(|ids| {
+            ids.into_iter()
+                .filter_map(|id| id.try_into().ok())
+                .map(TagId)
+                .collect()
+        }),
+    }
+}
+
+// XML-RPC uses the compact ISO 8601 format, i.e. `20240131T10:15:00`, whereas the REST API uses
+// `2024-01-31T10:15:00`. Any other format is returned as is.
+fn date_from_xmlrpc_date_time(date_time: &str) -> String {
+    let date_time = date_time.trim_end_matches('Z');
+    match date_time.split_once('T') {
+        Some((date, time)) if date.len() == 8 && date.chars().all(|c| c.is_ascii_digit()) => {
+            format!("{}-{}-{}T{}", &date[0..4], &date[4..6], &date[6..8], time)
+        }
+        _ => date_time.to_string(),
+    }
+}
+
+fn struct_from_optional_members<'a>(
+    members: impl IntoIterator<Item = (&'a str, Option<XmlRpcValue>)>,
+) -> XmlRpcValue {
+    XmlRpcValue::Struct(
+        members
+            .into_iter()
+            // Remove `None` values
+            .filter_map(|(k, opt_v)| opt_v.map(|v| (k.to_string(), v)))
+            .collect::<BTreeMap<_, _>>(),
+    )
+}
+
+// Uses the `serde` representation of the REST types, so values like `PostStatus::Custom` stay
+// consistent between the two APIs.
+fn serialized_string<T: Serialize>(value: &T) -> Option<XmlRpcValue> {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(s)) => Some(XmlRpcValue::String(s)),
+        _ => None,
+    }
+}
+
+fn deserialized_string<T: DeserializeOwned>(value: &XmlRpcValue) -> Option<T> {
+    value
+        .as_str()
+        .and_then(|s| T::deserialize(serde_json::Value::String(s.to_string())).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case("20240131T10:15:00", "2024-01-31T10:15:00")]
+    #[case("20240131T10:15:00Z", "2024-01-31T10:15:00")]
+    #[case("2024-01-31T10:15:00", "2024-01-31T10:15:00")]
+    #[case("00000000T00:00:00", "0000-00-00T00:00:00")]
+    fn test_date_from_xmlrpc_date_time(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(date_from_xmlrpc_date_time(input), expected);
+    }
+
+    #[test]
+    fn test_post_from_xmlrpc_value() {
+        let body = r#"<?xml version="1.0" encoding="UTF-8"?>
+<methodResponse><params><param><value><struct>
+  <member><name>post_id</name><value><string>12</string></value></member>
+  <member><name>post_title</name><value><string>Hello</string></value></member>
+  <member><name>post_date_gmt</name><value><dateTime.iso8601>20240131T10:15:00</dateTime.iso8601></value></member>
+  <member><name>post_status</name><value><string>draft</string></value></member>
+  <member><name>post_author</name><value><string>3</string></value></member>
+  <member><name>post_password</name><value><string></string></value></member>
+  <member><name>post_content</name><value><string>&lt;p&gt;Hi&lt;/p&gt;</string></value></member>
+  <member><name>post_format</name><value><string>aside</string></value></member>
+  <member><name>post_thumbnail</name><value><array><data></data></array></value></member>
+  <member><name>sticky</name><value><boolean>0</boolean></value></member>
+  <member><name>terms</name><value><array><data>
+    <value><struct>
+      <member><name>term_id</name><value><string>5</string></value></member>
+      <member><name>taxonomy</name><value><string>post_tag</string></value></member>
+    </struct></value>
+    <value><struct>
+      <member><name>term_id</name><value><string>1</string></value></member>
+      <member><name>taxonomy</name><value><string>category</string></value></member>
+    </struct></value>
+  </data></array></value></member>
+</struct></value></param></params></methodResponse>"#;
+        let value = xmlrpc_codec::decode_method_response(body.as_bytes()).unwrap();
+        let post = post_from_xmlrpc_value(&value);
+        assert_eq!(post.id, Some(PostId(12)));
+        assert_eq!(post.title.unwrap().raw.as_deref(), Some("Hello"));
+        assert_eq!(post.date_gmt.as_deref(), Some("2024-01-31T10:15:00"));
+        assert_eq!(post.status, Some(PostStatus::Draft));
+        assert_eq!(post.author, Some(UserId(3)));
+        let content = post.content.unwrap();
+        assert_eq!(content.raw.as_deref(), Some("<p>Hi</p>"));
+        assert_eq!(content.protected, Some(false));
+        assert_eq!(post.format, Some(PostFormat::Aside));
+        assert_eq!(post.featured_media, Some(0));
+        assert_eq!(post.sticky, Some(false));
+        assert_eq!(post.categories, Some(vec![1]));
+        assert_eq!(post.tags, Some(vec![TagId(5)]));
+        assert!(post.modified.is_none());
+    }
+
+    #[test]
+    fn test_site_settings_from_xmlrpc_value() {
+        let option = |value: &str| {
+            XmlRpcValue::Struct(BTreeMap::from([
+                ("desc".to_string(), XmlRpcValue::from("")),
+                ("readonly".to_string(), XmlRpcValue::Bool(false)),
+                ("value".to_string(), XmlRpcValue::from(value)),
+            ]))
+        };
+        let value = XmlRpcValue::Struct(BTreeMap::from([
+            ("blog_title".to_string(), option("My Site")),
+            ("blog_url".to_string(), option("https://example.com")),
+            ("default_comment_status".to_string(), option("closed")),
+        ]));
+        let settings = site_settings_from_xmlrpc_value(&value);
+        assert_eq!(settings.title.as_deref(), Some("My Site"));
+        assert_eq!(settings.url.as_deref(), Some("https://example.com"));
+        assert_eq!(
+            settings.default_comment_status,
+            Some(crate::site_settings::SiteSettingsCommentStatus::Closed)
+        );
+        assert!(settings.description.is_none());
+    }
+
+    #[test]
+    fn test_post_params_to_xmlrpc_value() {
+        let params = XmlRpcPostParams {
+            post_title: Some("Hello".to_string()),
+            post_status: Some(PostStatus::Custom("wc-pending".to_string())),
+            tags: vec![TagId(4)],
+            ..Default::default()
+        };
+        assert_eq!(
+            params.to_xmlrpc_value(),
+            XmlRpcValue::Struct(BTreeMap::from([
+                ("post_status".to_string(), XmlRpcValue::from("wc-pending")),
+                ("post_title".to_string(), XmlRpcValue::from("Hello")),
+                (
+                    "terms".to_string(),
+                    XmlRpcValue::Struct(BTreeMap::from([(
+                        "post_tag".to_string(),
+                        XmlRpcValue::Array(vec![XmlRpcValue::Int(4)])
+                    )]))
+                ),
+            ]))
+        );
+    }
+}
@@ -0,0 +1,291 @@
+use std::sync::Arc;
+
+use http::{HeaderMap, HeaderValue};
+
+use crate::request::endpoint::WpEndpointUrl;
+use crate::request::{
+    RequestExecutor, RequestMethod, WpNetworkHeaderMap, WpNetworkRequest, WpNetworkRequestBody,
+};
+use crate::{posts::PostId, posts::SparsePost, site_settings::SparseSiteSettings, ParsedUrl};
+
+use super::xmlrpc_codec::{self, XmlRpcValue};
+use super::{
+    post_from_xmlrpc_value, site_settings_from_xmlrpc_value, XmlRpcError, XmlRpcPostListParams,
+    XmlRpcPostParams, XmlRpcUserBlog, XMLRPC_SITE_SETTINGS_OPTIONS,
+};
+
+const CONTENT_TYPE_XML: &str = "text/xml";
+// Only a site that registers this method is considered to have a usable XML-RPC endpoint
+const REQUIRED_XMLRPC_METHOD: &str = "wp.getUsersBlogs";
+
+#[derive(Debug, uniffi::Object)]
+struct UniffiWpXmlRpcClient {
+    inner: Arc<WpXmlRpcClient>,
+}
+
+#[uniffi::export]
+impl UniffiWpXmlRpcClient {
+    #[uniffi::constructor]
+    fn new(
+        xmlrpc_url: Arc<ParsedUrl>,
+        username: String,
+        password: String,
+        request_executor: Arc<dyn RequestExecutor>,
+    ) -> Self {
+        Self {
+            inner: WpXmlRpcClient::new(xmlrpc_url, username, password, request_executor).into(),
+        }
+    }
+
+    async fn get_users_blogs(&self) -> Result<Vec<XmlRpcUserBlog>, XmlRpcError> {
+        self.inner.get_users_blogs().await
+    }
+
+    async fn get_posts(
+        &self,
+        blog_id: String,
+        params: XmlRpcPostListParams,
+    ) -> Result<Vec<SparsePost>, XmlRpcError> {
+        self.inner.get_posts(&blog_id, &params).await
+    }
+
+    async fn new_post(
+        &self,
+        blog_id: String,
+        params: XmlRpcPostParams,
+    ) -> Result<PostId, XmlRpcError> {
+        self.inner.new_post(&blog_id, &params).await
+    }
+
+    async fn edit_post(
+        &self,
+        blog_id: String,
+        post_id: PostId,
+        params: XmlRpcPostParams,
+    ) -> Result<bool, XmlRpcError> {
+        self.inner.edit_post(&blog_id, &post_id, &params).await
+    }
+
+    async fn get_options(&self, blog_id: String) -> Result<SparseSiteSettings, XmlRpcError> {
+        self.inner.get_options(&blog_id).await
+    }
+}
+
+#[derive(Debug)]
+pub struct WpXmlRpcClient {
+    xmlrpc_url: Arc<ParsedUrl>,
+    username: String,
+    password: String,
+    request_executor: Arc<dyn RequestExecutor>,
+}
+
+impl WpXmlRpcClient {
+    pub fn new(
+        xmlrpc_url: Arc<ParsedUrl>,
+        username: String,
+        password: String,
+        request_executor: Arc<dyn RequestExecutor>,
+    ) -> Self {
+        Self {
+            xmlrpc_url,
+            username,
+            password,
+            request_executor,
+        }
+    }
+
+    pub async fn get_users_blogs(&self) -> Result<Vec<XmlRpcUserBlog>, XmlRpcError> {
+        let response = self
+            .call("wp.getUsersBlogs", vec![self.username(), self.password()])
+            .await?;
+        expect_array(&response)?
+            .iter()
+            .map(|blog| {
+                XmlRpcUserBlog::from_xmlrpc_value(blog)
+                    .ok_or_else(|| unexpected_response("Invalid blog struct", blog))
+            })
+            .collect()
+    }
+
+    pub async fn get_posts(
+        &self,
+        blog_id: &str,
+        params: &XmlRpcPostListParams,
+    ) -> Result<Vec<SparsePost>, XmlRpcError> {
+        let response = self
+            .call(
+                "wp.getPosts",
+                vec![
+                    blog_id.into(),
+                    self.username(),
+                    self.password(),
+                    params.to_xmlrpc_value(),
+                ],
+            )
+            .await?;
+        Ok(expect_array(&response)?
+            .iter()
+            .map(post_from_xmlrpc_value)
+            .collect())
+    }
+
+    pub async fn new_post(
+        &self,
+        blog_id: &str,
+        params: &XmlRpcPostParams,
+    ) -> Result<PostId, XmlRpcError> {
+        let response = self
+            .call(
+                "wp.newPost",
+                vec![
+                    blog_id.into(),
+                    self.username(),
+                    self.password(),
+                    params.to_xmlrpc_value(),
+                ],
+            )
+            .await?;
+        response
+            .as_i64()
+            .and_then(|id| id.try_into().ok())
+            .map(PostId)
+            .ok_or_else(|| unexpected_response("Expecting a post id", &response))
+    }
+
+    pub async fn edit_post(
+        &self,
+        blog_id: &str,
+        post_id: &PostId,
+        params: &XmlRpcPostParams,
+    ) -> Result<bool, XmlRpcError> {
+        let response = self
+            .call(
+                "wp.editPost",
+                vec![
+                    blog_id.into(),
+                    self.username(),
+                    self.password(),
+                    XmlRpcValue::Int(post_id.0.into()),
+                    params.to_xmlrpc_value(),
+                ],
+            )
+            .await?;
+        response
+            .as_bool()
+            .ok_or_else(|| unexpected_response("Expecting a boolean", &response))
+    }
+
+    pub async fn get_options(&self, blog_id: &str) -> Result<SparseSiteSettings, XmlRpcError> {
+        let response = self
+            .call(
+                "wp.getOptions",
+                vec![
+                    blog_id.into(),
+                    self.username(),
+                    self.password(),
+                    XmlRpcValue::Array(
+                        XMLRPC_SITE_SETTINGS_OPTIONS
+                            .iter()
+                            .map(|o| (*o).into())
+                            .collect(),
+                    ),
+                ],
+            )
+            .await?;
+        if response.as_struct().is_none() {
+            return Err(unexpected_response("Expecting a struct", &response));
+        }
+        Ok(site_settings_from_xmlrpc_value(&response))
+    }
+
+    async fn call(
+        &self,
+        method_name: &str,
+        params: Vec<XmlRpcValue>,
+    ) -> Result<XmlRpcValue, XmlRpcError> {
+        call(
+            self.request_executor.as_ref(),
+            &self.xmlrpc_url,
+            method_name,
+            &params,
+        )
+        .await
+    }
+
+    fn username(&self) -> XmlRpcValue {
+        self.username.as_str().into()
+    }
+
+    fn password(&self) -> XmlRpcValue {
+        self.password.as_str().into()
+    }
+}
+
+// Checks whether the given url is a working XML-RPC endpoint with the WordPress API enabled.
+//
+// `system.listMethods` doesn't require authentication, so this can be used during discovery.
+pub(crate) async fn is_xmlrpc_available(
+    request_executor: &dyn RequestExecutor,
+    xmlrpc_url: &ParsedUrl,
+) -> bool {
+    call(request_executor, xmlrpc_url, "system.listMethods", &[])
+        .await
+        .ok()
+        .and_then(|methods| {
+            methods
+                .as_array()
+                .map(|m| m.iter().any(|x| x.as_str() == Some(REQUIRED_XMLRPC_METHOD)))
+        })
+        .unwrap_or_default()
+}
+
+async fn call(
+    request_executor: &dyn RequestExecutor,
+    xmlrpc_url: &ParsedUrl,
+    method_name: &str,
+    params: &[XmlRpcValue],
+) -> Result<XmlRpcValue, XmlRpcError> {
+    let mut header_map = HeaderMap::new();
+    header_map.insert(
+        http::header::ACCEPT,
+        HeaderValue::from_static(CONTENT_TYPE_XML),
+    );
+    header_map.insert(
+        http::header::CONTENT_TYPE,
+        HeaderValue::from_static(CONTENT_TYPE_XML),
+    );
+    let request = WpNetworkRequest {
+        method: RequestMethod::POST,
+        url: WpEndpointUrl(xmlrpc_url.url()),
+        header_map: WpNetworkHeaderMap::new(header_map).into(),
+        body: Some(Arc::new(WpNetworkRequestBody::new(
+            xmlrpc_codec::encode_method_call(method_name, params),
+        ))),
+        timeout_ms: None,
+    };
+    let response = request_executor.execute(request.into()).await?;
+    // Faults are returned with a `200` status code, so the status code is only taken into
+    // account if the response body isn't a valid XML-RPC response.
+    xmlrpc_codec::decode_method_response(&response.body).map_err(|e| match e {
+        XmlRpcError::ResponseParsingError { .. } if !(200..300).contains(&response.status_code) => {
+            XmlRpcError::RequestExecutionFailed {
+                status_code: Some(response.status_code),
+                reason: String::from_utf8_lossy(&response.body).to_string(),
+            }
+        }
+        e => e,
+    })
+}
+
+fn expect_array(value: &XmlRpcValue) -> Result<&[XmlRpcValue], XmlRpcError> {
+    value
+        .as_array()
+        .ok_or_else(|| unexpected_response("Expecting an array", value))
+}
+
+fn unexpected_response(reason: &str, value: &XmlRpcValue) -> XmlRpcError {
+    XmlRpcError::ResponseParsingError {
+        reason: reason.to_string(),
+        response: format!("{:?}", value),
+    }
+}
@@ -0,0 +1,482 @@
+use std::collections::BTreeMap;
+
+use base64::prelude::*;
+use quick_xml::events::Event;
+
+use super::XmlRpcError;
+
+// Type tags from the XML-RPC specification: http://xmlrpc.com/spec.md
+const TYPE_ARRAY: &str = "array";
+const TYPE_BASE64: &str = "base64";
+const TYPE_BOOLEAN: &str = "boolean";
+const TYPE_DATE_TIME: &str = "dateTime.iso8601";
+const TYPE_DOUBLE: &str = "double";
+const TYPE_I4: &str = "i4";
+const TYPE_I8: &str = "i8";
+const TYPE_INT: &str = "int";
+const TYPE_NIL: &str = "nil";
+const TYPE_STRING: &str = "string";
+const TYPE_STRUCT: &str = "struct";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum XmlRpcValue {
+    Array(Vec<XmlRpcValue>),
+    Base64(Vec<u8>),
+    Bool(bool),
+    // Kept in the wire format, i.e. `20240131T10:15:00`
+    DateTime(String),
+    Double(f64),
+    Int(i64),
+    Nil,
+    String(String),
+    Struct(BTreeMap<String, XmlRpcValue>),
+}
+
+impl XmlRpcValue {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) | Self::DateTime(s) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+
+    // WordPress isn't consistent about integers, e.g. `post_id` is returned as a `string` while
+    // `wp.newPost` is documented to return an `int`, so both representations are accepted.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Int(i) => Some(*i),
+            Self::String(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    // Similar to `as_i64`, WordPress sometimes returns booleans as `int` or `string`.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(b) => Some(*b),
+            Self::Int(i) => Some(*i != 0),
+            Self::String(s) => match s.trim() {
+                "1" | "true" => Some(true),
+                "0" | "false" | "" => Some(false),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[XmlRpcValue]> {
+        if let Self::Array(values) = self {
+            Some(values.as_slice())
+        } else {
+            None
+        }
+    }
+
+    pub fn as_struct(&self) -> Option<&BTreeMap<String, XmlRpcValue>> {
+        if let Self::Struct(members) = self {
+            Some(members)
+        } else {
+            None
+        }
+    }
+
+    pub fn member(&self, name: &str) -> Option<&XmlRpcValue> {
+        self.as_struct().and_then(|members| members.get(name))
+    }
+
+    fn write_to(&self, out: &mut String) {
+        out.push_str("<value>");
+        match self {
+            Self::Array(values) => {
+                out.push_str("<array><data>");
+                values.iter().for_each(|v| v.write_to(out));
+                out.push_str("</data></array>");
+            }
+            Self::Base64(bytes) => {
+                write_tag(out, TYPE_BASE64, &BASE64_STANDARD.encode(bytes));
+            }
+            Self::Bool(b) => write_tag(out, TYPE_BOOLEAN, if *b { "1" } else { "0" }),
+            Self::DateTime(s) => write_tag(out, TYPE_DATE_TIME, &escape(s)),
+            Self::Double(d) => write_tag(out, TYPE_DOUBLE, &d.to_string()),
+            Self::Int(i) => write_tag(out, TYPE_INT, &i.to_string()),
+            Self::Nil => out.push_str("<nil/>"),
+            Self::String(s) => write_tag(out, TYPE_STRING, &escape(s)),
+            Self::Struct(members) => {
+                out.push_str("<struct>");
+                members.iter().for_each(|(name, value)| {
+                    out.push_str("<member>");
+                    write_tag(out, "name", &escape(name));
+                    value.write_to(out);
+                    out.push_str("</member>");
+                });
+                out.push_str("</struct>");
+            }
+        }
+        out.push_str("</value>");
+    }
+}
+
+impl From<&str> for XmlRpcValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for XmlRpcValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<i64> for XmlRpcValue {
+    fn from(value: i64) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<bool> for XmlRpcValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+pub fn encode_method_call(method_name: &str, params: &[XmlRpcValue]) -> Vec<u8> {
+    let mut out = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    out.push_str("<methodCall>");
+    write_tag(&mut out, "methodName", &escape(method_name));
+    out.push_str("<params>");
+    params.iter().for_each(|p| {
+        out.push_str("<param>");
+        p.write_to(&mut out);
+        out.push_str("</param>");
+    });
+    out.push_str("</params></methodCall>");
+    out.into_bytes()
+}
+
+// Decodes the single return value of a `<methodResponse>`, or returns `XmlRpcError::Fault` if
+// the server responded with a `<fault>`.
+pub fn decode_method_response(body: &[u8]) -> Result<XmlRpcValue, XmlRpcError> {
+    let root = parse_xml(body)?;
+    if root.name != "methodResponse" {
+        return Err(parsing_error(
+            format!("Expected <methodResponse>, found <{}>", root.name),
+            body,
+        ));
+    }
+    let first_child = root
+        .elements()
+        .next()
+        .ok_or_else(|| parsing_error("<methodResponse> is empty".to_string(), body))?;
+    match first_child.name.as_str() {
+        "params" => first_child
+            .child("param")
+            .and_then(|param| param.child("value"))
+            .ok_or_else(|| parsing_error("Missing <param><value>".to_string(), body))
+            .and_then(|value| decode_value(value).map_err(|reason| parsing_error(reason, body))),
+        "fault" => {
+            let fault = first_child
+                .child("value")
+                .ok_or_else(|| parsing_error("Missing <fault><value>".to_string(), body))
+                .and_then(|value| {
+                    decode_value(value).map_err(|reason| parsing_error(reason, body))
+                })?;
+            Err(XmlRpcError::Fault {
+                fault_code: fault
+                    .member("faultCode")
+                    .and_then(|v| v.as_i64())
+                    .unwrap_or_default(),
+                fault_string: fault
+                    .member("faultString")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+            })
+        }
+        other => Err(parsing_error(
+            format!("Unexpected <{}> in <methodResponse>", other),
+            body,
+        )),
+    }
+}
+
+fn decode_value(value: &XmlElement) -> Result<XmlRpcValue, String> {
+    // A `<value>` without a type tag is a string
+    let Some(typed) = value.elements().next() else {
+        return Ok(XmlRpcValue::String(value.text()));
+    };
+    let text = typed.text();
+    match typed.name.as_str() {
+        TYPE_ARRAY => typed
+            .child("data")
+            .map(|data| {
+                data.elements()
+                    .filter(|e| e.name == "value")
+                    .map(decode_value)
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .unwrap_or_else(|| Ok(Vec::new()))
+            .map(XmlRpcValue::Array),
+        TYPE_BASE64 => BASE64_STANDARD
+            .decode(text.split_whitespace().collect::<String>())
+            .map(XmlRpcValue::Base64)
+            .map_err(|e| format!("Invalid <base64> value: {}", e)),
+        TYPE_BOOLEAN => match text.trim() {
+            "1" => Ok(XmlRpcValue::Bool(true)),
+            "0" => Ok(XmlRpcValue::Bool(false)),
+            other => Err(format!("Invalid <boolean> value: '{}'", other)),
+        },
+        TYPE_DATE_TIME => Ok(XmlRpcValue::DateTime(text.trim().to_string())),
+        TYPE_DOUBLE => text
+            .trim()
+            .parse()
+            .map(XmlRpcValue::Double)
+            .map_err(|_| format!("Invalid <double> value: '{}'", text)),
+        TYPE_I4 | TYPE_I8 | TYPE_INT => text
+            .trim()
+            .parse()
+            .map(XmlRpcValue::Int)
+            .map_err(|_| format!("Invalid <{}> value: '{}'", typed.name, text)),
+        TYPE_NIL => Ok(XmlRpcValue::Nil),
+        TYPE_STRING => Ok(XmlRpcValue::String(text)),
+        TYPE_STRUCT => typed
+            .elements()
+            .filter(|e| e.name == "member")
+            .map(|member| {
+                let name = member
+                    .child("name")
+                    .map(|n| n.text())
+                    .ok_or("<member> is missing <name>")?;
+                let value = member
+                    .child("value")
+                    .ok_or("<member> is missing <value>")
+                    .map_err(|e| e.to_string())
+                    .and_then(decode_value)?;
+                Ok((name, value))
+            })
+            .collect::<Result<BTreeMap<_, _>, String>>()
+            .map(XmlRpcValue::Struct),
+        other => Err(format!("Unsupported value type: <{}>", other)),
+    }
+}
+
+fn write_tag(out: &mut String, tag: &str, escaped_content: &str) {
+    out.push('<');
+    out.push_str(tag);
+    out.push('>');
+    out.push_str(escaped_content);
+    out.push_str("</");
+    out.push_str(tag);
+    out.push('>');
+}
+
+fn escape(s: &str) -> String {
+    quick_xml::escape::escape(s).into_owned()
+}
+
+fn parsing_error(reason: String, body: &[u8]) -> XmlRpcError {
+    XmlRpcError::ResponseParsingError {
+        reason,
+        response: String::from_utf8_lossy(body).to_string(),
+    }
+}
+
+// A minimal DOM, which is all we need for the small XML-RPC vocabulary
+#[derive(Debug, Default)]
+struct XmlElement {
+    name: String,
+    children: Vec<XmlNode>,
+}
+
+#[derive(Debug)]
+enum XmlNode {
+    Element(XmlElement),
+    Text(String),
+}
+
+impl XmlElement {
+    fn elements(&self) -> impl Iterator<Item = &XmlElement> {
+        self.children.iter().filter_map(|c| match c {
+            XmlNode::Element(e) => Some(e),
+            XmlNode::Text(_) => None,
+        })
+    }
+
+    fn child(&self, name: &str) -> Option<&XmlElement> {
+        self.elements().find(|e| e.name == name)
+    }
+
+    fn text(&self) -> String {
+        self.children
+            .iter()
+            .filter_map(|c| match c {
+                XmlNode::Text(t) => Some(t.as_str()),
+                XmlNode::Element(_) => None,
+            })
+            .collect()
+    }
+}
+
+fn parse_xml(body: &[u8]) -> Result<XmlElement, XmlRpcError> {
+    let mut reader = quick_xml::Reader::from_reader(body);
+    let mut stack: Vec<XmlElement> = Vec::new();
+    let element_name = |name: quick_xml::name::QName| {
+        String::from_utf8_lossy(name.local_name().as_ref()).to_string()
+    };
+    let push_child = |stack: &mut Vec<XmlElement>, node: XmlNode| {
+        if let Some(parent) = stack.last_mut() {
+            parent.children.push(node);
+        }
+    };
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| parsing_error(e.to_string(), body))?;
+        match event {
+            Event::Start(e) => stack.push(XmlElement {
+                name: element_name(e.name()),
+                children: Vec::new(),
+            }),
+            Event::Empty(e) => {
+                let element = XmlElement {
+                    name: element_name(e.name()),
+                    children: Vec::new(),
+                };
+                if stack.is_empty() {
+                    return Ok(element);
+                }
+                push_child(&mut stack, XmlNode::Element(element));
+            }
+            Event::End(_) => {
+                let element = stack
+                    .pop()
+                    .ok_or_else(|| parsing_error("Unbalanced closing tag".to_string(), body))?;
+                if stack.is_empty() {
+                    return Ok(element);
+                }
+                push_child(&mut stack, XmlNode::Element(element));
+            }
+            Event::Text(t) => {
+                let text = t
+                    .unescape()
+                    .map_err(|e| parsing_error(e.to_string(), body))?;
+                push_child(&mut stack, XmlNode::Text(text.into_owned()));
+            }
+            Event::CData(c) => {
+                let text = String::from_utf8_lossy(&c.into_inner()).to_string();
+                push_child(&mut stack, XmlNode::Text(text));
+            }
+            Event::Eof => {
+                return Err(parsing_error(
+                    "Unexpected end of document".to_string(),
+                    body,
+                ))
+            }
+            Event::Comment(_) | Event::Decl(_) | Event::DocType(_) | Event::PI(_) => (),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[test]
+    fn test_encode_method_call() {
+        let members = BTreeMap::from([
+            ("post_status".to_string(), XmlRpcValue::from("draft")),
+            (
+                "post_title".to_string(),
+                XmlRpcValue::from("Tom & Jerry <3"),
+            ),
+            ("sticky".to_string(), XmlRpcValue::from(true)),
+        ]);
+        let body = encode_method_call(
+            "wp.newPost",
+            &[
+                XmlRpcValue::Int(1),
+                XmlRpcValue::Struct(members),
+                XmlRpcValue::Array(vec![XmlRpcValue::Nil]),
+            ],
+        );
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            concat!(
+                r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+                "<methodCall><methodName>wp.newPost</methodName><params>",
+                "<param><value><int>1</int></value></param>",
+                "<param><value><struct>",
+                "<member><name>post_status</name><value><string>draft</string></value></member>",
+                "<member><name>post_title</name><value><string>Tom &amp; Jerry &lt;3</string></value></member>",
+                "<member><name>sticky</name><value><boolean>1</boolean></value></member>",
+                "</struct></value></param>",
+                "<param><value><array><data><value><nil/></value></data></array></value></param>",
+                "</params></methodCall>"
+            )
+        );
+    }
+
+    #[rstest]
+    #[case("<value><int>42</int></value>", XmlRpcValue::Int(42))]
+    #[case("<value><i4>-7</i4></value>", XmlRpcValue::Int(-7))]
+    #[case("<value><boolean>0</boolean></value>", XmlRpcValue::Bool(false))]
+    #[case("<value><double>1.5</double></value>", XmlRpcValue::Double(1.5))]
+    #[case("<value>untyped</value>", XmlRpcValue::String("untyped".to_string()))]
+    #[case("<value><string> a &amp; b </string></value>", XmlRpcValue::String(" a & b ".to_string()))]
+    #[case("<value><string><![CDATA[<p>Hi</p>]]></string></value>", XmlRpcValue::String("<p>Hi</p>".to_string()))]
+    #[case("<value><string/></value>", XmlRpcValue::String("".to_string()))]
+    #[case("<value><dateTime.iso8601>20240131T10:15:00</dateTime.iso8601></value>", XmlRpcValue::DateTime("20240131T10:15:00".to_string()))]
+    #[case("<value><base64>aGVsbG8=</base64></value>", XmlRpcValue::Base64(b"hello".to_vec()))]
+    #[case("<value><nil/></value>", XmlRpcValue::Nil)]
+    #[case("<value><array><data></data></array></value>", XmlRpcValue::Array(vec![]))]
+    #[case(
+        "<value><array><data>\n  <value><int>1</int></value>\n  <value>two</value>\n</data></array></value>",
+        XmlRpcValue::Array(vec![XmlRpcValue::Int(1), XmlRpcValue::String("two".to_string())])
+    )]
+    #[case(
+        "<value><struct>\n<member><name>blogid</name><value><string>1</string></value></member>\n</struct></value>",
+        XmlRpcValue::Struct(BTreeMap::from([("blogid".to_string(), XmlRpcValue::from("1"))]))
+    )]
+    fn test_decode_method_response(#[case] value: &str, #[case] expected_value: XmlRpcValue) {
+        let body = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<methodResponse>\n  <params>\n    <param>\n      {}\n    </param>\n  </params>\n</methodResponse>\n",
+            value
+        );
+        assert_eq!(decode_method_response(body.as_bytes()), Ok(expected_value));
+    }
+
+    #[test]
+    fn test_decode_fault() {
+        let body = r#"<?xml version="1.0" encoding="UTF-8"?>
+<methodResponse>
+  <fault>
+    <value>
+      <struct>
+        <member><name>faultCode</name><value><int>403</int></value></member>
+        <member><name>faultString</name><value><string>Incorrect username or password.</string></value></member>
+      </struct>
+    </value>
+  </fault>
+</methodResponse>"#;
+        assert_eq!(
+            decode_method_response(body.as_bytes()),
+            Err(XmlRpcError::Fault {
+                fault_code: 403,
+                fault_string: "Incorrect username or password.".to_string()
+            })
+        );
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("<html><body>Forbidden</body></html>")]
+    #[case("<methodResponse><params><param><value><int>x</int></value></param></params></methodResponse>")]
+    #[case("<methodResponse><params><param><value><int>1</int></value>")]
+    fn test_decode_invalid_response(#[case] body: &str) {
+        assert!(matches!(
+            decode_method_response(body.as_bytes()),
+            Err(XmlRpcError::ResponseParsingError { .. })
+        ));
+    }
+}
@@ -0,0 +1,262 @@
+use std::sync::Mutex;
+
+use rocket::{delete, get, http::Status, post, serde::json::Json, Route, State};
+use serde_json::{json, Value};
+use wp_api::{
+    application_passwords::{
+        ApplicationPasswordWithEditContext, ApplicationPasswordWithEmbedContext,
+        ApplicationPasswordWithViewContext,
+    },
+    WpErrorCode,
+};
+
+use crate::{
+    response::{WpRestError, WpRestResponse, WpRestResult},
+    site::{new_application_password_record, Authenticated, FakeApplicationPassword, FakeSite},
+    wp_request::{ContextualTypes, WpRequest},
+};
+
+type ApplicationPasswordContexts = (
+    ApplicationPasswordWithEditContext,
+    ApplicationPasswordWithViewContext,
+    ApplicationPasswordWithEmbedContext,
+);
+
+pub(crate) fn routes() -> Vec<Route> {
+    rocket::routes![
+        list_application_passwords,
+        create_application_password,
+        delete_all_application_passwords,
+        retrieve_current_application_password,
+        retrieve_application_password,
+        update_application_password,
+        delete_application_password
+    ]
+}
+
+#[get("/users/<user_id>/application-passwords")]
+fn list_application_passwords(
+    site: &State<Mutex<FakeSite>>,
+    request: WpRequest,
+    user_id: i32,
+) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    check_permission(
+        &site,
+        &authenticated,
+        user_id,
+        WpErrorCode::CannotListApplicationPasswords,
+        "Sorry, you are not allowed to list application passwords for this user.",
+    )?;
+    let application_passwords = site
+        .user(user_id)?
+        .application_passwords
+        .iter()
+        .map(|p| request.render::<ApplicationPasswordContexts>(&p.record))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(WpRestResponse::ok(Value::Array(application_passwords)))
+}
+
+#[post("/users/<user_id>/application-passwords", data = "<body>")]
+fn create_application_password(
+    site: &State<Mutex<FakeSite>>,
+    request: WpRequest,
+    user_id: i32,
+    body: Json<Value>,
+) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    check_permission(
+        &site,
+        &authenticated,
+        user_id,
+        WpErrorCode::CannotCreateApplicationPasswords,
+        "Sorry, you are not allowed to create application passwords for this user.",
+    )?;
+    let name = body["name"]
+        .as_str()
+        .ok_or_else(|| WpRestError::missing_param("name"))?;
+    let app_id = body["app_id"].as_str().unwrap_or_default();
+    let (record, password) = new_application_password_record(name, app_id);
+    let mut response = edit_context(&record);
+    response["password"] = json!(password);
+    site.user_mut(user_id)?
+        .application_passwords
+        .push(FakeApplicationPassword { record, password });
+    Ok(WpRestResponse::created(response))
+}
+
+#[delete("/users/<user_id>/application-passwords")]
+fn delete_all_application_passwords(
+    site: &State<Mutex<FakeSite>>,
+    request: WpRequest,
+    user_id: i32,
+) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    check_permission(
+        &site,
+        &authenticated,
+        user_id,
+        WpErrorCode::CannotDeleteApplicationPasswords,
+        "Sorry, you are not allowed to delete application passwords for this user.",
+    )?;
+    let application_passwords = std::mem::take(&mut site.user_mut(user_id)?.application_passwords);
+    Ok(WpRestResponse::ok(json!({
+        "deleted": true,
+        "count": application_passwords.len(),
+    })))
+}
+
+#[get("/users/<user_id>/application-passwords/introspect")]
+fn retrieve_current_application_password(
+    site: &State<Mutex<FakeSite>>,
+    request: WpRequest,
+    user_id: i32,
+) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    check_permission(
+        &site,
+        &authenticated,
+        user_id,
+        WpErrorCode::CannotReadApplicationPassword,
+        "Sorry, you are not allowed to read this application password.",
+    )?;
+    let Some(authenticated) = authenticated.filter(|a| a.user_id == user_id) else {
+        return Err(WpRestError::new(
+            Status::Unauthorized,
+            WpErrorCode::CannotIntrospectAppPasswordForNonAuthenticatedUser,
+            "The authenticated application password can only be introspected for the current user.",
+        ));
+    };
+    let application_password = find(&site, user_id, &authenticated.application_password_uuid)?;
+    Ok(WpRestResponse::ok(
+        request.render::<ApplicationPasswordContexts>(&application_password.record)?,
+    ))
+}
+
+#[get("/users/<user_id>/application-passwords/<uuid>", rank = 2)]
+fn retrieve_application_password(
+    site: &State<Mutex<FakeSite>>,
+    request: WpRequest,
+    user_id: i32,
+    uuid: &str,
+) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    check_permission(
+        &site,
+        &authenticated,
+        user_id,
+        WpErrorCode::CannotReadApplicationPassword,
+        "Sorry, you are not allowed to read this application password.",
+    )?;
+    let application_password = find(&site, user_id, uuid)?;
+    Ok(WpRestResponse::ok(
+        request.render::<ApplicationPasswordContexts>(&application_password.record)?,
+    ))
+}
+
+#[post("/users/<user_id>/application-passwords/<uuid>", data = "<body>")]
+fn update_application_password(
+    site: &State<Mutex<FakeSite>>,
+    request: WpRequest,
+    user_id: i32,
+    uuid: &str,
+    body: Json<Value>,
+) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    check_permission(
+        &site,
+        &authenticated,
+        user_id,
+        WpErrorCode::CannotEditApplicationPassword,
+        "Sorry, you are not allowed to edit this application password.",
+    )?;
+    find(&site, user_id, uuid)?;
+    let application_password = site
+        .user_mut(user_id)?
+        .application_passwords
+        .iter_mut()
+        .find(|p| p.record["uuid"] == uuid)
+        .expect("Application password was just found");
+    for field in ["name", "app_id"] {
+        if let Some(value) = body[field].as_str() {
+            application_password.record[field] = json!(value);
+        }
+    }
+    Ok(WpRestResponse::ok(edit_context(
+        &application_password.record,
+    )))
+}
+
+#[delete("/users/<user_id>/application-passwords/<uuid>")]
+fn delete_application_password(
+    site: &State<Mutex<FakeSite>>,
+    request: WpRequest,
+    user_id: i32,
+    uuid: &str,
+) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    check_permission(
+        &site,
+        &authenticated,
+        user_id,
+        WpErrorCode::CannotDeleteApplicationPassword,
+        "Sorry, you are not allowed to delete this application password.",
+    )?;
+    let previous = edit_context(&find(&site, user_id, uuid)?.record);
+    site.user_mut(user_id)?
+        .application_passwords
+        .retain(|p| p.record["uuid"] != uuid);
+    Ok(WpRestResponse::ok(json!({
+        "deleted": true,
+        "previous": previous,
+    })))
+}
+
+// Users can manage their own application passwords, and the ones of other users if they can
+// edit them.
+fn check_permission(
+    site: &FakeSite,
+    authenticated: &Option<Authenticated>,
+    user_id: i32,
+    error_code: WpErrorCode,
+    message: &str,
+) -> Result<(), WpRestError> {
+    let is_current_user = authenticated.as_ref().is_some_and(|a| a.user_id == user_id);
+    if authenticated.is_some() && !is_current_user {
+        site.user(user_id)?;
+    }
+    if is_current_user || site.can(authenticated, "edit_users") {
+        Ok(())
+    } else {
+        Err(WpRequest::forbidden(authenticated, error_code, message))
+    }
+}
+
+fn find<'a>(
+    site: &'a FakeSite,
+    user_id: i32,
+    uuid: &str,
+) -> Result<&'a FakeApplicationPassword, WpRestError> {
+    site.user(user_id)?
+        .application_passwords
+        .iter()
+        .find(|p| p.record["uuid"] == uuid)
+        .ok_or_else(|| {
+            WpRestError::new(
+                Status::NotFound,
+                WpErrorCode::ApplicationPasswordNotFound,
+                "Application password not found.",
+            )
+        })
+}
+
+fn edit_context(record: &Value) -> Value {
+    ApplicationPasswordContexts::filter(record, wp_api::WpContext::Edit)
+}
@@ -0,0 +1,178 @@
+//! An in-process fake of the WordPress REST API.
+//!
+//! [`FakeWpServer`] mounts Rocket routes for users, application passwords, plugins, site settings
+//! & posts on top of an in-memory site, and implements [`RequestExecutor`] by dispatching requests
+//! to them without opening a socket. Every server has its own site, so tests that mutate it can
+//! run in parallel without having to restore a database.
+use std::{
+    fmt::Debug,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use http::{HeaderMap, HeaderName, HeaderValue};
+use rocket::{http::Header, local::asynchronous::Client};
+use url::{Position, Url};
+use wp_api::{
+    request::{
+        RequestExecutor, RequestMethod, WpNetworkHeaderMap, WpNetworkRequest, WpNetworkResponse,
+    },
+    ParsedUrl, RequestExecutionError, WpApiClient, WpAuthentication,
+};
+
+mod application_passwords;
+mod plugins;
+mod posts;
+mod response;
+mod site;
+mod site_settings;
+mod users;
+mod wp_request;
+
+pub use site::{
+    FAKE_ADMIN_PASSWORD, FAKE_ADMIN_USERNAME, FAKE_AUTHOR_PASSWORD, FAKE_AUTHOR_USERNAME,
+    FAKE_SITE_URL, FAKE_SUBSCRIBER_PASSWORD, FAKE_SUBSCRIBER_USERNAME,
+};
+
+pub struct FakeWpServer {
+    client: Client,
+}
+
+impl FakeWpServer {
+    pub async fn new() -> Self {
+        let config = rocket::Config {
+            log_level: rocket::config::LogLevel::Off,
+            ..rocket::Config::debug_default()
+        };
+        let rocket = rocket::custom(config)
+            .manage(Mutex::new(site::FakeSite::seeded()))
+            .register("/", rocket::catchers![response::no_route])
+            .mount("/wp-json/wp/v2/", application_passwords::routes())
+            .mount("/wp-json/wp/v2/", plugins::routes())
+            .mount("/wp-json/wp/v2/", posts::routes())
+            .mount("/wp-json/wp/v2/", site_settings::routes())
+            .mount("/wp-json/wp/v2/", users::routes());
+        let client = Client::untracked(rocket)
+            .await
+            .expect("The fake server's routes should be valid");
+        Self { client }
+    }
+
+    pub fn site_url() -> Arc<ParsedUrl> {
+        ParsedUrl::parse(FAKE_SITE_URL)
+            .expect("FAKE_SITE_URL is a valid url")
+            .into()
+    }
+
+    pub fn api_client(self: &Arc<Self>, authentication: WpAuthentication) -> WpApiClient {
+        WpApiClient::new(Self::site_url(), authentication, self.clone())
+    }
+
+    pub fn api_client_as_admin(self: &Arc<Self>) -> WpApiClient {
+        self.api_client(WpAuthentication::from_username_and_password(
+            FAKE_ADMIN_USERNAME.to_string(),
+            FAKE_ADMIN_PASSWORD.to_string(),
+        ))
+    }
+
+    pub fn api_client_as_subscriber(self: &Arc<Self>) -> WpApiClient {
+        self.api_client(WpAuthentication::from_username_and_password(
+            FAKE_SUBSCRIBER_USERNAME.to_string(),
+            FAKE_SUBSCRIBER_PASSWORD.to_string(),
+        ))
+    }
+
+    pub fn api_client_as_unauthenticated(self: &Arc<Self>) -> WpApiClient {
+        self.api_client(WpAuthentication::None)
+    }
+
+    /// Changes the title of a post, as if it was edited by another client.
+    pub fn edit_post_title(&self, post_id: i32, title: &str) {
+        let mut site = self.site();
+        let post = site.posts.get_mut(&post_id).expect("Post exists");
+        post["title"] = serde_json::json!({ "raw": title, "rendered": title });
+        post["modified"] = site::now().into();
+        post["modified_gmt"] = site::now().into();
+    }
+
+    /// Moves a post to the trash, as if it was trashed by another client.
+    pub fn trash_post(&self, post_id: i32) {
+        let mut site = self.site();
+        let post = site.posts.get_mut(&post_id).expect("Post exists");
+        post["status"] = "trash".into();
+        post["modified"] = site::now().into();
+        post["modified_gmt"] = site::now().into();
+    }
+
+    fn site(&self) -> MutexGuard<'_, site::FakeSite> {
+        self.client
+            .rocket()
+            .state::<Mutex<site::FakeSite>>()
+            .expect("The fake site is managed by Rocket")
+            .lock()
+            .expect("Fake site lock is not poisoned")
+    }
+}
+
+impl Debug for FakeWpServer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FakeWpServer").finish_non_exhaustive()
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestExecutor for FakeWpServer {
+    async fn execute(
+        &self,
+        request: Arc<WpNetworkRequest>,
+    ) -> Result<WpNetworkResponse, RequestExecutionError> {
+        let url = Url::parse(&request.url().0).map_err(|e| {
+            RequestExecutionError::RequestExecutionFailed {
+                status_code: None,
+                reason: e.to_string(),
+            }
+        })?;
+        let mut local_request = self.client.req(
+            rocket_method(request.method()),
+            url[Position::BeforePath..].to_string(),
+        );
+        for (name, value) in request.header_map().as_header_map().iter() {
+            if let Ok(value) = value.to_str() {
+                local_request.add_header(Header::new(name.as_str().to_string(), value.to_string()));
+            }
+        }
+        if let Some(body) = request.body() {
+            local_request.set_body(body.contents());
+        }
+
+        let local_response = local_request.dispatch().await;
+        let status_code = local_response.status().code;
+        let mut header_map = HeaderMap::new();
+        for header in local_response.headers().iter() {
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::try_from(header.name().as_str()),
+                HeaderValue::from_str(header.value()),
+            ) {
+                header_map.append(name, value);
+            }
+        }
+        let body = local_response.into_bytes().await.unwrap_or_default();
+        Ok(WpNetworkResponse {
+            body,
+            status_code,
+            header_map: Arc::new(WpNetworkHeaderMap::new(header_map)),
+        })
+    }
+}
+
+fn rocket_method(method: RequestMethod) -> rocket::http::Method {
+    use rocket::http::Method;
+    match method {
+        RequestMethod::GET => Method::Get,
+        RequestMethod::POST => Method::Post,
+        RequestMethod::PUT => Method::Put,
+        RequestMethod::PATCH => Method::Patch,
+        RequestMethod::DELETE => Method::Delete,
+        RequestMethod::HEAD => Method::Head,
+        RequestMethod::OPTIONS => Method::Options,
+    }
+}
@@ -0,0 +1,267 @@
+use std::sync::Mutex;
+
+use rocket::{delete, get, http::Status, post, serde::json::Json, Route, State};
+use serde_json::{json, Value};
+use wp_api::{
+    plugins::{
+        PluginListParams, PluginStatus, PluginWithEditContext, PluginWithEmbedContext,
+        PluginWithViewContext,
+    },
+    WpErrorCode,
+};
+
+use crate::{
+    response::{WpRestError, WpRestResponse, WpRestResult},
+    site::{new_plugin_record, Authenticated, FakeSite, WP_ORG_PLUGINS},
+    wp_request::{ContextualTypes, WpRequest},
+};
+
+type PluginContexts = (
+    PluginWithEditContext,
+    PluginWithViewContext,
+    PluginWithEmbedContext,
+);
+
+pub(crate) fn routes() -> Vec<Route> {
+    rocket::routes![
+        list_plugins,
+        create_plugin,
+        retrieve_plugin,
+        update_plugin,
+        delete_plugin
+    ]
+}
+
+#[get("/plugins")]
+fn list_plugins(site: &State<Mutex<FakeSite>>, request: WpRequest) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    check_capability(
+        &site,
+        &authenticated,
+        "activate_plugins",
+        WpErrorCode::CannotViewPlugins,
+        "Sorry, you are not allowed to manage plugins for this site.",
+    )?;
+    let params = PluginListParams::from_query_pairs(request.query_pairs());
+    let plugins = site
+        .plugins
+        .values()
+        .filter(|p| {
+            params
+                .status
+                .map_or(true, |status| p["status"] == json!(status))
+        })
+        .filter(|p| {
+            params.search.as_ref().map_or(true, |search| {
+                let search = search.to_lowercase();
+                [&p["name"], &p["description"]["raw"]].iter().any(|v| {
+                    v.as_str()
+                        .unwrap_or_default()
+                        .to_lowercase()
+                        .contains(&search)
+                })
+            })
+        })
+        .map(|p| request.render::<PluginContexts>(p))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(WpRestResponse::ok(Value::Array(plugins)))
+}
+
+#[post("/plugins", data = "<body>")]
+fn create_plugin(
+    site: &State<Mutex<FakeSite>>,
+    request: WpRequest,
+    body: Json<Value>,
+) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    check_capability(
+        &site,
+        &authenticated,
+        "install_plugins",
+        WpErrorCode::CannotInstallPlugin,
+        "Sorry, you are not allowed to install plugins on this site.",
+    )?;
+    let slug = body["slug"]
+        .as_str()
+        .ok_or_else(|| WpRestError::missing_param("slug"))?;
+    let status = requested_status(&body)?.unwrap_or(PluginStatus::Inactive);
+    if status != PluginStatus::Inactive {
+        check_status_change(&site, &authenticated, status)?;
+    }
+    let (_, plugin, _, _) = WP_ORG_PLUGINS
+        .iter()
+        .find(|(directory_slug, _, _, _)| *directory_slug == slug)
+        .ok_or_else(|| {
+            WpRestError::new(
+                Status::InternalServerError,
+                WpErrorCode::WpCorePluginsApiFailed,
+                "Plugin not found.",
+            )
+        })?;
+    if site.plugins.contains_key(*plugin) {
+        return Err(WpRestError::new(
+            Status::InternalServerError,
+            WpErrorCode::WpCoreFolderExists,
+            "Destination folder already exists.",
+        ));
+    }
+    let record = new_plugin_record(plugin, json!(status).as_str().unwrap_or_default())
+        .expect("Plugin is in the catalog");
+    let response = edit_context(&record);
+    site.plugins.insert(plugin.to_string(), record);
+    Ok(WpRestResponse::created(response))
+}
+
+#[get("/plugins/<directory>/<file>")]
+fn retrieve_plugin(
+    site: &State<Mutex<FakeSite>>,
+    request: WpRequest,
+    directory: &str,
+    file: &str,
+) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    check_capability(
+        &site,
+        &authenticated,
+        "activate_plugins",
+        WpErrorCode::CannotViewPlugin,
+        "Sorry, you are not allowed to manage this plugin.",
+    )?;
+    let plugin = find(&site, directory, file)?;
+    Ok(WpRestResponse::ok(
+        request.render::<PluginContexts>(plugin)?,
+    ))
+}
+
+#[post("/plugins/<directory>/<file>", data = "<body>")]
+fn update_plugin(
+    site: &State<Mutex<FakeSite>>,
+    request: WpRequest,
+    directory: &str,
+    file: &str,
+    body: Json<Value>,
+) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    check_capability(
+        &site,
+        &authenticated,
+        "activate_plugins",
+        WpErrorCode::CannotManagePlugins,
+        "Sorry, you are not allowed to manage plugins for this site.",
+    )?;
+    find(&site, directory, file)?;
+    if let Some(status) = requested_status(&body)? {
+        check_status_change(&site, &authenticated, status)?;
+        let plugin = site
+            .plugins
+            .get_mut(&format!("{}/{}", directory, file))
+            .expect("Plugin was just found");
+        plugin["status"] = json!(status);
+    }
+    Ok(WpRestResponse::ok(edit_context(find(
+        &site, directory, file,
+    )?)))
+}
+
+#[delete("/plugins/<directory>/<file>")]
+fn delete_plugin(
+    site: &State<Mutex<FakeSite>>,
+    request: WpRequest,
+    directory: &str,
+    file: &str,
+) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    check_capability(
+        &site,
+        &authenticated,
+        "delete_plugins",
+        WpErrorCode::CannotManagePlugins,
+        "Sorry, you are not allowed to manage plugins for this site.",
+    )?;
+    let plugin = find(&site, directory, file)?;
+    if plugin["status"] == json!(PluginStatus::Active) {
+        return Err(WpRestError::new(
+            Status::BadRequest,
+            WpErrorCode::CannotDeleteActivePlugin,
+            "Cannot delete an active plugin. Please deactivate it first.",
+        ));
+    }
+    let previous = edit_context(plugin);
+    site.plugins.remove(&format!("{}/{}", directory, file));
+    Ok(WpRestResponse::ok(json!({
+        "deleted": true,
+        "previous": previous,
+    })))
+}
+
+fn check_capability(
+    site: &FakeSite,
+    authenticated: &Option<Authenticated>,
+    capability: &str,
+    error_code: WpErrorCode,
+    message: &str,
+) -> Result<(), WpRestError> {
+    if site.can(authenticated, capability) {
+        Ok(())
+    } else {
+        Err(WpRequest::forbidden(authenticated, error_code, message))
+    }
+}
+
+fn check_status_change(
+    site: &FakeSite,
+    authenticated: &Option<Authenticated>,
+    status: PluginStatus,
+) -> Result<(), WpRestError> {
+    match status {
+        // The fake site is not a multisite, so plugins can't be network activated
+        PluginStatus::NetworkActive => Err(WpRequest::forbidden(
+            authenticated,
+            WpErrorCode::CannotManageNetworkPlugins,
+            "Sorry, you are not allowed to manage network plugins.",
+        )),
+        PluginStatus::Active => check_capability(
+            site,
+            authenticated,
+            "activate_plugins",
+            WpErrorCode::CannotActivatePlugin,
+            "Sorry, you are not allowed to activate this plugin.",
+        ),
+        PluginStatus::Inactive => check_capability(
+            site,
+            authenticated,
+            "activate_plugins",
+            WpErrorCode::CannotDeactivatePlugin,
+            "Sorry, you are not allowed to deactivate this plugin.",
+        ),
+    }
+}
+
+fn requested_status(body: &Value) -> Result<Option<PluginStatus>, WpRestError> {
+    body.get("status")
+        .map(|status| {
+            serde_json::from_value(status.clone()).map_err(|_| WpRestError::invalid_param("status"))
+        })
+        .transpose()
+}
+
+fn find<'a>(site: &'a FakeSite, directory: &str, file: &str) -> Result<&'a Value, WpRestError> {
+    site.plugins
+        .get(&format!("{}/{}", directory, file))
+        .ok_or_else(|| {
+            WpRestError::new(
+                Status::NotFound,
+                WpErrorCode::PluginNotFound,
+                "Plugin not found.",
+            )
+        })
+}
+
+fn edit_context(record: &Value) -> Value {
+    PluginContexts::filter(record, wp_api::WpContext::Edit)
+}
@@ -0,0 +1,267 @@
+use std::sync::Mutex;
+
+use rocket::{get, http::Status, post, serde::json::Json, Route, State};
+use serde_json::{json, Value};
+use wp_api::{
+    posts::{
+        PostListParams, PostStatus, PostWithEditContext, PostWithEmbedContext, PostWithViewContext,
+        WpApiParamPostsOrderBy,
+    },
+    WpApiParamOrder, WpContext, WpErrorCode,
+};
+
+use crate::{
+    response::{site_link, WpRestError, WpRestResponse, WpRestResult},
+    site::{compare_json, now, FakeSite},
+    wp_request::{ContextualTypes, WpRequest},
+};
+
+type PostContexts = (
+    PostWithEditContext,
+    PostWithViewContext,
+    PostWithEmbedContext,
+);
+
+pub(crate) fn routes() -> Vec<Route> {
+    rocket::routes![list_posts, retrieve_post, update_post]
+}
+
+#[get("/posts")]
+fn list_posts(site: &State<Mutex<FakeSite>>, request: WpRequest) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    let can_edit_posts = site.can(&authenticated, "edit_posts");
+    if request.context()? == WpContext::Edit && !can_edit_posts {
+        return Err(WpRequest::forbidden(
+            &authenticated,
+            WpErrorCode::ForbiddenContext,
+            "Sorry, you are not allowed to edit posts in this post type.",
+        ));
+    }
+    let params = PostListParams::from_query_pairs(request.query_pairs());
+    let statuses = if params.status.is_empty() {
+        vec![PostStatus::Publish]
+    } else {
+        params.status.clone()
+    };
+    if !can_edit_posts && statuses.iter().any(|s| *s != PostStatus::Publish) {
+        return Err(WpRequest::forbidden(
+            &authenticated,
+            WpErrorCode::CustomError("rest_forbidden_status".to_string()),
+            "Status is forbidden.",
+        ));
+    }
+
+    let mut posts = site
+        .posts
+        .values()
+        .filter(|p| statuses.iter().any(|s| p["status"] == json!(s)))
+        .filter(|p| params.include.is_empty() || params.include.iter().any(|id| p["id"] == id.0))
+        .filter(|p| !params.exclude.iter().any(|id| p["id"] == id.0))
+        .filter(|p| params.author.is_empty() || params.author.iter().any(|id| p["author"] == id.0))
+        .filter(|p| !params.author_exclude.iter().any(|id| p["author"] == id.0))
+        .filter(|p| params.slug.is_empty() || params.slug.iter().any(|s| p["slug"] == *s))
+        .filter(|p| params.sticky.map_or(true, |sticky| p["sticky"] == sticky))
+        .filter(|p| is_after(&p["date"], &params.after) && is_before(&p["date"], &params.before))
+        .filter(|p| {
+            is_after(&p["modified"], &params.modified_after)
+                && is_before(&p["modified"], &params.modified_before)
+        })
+        .filter(|p| {
+            params.search.as_ref().map_or(true, |search| {
+                let search = search.to_lowercase();
+                [
+                    &p["title"]["raw"],
+                    &p["content"]["raw"],
+                    &p["excerpt"]["raw"],
+                ]
+                .iter()
+                .any(|v| {
+                    v.as_str()
+                        .unwrap_or_default()
+                        .to_lowercase()
+                        .contains(&search)
+                })
+            })
+        })
+        .collect::<Vec<_>>();
+    let orderby_field = match params.orderby.unwrap_or_default() {
+        WpApiParamPostsOrderBy::Author => "author",
+        WpApiParamPostsOrderBy::Id | WpApiParamPostsOrderBy::Include => "id",
+        WpApiParamPostsOrderBy::Modified => "modified",
+        WpApiParamPostsOrderBy::Slug | WpApiParamPostsOrderBy::IncludeSlugs => "slug",
+        WpApiParamPostsOrderBy::Title => "title",
+        _ => "date",
+    };
+    posts.sort_by(|a, b| {
+        let (a, b) = match orderby_field {
+            "title" => (&a["title"]["raw"], &b["title"]["raw"]),
+            field => (&a[field], &b[field]),
+        };
+        compare_json(a, b)
+    });
+    // Unlike the other endpoints, posts are listed in descending order by default
+    if params.order != Some(WpApiParamOrder::Asc) {
+        posts.reverse();
+    }
+    let posts = posts
+        .into_iter()
+        .map(|p| request.render::<PostContexts>(p))
+        .collect::<Result<Vec<_>, _>>()?;
+    WpRestResponse::paginated(
+        &request,
+        posts,
+        Some(WpErrorCode::CustomError(
+            "rest_post_invalid_page_number".to_string(),
+        )),
+    )
+}
+
+#[get("/posts/<post_id>")]
+fn retrieve_post(site: &State<Mutex<FakeSite>>, request: WpRequest, post_id: i32) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    let can_edit_posts = site.can(&authenticated, "edit_posts");
+    if request.context()? == WpContext::Edit && !can_edit_posts {
+        return Err(WpRequest::forbidden(
+            &authenticated,
+            WpErrorCode::CannotEdit,
+            "Sorry, you are not allowed to edit this post.",
+        ));
+    }
+    let post = find(&site, post_id)?;
+    if post["status"] != "publish" && !can_edit_posts {
+        return Err(WpRequest::forbidden(
+            &authenticated,
+            WpErrorCode::Forbidden,
+            "Sorry, you are not allowed to do that.",
+        ));
+    }
+    Ok(WpRestResponse::ok(request.render::<PostContexts>(post)?))
+}
+
+#[post("/posts/<post_id>", data = "<body>")]
+fn update_post(
+    site: &State<Mutex<FakeSite>>,
+    request: WpRequest,
+    post_id: i32,
+    body: Json<Value>,
+) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    find(&site, post_id)?;
+    if !site.can(&authenticated, "edit_posts") {
+        return Err(WpRequest::forbidden(
+            &authenticated,
+            WpErrorCode::CannotEdit,
+            "Sorry, you are not allowed to edit this post.",
+        ));
+    }
+    let post = site.posts.get_mut(&post_id).expect("Post was just found");
+    if let Value::Object(body) = &body.0 {
+        for (key, value) in body {
+            match (key.as_str(), value.as_str()) {
+                ("title", Some(title)) => {
+                    post["title"] = json!({ "raw": title, "rendered": title });
+                }
+                ("content" | "excerpt", Some(text)) => {
+                    post[key]["raw"] = json!(text);
+                    post[key]["rendered"] = json!(format!("<p>{}</p>\n", text));
+                }
+                _ if post.get(key).is_some() => post[key] = value.clone(),
+                // Unknown fields are ignored by WordPress as well
+                _ => continue,
+            }
+        }
+    }
+    post["modified"] = json!(now());
+    post["modified_gmt"] = json!(now());
+    Ok(WpRestResponse::ok(PostContexts::filter(
+        post,
+        WpContext::Edit,
+    )))
+}
+
+fn find(site: &FakeSite, post_id: i32) -> Result<&Value, WpRestError> {
+    site.posts.get(&post_id).ok_or_else(|| {
+        WpRestError::custom(Status::NotFound, "rest_post_invalid_id", "Invalid post ID.")
+    })
+}
+
+pub(crate) fn seed_posts(site: &mut FakeSite) {
+    let seeds = (1..=12)
+        .map(|i| {
+            (
+                format!("Hello world #{}", i),
+                if i % 3 == 0 { 2 } else { 1 },
+                "publish",
+            )
+        })
+        .chain([
+            ("A draft".to_string(), 1, "draft"),
+            ("A private post".to_string(), 1, "private"),
+        ]);
+    for (index, (title, author, status)) in seeds.enumerate() {
+        let id = index as i32 + 1;
+        let slug = crate::site::sanitize_slug(&title)
+            .split('-')
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+        let date = format!("2024-01-{:02}T10:00:00", id);
+        let content = format!("Welcome to post #{}. This is the content.", id);
+        site.posts.insert(
+            id,
+            json!({
+                "id": id,
+                "date": date,
+                "date_gmt": date,
+                "guid": { "raw": site_link(&format!("/?p={}", id)), "rendered": site_link(&format!("/?p={}", id)) },
+                "link": site_link(&format!("/{}/", slug)),
+                "modified": date,
+                "modified_gmt": date,
+                "slug": slug,
+                "status": status,
+                "type": "post",
+                "password": "",
+                "permalink_template": site_link("/%postname%/"),
+                "generated_slug": slug,
+                "title": { "raw": title, "rendered": title },
+                "content": {
+                    "raw": content,
+                    "rendered": format!("<p>{}</p>\n", content),
+                    "protected": false,
+                    "block_version": 0,
+                },
+                "author": author,
+                "excerpt": {
+                    "raw": "",
+                    "rendered": format!("<p>{}</p>\n", content),
+                    "protected": false,
+                },
+                "featured_media": 0,
+                "comment_status": "open",
+                "ping_status": "open",
+                "format": "standard",
+                "meta": { "footnotes": "" },
+                "sticky": id == 1,
+                "template": "",
+                "categories": [1],
+                "tags": [],
+            }),
+        );
+    }
+}
+
+// Dates are compared as strings, which works for the `YYYY-MM-DDTHH:MM:SS` format WordPress uses
+fn is_after(date: &Value, after: &Option<String>) -> bool {
+    after.as_ref().map_or(true, |after| {
+        date.as_str().unwrap_or_default() > &after[..after.len().min(19)]
+    })
+}
+
+fn is_before(date: &Value, before: &Option<String>) -> bool {
+    before.as_ref().map_or(true, |before| {
+        date.as_str().unwrap_or_default() < &before[..before.len().min(19)]
+    })
+}
@@ -0,0 +1,196 @@
+use std::io::Cursor;
+
+use rocket::{
+    catch,
+    http::{ContentType, Header, Status},
+    response::{self, Responder},
+    Request, Response,
+};
+use serde_json::{json, Value};
+use url::Url;
+use wp_api::WpErrorCode;
+
+use crate::{site::FAKE_SITE_URL, wp_request::WpRequest};
+
+pub(crate) type WpRestResult = Result<WpRestResponse, WpRestError>;
+
+const DEFAULT_PER_PAGE: u32 = 10;
+const MAX_PER_PAGE: u32 = 100;
+
+#[derive(Debug)]
+pub(crate) struct WpRestResponse {
+    status: Status,
+    body: Value,
+    headers: Vec<Header<'static>>,
+}
+
+impl WpRestResponse {
+    pub fn ok(body: Value) -> Self {
+        Self {
+            status: Status::Ok,
+            body,
+            headers: Vec::new(),
+        }
+    }
+
+    pub fn created(body: Value) -> Self {
+        Self {
+            status: Status::Created,
+            body,
+            headers: Vec::new(),
+        }
+    }
+
+    // Mirrors `WP_REST_Posts_Controller::get_items` & co: the requested page of `items` is
+    // returned along with the `X-WP-Total`, `X-WP-TotalPages` & `Link` headers.
+    pub fn paginated(
+        request: &WpRequest,
+        items: Vec<Value>,
+        invalid_page_number_code: Option<WpErrorCode>,
+    ) -> WpRestResult {
+        let per_page = request.u32_param("per_page")?.unwrap_or(DEFAULT_PER_PAGE);
+        if per_page == 0 || per_page > MAX_PER_PAGE {
+            return Err(WpRestError::invalid_param("per_page"));
+        }
+        let page = request.u32_param("page")?.unwrap_or(1);
+        if page == 0 {
+            return Err(WpRestError::invalid_param("page"));
+        }
+        let offset = request.u32_param("offset")?;
+
+        let total = items.len();
+        let total_pages = total.div_ceil(per_page as usize);
+        if let Some(error_code) = invalid_page_number_code {
+            if page as usize > total_pages && total > 0 {
+                return Err(WpRestError::new(
+                    Status::BadRequest,
+                    error_code,
+                    "The page number requested is larger than the number of pages available.",
+                ));
+            }
+        }
+        let start = offset
+            .map(|o| o as usize)
+            .unwrap_or((page as usize - 1) * per_page as usize);
+        let body = items
+            .into_iter()
+            .skip(start)
+            .take(per_page as usize)
+            .collect::<Vec<_>>();
+
+        let mut headers = vec![
+            Header::new("X-WP-Total", total.to_string()),
+            Header::new("X-WP-TotalPages", total_pages.to_string()),
+        ];
+        let mut links = Vec::new();
+        if page > 1 {
+            let prev_page = page.min(total_pages as u32 + 1) - 1;
+            links.push(format!(
+                "<{}>; rel=\"prev\"",
+                request.url_for_page(prev_page)
+            ));
+        }
+        if (page as usize) < total_pages {
+            links.push(format!(
+                "<{}>; rel=\"next\"",
+                request.url_for_page(page + 1)
+            ));
+        }
+        if !links.is_empty() {
+            headers.push(Header::new("Link", links.join(", ")));
+        }
+        Ok(Self {
+            status: Status::Ok,
+            body: Value::Array(body),
+            headers,
+        })
+    }
+}
+
+impl<'r> Responder<'r, 'static> for WpRestResponse {
+    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'static> {
+        let body = self.body.to_string();
+        let mut response = Response::build();
+        response
+            .status(self.status)
+            .header(ContentType::JSON)
+            .sized_body(body.len(), Cursor::new(body));
+        for header in self.headers {
+            response.header_adjoin(header);
+        }
+        response.ok()
+    }
+}
+
+// An error response in the shape of `WP_Error`: `{ "code", "message", "data": { "status" } }`
+#[derive(Debug)]
+pub(crate) struct WpRestError {
+    status: Status,
+    code: WpErrorCode,
+    message: String,
+}
+
+impl WpRestError {
+    pub fn new(status: Status, code: WpErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            code,
+            message: message.into(),
+        }
+    }
+
+    pub fn custom(status: Status, code: &str, message: impl Into<String>) -> Self {
+        Self::new(status, WpErrorCode::CustomError(code.to_string()), message)
+    }
+
+    pub fn invalid_param(param: &str) -> Self {
+        Self::new(
+            Status::BadRequest,
+            WpErrorCode::InvalidParam,
+            format!("Invalid parameter(s): {}", param),
+        )
+    }
+
+    pub fn missing_param(param: &str) -> Self {
+        Self::custom(
+            Status::BadRequest,
+            "rest_missing_callback_param",
+            format!("Missing parameter(s): {}", param),
+        )
+    }
+}
+
+impl<'r> Responder<'r, 'static> for WpRestError {
+    fn respond_to(self, request: &'r Request<'_>) -> response::Result<'static> {
+        WpRestResponse {
+            status: self.status,
+            body: json!({
+                "code": self.code,
+                "message": self.message,
+                "data": { "status": self.status.code },
+            }),
+            headers: Vec::new(),
+        }
+        .respond_to(request)
+    }
+}
+
+#[catch(default)]
+pub(crate) fn no_route(status: Status, _: &Request) -> WpRestError {
+    if status == Status::NotFound {
+        WpRestError::custom(
+            status,
+            "rest_no_route",
+            "No route was found matching the URL and request method.",
+        )
+    } else {
+        WpRestError::custom(status, "rest_invalid_request", status.to_string())
+    }
+}
+
+pub(crate) fn site_link(path: &str) -> String {
+    Url::parse(FAKE_SITE_URL)
+        .and_then(|url| url.join(path))
+        .map(|url| url.to_string())
+        .expect("Fake site links are valid")
+}
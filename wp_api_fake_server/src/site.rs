@@ -0,0 +1,370 @@
+use std::collections::BTreeMap;
+
+use rocket::http::Status;
+use serde_json::{json, Value};
+use wp_api::WpErrorCode;
+
+use crate::response::{site_link, WpRestError};
+
+pub const FAKE_SITE_URL: &str = "https://fake.wordpress.test";
+pub const FAKE_ADMIN_USERNAME: &str = "test";
+pub const FAKE_ADMIN_PASSWORD: &str = "FakeAdminPassword0000000";
+pub const FAKE_AUTHOR_USERNAME: &str = "themedemos";
+pub const FAKE_AUTHOR_PASSWORD: &str = "FakeAuthorPassword000000";
+pub const FAKE_SUBSCRIBER_USERNAME: &str = "subscriber";
+pub const FAKE_SUBSCRIBER_PASSWORD: &str = "FakeSubscriberPassword00";
+
+const FAKE_CLIENT_IP: &str = "127.0.0.1";
+
+// Plugins that can be installed from the fake WordPress.org plugin directory:
+// `(wp_org_directory_slug, plugin, name, version)`
+pub(crate) const WP_ORG_PLUGINS: &[(&str, &str, &str, &str)] = &[
+    (
+        "classic-editor",
+        "classic-editor/classic-editor",
+        "Classic Editor",
+        "1.6.4",
+    ),
+    (
+        "classic-widgets",
+        "classic-widgets/classic-widgets",
+        "Classic Widgets",
+        "0.3",
+    ),
+    ("hello-dolly", "hello-dolly/hello", "Hello Dolly", "1.7.2"),
+];
+
+#[derive(Debug)]
+pub(crate) struct FakeSite {
+    pub users: BTreeMap<i32, FakeUser>,
+    pub plugins: BTreeMap<String, Value>,
+    pub settings: Value,
+    pub posts: BTreeMap<i32, Value>,
+    next_user_id: i32,
+}
+
+#[derive(Debug)]
+pub(crate) struct FakeUser {
+    // The user in the shape of `UserWithEditContext`
+    pub record: Value,
+    pub application_passwords: Vec<FakeApplicationPassword>,
+}
+
+#[derive(Debug)]
+pub(crate) struct FakeApplicationPassword {
+    // The application password in the shape of `ApplicationPasswordWithEditContext`, without the
+    // `password` which is only returned when it's created
+    pub record: Value,
+    pub password: String,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Authenticated {
+    pub user_id: i32,
+    pub application_password_uuid: String,
+}
+
+impl FakeSite {
+    pub fn seeded() -> Self {
+        let mut site = Self {
+            users: BTreeMap::new(),
+            plugins: BTreeMap::new(),
+            settings: seed_settings(),
+            posts: BTreeMap::new(),
+            next_user_id: 1,
+        };
+        for (username, email, name, role, password) in [
+            (
+                FAKE_ADMIN_USERNAME,
+                "test@example.com",
+                "test",
+                "administrator",
+                FAKE_ADMIN_PASSWORD,
+            ),
+            (
+                FAKE_AUTHOR_USERNAME,
+                "themeshaperwp+demos@gmail.com",
+                "Theme Demos",
+                "author",
+                FAKE_AUTHOR_PASSWORD,
+            ),
+            (
+                FAKE_SUBSCRIBER_USERNAME,
+                "subscriber@example.com",
+                "Subscriber",
+                "subscriber",
+                FAKE_SUBSCRIBER_PASSWORD,
+            ),
+        ] {
+            let user_id = site.insert_user(username, email, name, role);
+            let (mut record, _) = new_application_password_record("Test", "");
+            record["uuid"] = json!(format!("00000000-0000-4000-8000-00000000000{}", user_id));
+            site.user_mut(user_id)
+                .expect("User was just inserted")
+                .application_passwords
+                .push(FakeApplicationPassword {
+                    record,
+                    password: password.to_string(),
+                });
+        }
+        for (plugin, status) in [
+            ("classic-editor/classic-editor", "active"),
+            ("hello-dolly/hello", "inactive"),
+        ] {
+            let record =
+                new_plugin_record(plugin, status).expect("Seed plugins are in the catalog");
+            site.plugins.insert(plugin.to_string(), record);
+        }
+        crate::posts::seed_posts(&mut site);
+        site
+    }
+
+    pub fn insert_user(&mut self, username: &str, email: &str, name: &str, role: &str) -> i32 {
+        let id = self.next_user_id;
+        self.next_user_id += 1;
+        let slug = sanitize_slug(username);
+        let record = json!({
+            "id": id,
+            "username": username,
+            "name": name,
+            "first_name": "",
+            "last_name": "",
+            "email": email,
+            "url": "",
+            "description": "",
+            "link": site_link(&format!("/author/{}/", slug)),
+            "locale": "en_US",
+            "nickname": username,
+            "slug": slug,
+            "registered_date": now(),
+            "roles": [role],
+            "capabilities": role_capabilities(role),
+            "extra_capabilities": { role: true },
+            "avatar_urls": {
+                "24": format!("https://secure.gravatar.com/avatar/{}?s=24&d=mm&r=g", id),
+                "48": format!("https://secure.gravatar.com/avatar/{}?s=48&d=mm&r=g", id),
+                "96": format!("https://secure.gravatar.com/avatar/{}?s=96&d=mm&r=g", id),
+            },
+        });
+        self.users.insert(
+            id,
+            FakeUser {
+                record,
+                application_passwords: Vec::new(),
+            },
+        );
+        id
+    }
+
+    pub fn user(&self, user_id: i32) -> Result<&FakeUser, WpRestError> {
+        self.users.get(&user_id).ok_or_else(invalid_user_id)
+    }
+
+    pub fn user_mut(&mut self, user_id: i32) -> Result<&mut FakeUser, WpRestError> {
+        self.users.get_mut(&user_id).ok_or_else(invalid_user_id)
+    }
+
+    pub fn authenticate(
+        &mut self,
+        username: &str,
+        password: &str,
+    ) -> Result<Authenticated, WpRestError> {
+        let password = password.replace(' ', "");
+        let (user_id, user) = self
+            .users
+            .iter_mut()
+            .find(|(_, user)| {
+                user.record["username"] == username || user.record["email"] == username
+            })
+            .ok_or_else(|| {
+                WpRestError::custom(
+                    Status::Unauthorized,
+                    "invalid_username",
+                    "Unknown username. Check again or try your email address.",
+                )
+            })?;
+        let application_password = user
+            .application_passwords
+            .iter_mut()
+            .find(|p| p.password == password)
+            .ok_or_else(|| {
+                WpRestError::custom(
+                    Status::Unauthorized,
+                    "incorrect_password",
+                    "The provided password is an invalid application password.",
+                )
+            })?;
+        application_password.record["last_used"] = json!(now());
+        application_password.record["last_ip"] = json!(FAKE_CLIENT_IP);
+        Ok(Authenticated {
+            user_id: *user_id,
+            application_password_uuid: application_password.record["uuid"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+        })
+    }
+
+    pub fn can(&self, authenticated: &Option<Authenticated>, capability: &str) -> bool {
+        authenticated
+            .as_ref()
+            .and_then(|a| self.users.get(&a.user_id))
+            .is_some_and(|user| user.record["capabilities"][capability] == true)
+    }
+}
+
+fn invalid_user_id() -> WpRestError {
+    WpRestError::new(
+        Status::NotFound,
+        WpErrorCode::UserInvalidId,
+        "Invalid user ID.",
+    )
+}
+
+pub(crate) fn role_capabilities(role: &str) -> Option<Value> {
+    let capabilities: &[&str] = match role {
+        "administrator" => &[
+            "activate_plugins",
+            "create_users",
+            "delete_plugins",
+            "delete_users",
+            "edit_others_posts",
+            "edit_plugins",
+            "edit_posts",
+            "edit_published_posts",
+            "edit_users",
+            "install_plugins",
+            "list_users",
+            "manage_options",
+            "promote_users",
+            "publish_posts",
+            "read",
+            "read_private_posts",
+            "update_plugins",
+        ],
+        "editor" => &[
+            "edit_others_posts",
+            "edit_posts",
+            "edit_published_posts",
+            "publish_posts",
+            "read",
+            "read_private_posts",
+        ],
+        "author" => &[
+            "edit_posts",
+            "edit_published_posts",
+            "publish_posts",
+            "read",
+            "upload_files",
+        ],
+        "contributor" => &["edit_posts", "read"],
+        "subscriber" => &["read"],
+        _ => return None,
+    };
+    let mut map = capabilities
+        .iter()
+        .map(|c| (c.to_string(), json!(true)))
+        .collect::<serde_json::Map<_, _>>();
+    map.insert(role.to_string(), json!(true));
+    Some(Value::Object(map))
+}
+
+pub(crate) fn new_application_password_record(name: &str, app_id: &str) -> (Value, String) {
+    let record = json!({
+        "uuid": uuid::Uuid::new_v4().to_string(),
+        "app_id": app_id,
+        "name": name,
+        "created": now(),
+        "last_used": null,
+        "last_ip": null,
+    });
+    (record, generate_application_password())
+}
+
+pub(crate) fn new_plugin_record(plugin: &str, status: &str) -> Option<Value> {
+    let (directory_slug, plugin, name, version) =
+        WP_ORG_PLUGINS.iter().find(|(_, p, _, _)| *p == plugin)?;
+    let description = format!("The {} plugin.", name);
+    Some(json!({
+        "plugin": plugin,
+        "status": status,
+        "name": name,
+        "plugin_uri": format!("https://wordpress.org/plugins/{}/", directory_slug),
+        "author": "WordPress Contributors",
+        "author_uri": "https://wordpress.org/",
+        "description": {
+            "raw": description,
+            "rendered": format!("{} <cite>By <a href=\"https://wordpress.org/\">WordPress Contributors</a>.</cite>", description),
+        },
+        "version": version,
+        "network_only": false,
+        "requires_wp": "",
+        "requires_php": "",
+        "textdomain": directory_slug,
+    }))
+}
+
+fn seed_settings() -> Value {
+    json!({
+        "title": "Fake WordPress Site",
+        "description": "Just another WordPress site",
+        "url": FAKE_SITE_URL,
+        "email": "test@example.com",
+        "timezone": "",
+        "date_format": "F j, Y",
+        "time_format": "g:i a",
+        "start_of_week": 1,
+        "language": "en_US",
+        "use_smilies": true,
+        "default_category": 1,
+        "default_post_format": "0",
+        "posts_per_page": 10,
+        "show_on_front": "posts",
+        "page_on_front": 0,
+        "page_for_posts": 0,
+        "default_ping_status": "open",
+        "default_comment_status": "open",
+        "site_logo": null,
+        "site_icon": 0,
+    })
+}
+
+pub(crate) fn compare_json(a: &Value, b: &Value) -> std::cmp::Ordering {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a
+            .as_f64()
+            .partial_cmp(&b.as_f64())
+            .unwrap_or(std::cmp::Ordering::Equal),
+        _ => a
+            .as_str()
+            .unwrap_or_default()
+            .to_lowercase()
+            .cmp(&b.as_str().unwrap_or_default().to_lowercase()),
+    }
+}
+
+pub(crate) fn sanitize_slug(value: &str) -> String {
+    value
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect()
+}
+
+// WordPress formats dates without a timezone, i.e. `2024-08-06T10:11:12`
+pub(crate) fn now() -> String {
+    chrono::Utc::now().format("%Y-%m-%dT%H:%M:%S").to_string()
+}
+
+// Application passwords are 24 alphanumeric characters, which WordPress displays in chunks of 4
+// separated by spaces.
+fn generate_application_password() -> String {
+    const PASSWORD_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+    uuid::Uuid::new_v4()
+        .as_bytes()
+        .iter()
+        .chain(uuid::Uuid::new_v4().as_bytes().iter())
+        .take(24)
+        .map(|b| PASSWORD_CHARS[*b as usize % PASSWORD_CHARS.len()] as char)
+        .collect()
+}
@@ -0,0 +1,81 @@
+use std::sync::Mutex;
+
+use rocket::{get, post, serde::json::Json, Route, State};
+use serde_json::Value;
+use wp_api::{
+    site_settings::{
+        SiteSettingsWithEditContext, SiteSettingsWithEmbedContext, SiteSettingsWithViewContext,
+    },
+    WpErrorCode,
+};
+
+use crate::{
+    response::{WpRestError, WpRestResponse, WpRestResult},
+    site::{Authenticated, FakeSite},
+    wp_request::{ContextualTypes, WpRequest},
+};
+
+type SiteSettingsContexts = (
+    SiteSettingsWithEditContext,
+    SiteSettingsWithViewContext,
+    SiteSettingsWithEmbedContext,
+);
+
+pub(crate) fn routes() -> Vec<Route> {
+    rocket::routes![retrieve_site_settings, update_site_settings]
+}
+
+#[get("/settings")]
+fn retrieve_site_settings(site: &State<Mutex<FakeSite>>, request: WpRequest) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    check_permission(&site, &authenticated)?;
+    Ok(WpRestResponse::ok(
+        request.render::<SiteSettingsContexts>(&site.settings)?,
+    ))
+}
+
+#[post("/settings", data = "<body>")]
+fn update_site_settings(
+    site: &State<Mutex<FakeSite>>,
+    request: WpRequest,
+    body: Json<Value>,
+) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    check_permission(&site, &authenticated)?;
+    let mut settings = site.settings.clone();
+    if let (Value::Object(settings), Value::Object(body)) = (&mut settings, &body.0) {
+        for (key, value) in body {
+            match settings.get_mut(key) {
+                Some(setting) => *setting = value.clone(),
+                // Unknown settings are ignored by WordPress as well
+                None => continue,
+            }
+        }
+    }
+    // Settings of the wrong type are rejected by the schema validation
+    if let Err(e) = serde_json::from_value::<SiteSettingsWithEditContext>(settings.clone()) {
+        return Err(WpRestError::invalid_param(&e.to_string()));
+    }
+    site.settings = settings;
+    Ok(WpRestResponse::ok(SiteSettingsContexts::filter(
+        &site.settings,
+        wp_api::WpContext::Edit,
+    )))
+}
+
+fn check_permission(
+    site: &FakeSite,
+    authenticated: &Option<Authenticated>,
+) -> Result<(), WpRestError> {
+    if site.can(authenticated, "manage_options") {
+        Ok(())
+    } else {
+        Err(WpRequest::forbidden(
+            authenticated,
+            WpErrorCode::Forbidden,
+            "Sorry, you are not allowed to do that.",
+        ))
+    }
+}
@@ -0,0 +1,468 @@
+use std::sync::Mutex;
+
+use rocket::{delete, get, http::Status, post, serde::json::Json, Route, State};
+use serde_json::{json, Value};
+use wp_api::{
+    users::{
+        UserListParams, UserWithEditContext, UserWithEmbedContext, UserWithViewContext,
+        WpApiParamUsersOrderBy,
+    },
+    WpApiParamOrder, WpErrorCode,
+};
+
+use crate::{
+    response::{WpRestError, WpRestResponse, WpRestResult},
+    site::{compare_json, role_capabilities, sanitize_slug, Authenticated, FakeSite},
+    wp_request::{ContextualTypes, WpRequest},
+};
+
+pub(crate) type UserContexts = (
+    UserWithEditContext,
+    UserWithViewContext,
+    UserWithEmbedContext,
+);
+
+// Fields of `UserUpdateParams` that are copied to the user as they are
+const EDITABLE_FIELDS: &[&str] = &[
+    "name",
+    "first_name",
+    "last_name",
+    "url",
+    "description",
+    "locale",
+    "nickname",
+];
+
+pub(crate) fn routes() -> Vec<Route> {
+    rocket::routes![
+        list_users,
+        create_user,
+        retrieve_user,
+        retrieve_me,
+        update_user,
+        update_me,
+        delete_user,
+        delete_me
+    ]
+}
+
+#[get("/users")]
+fn list_users(site: &State<Mutex<FakeSite>>, request: WpRequest) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    let can_list_users = site.can(&authenticated, "list_users");
+    if request.context()? == wp_api::WpContext::Edit && !can_list_users {
+        return Err(WpRequest::forbidden(
+            &authenticated,
+            WpErrorCode::ForbiddenContext,
+            "Sorry, you are not allowed to list users.",
+        ));
+    }
+    let params = UserListParams::from_query_pairs(request.query_pairs());
+    if !can_list_users
+        && matches!(
+            params.orderby,
+            Some(WpApiParamUsersOrderBy::Email | WpApiParamUsersOrderBy::RegisteredDate)
+        )
+    {
+        return Err(WpRequest::forbidden(
+            &authenticated,
+            WpErrorCode::ForbiddenOrderBy,
+            "Sorry, you are not allowed to order users by this parameter.",
+        ));
+    }
+
+    let mut users = site
+        .users
+        .values()
+        .map(|u| &u.record)
+        // Users without published posts are only visible to the ones who can list users
+        .filter(|u| can_list_users || has_published_posts(&site, u))
+        .filter(|u| params.include.is_empty() || params.include.iter().any(|id| u["id"] == id.0))
+        .filter(|u| !params.exclude.iter().any(|id| u["id"] == id.0))
+        .filter(|u| params.slug.is_empty() || params.slug.iter().any(|s| u["slug"] == *s))
+        .filter(|u| {
+            params.roles.is_empty()
+                || params.roles.iter().any(|r| {
+                    u["roles"]
+                        .as_array()
+                        .is_some_and(|roles| roles.contains(&json!(r)))
+                })
+        })
+        .filter(|u| {
+            params.search.as_ref().map_or(true, |search| {
+                let search = search.trim_matches('*').to_lowercase();
+                ["username", "email", "name", "slug", "url"]
+                    .iter()
+                    .any(|f| {
+                        u[*f]
+                            .as_str()
+                            .unwrap_or_default()
+                            .to_lowercase()
+                            .contains(&search)
+                    })
+            })
+        })
+        .collect::<Vec<_>>();
+    let orderby_field = match params.orderby.unwrap_or_default() {
+        WpApiParamUsersOrderBy::Id | WpApiParamUsersOrderBy::Include => "id",
+        WpApiParamUsersOrderBy::Name => "name",
+        WpApiParamUsersOrderBy::RegisteredDate => "registered_date",
+        WpApiParamUsersOrderBy::Slug | WpApiParamUsersOrderBy::IncludeSlugs => "slug",
+        WpApiParamUsersOrderBy::Email => "email",
+        WpApiParamUsersOrderBy::Url => "url",
+    };
+    users.sort_by(|a, b| compare_json(&a[orderby_field], &b[orderby_field]));
+    if params.order == Some(WpApiParamOrder::Desc) {
+        users.reverse();
+    }
+    let users = users
+        .into_iter()
+        .map(|u| request.render::<UserContexts>(u))
+        .collect::<Result<Vec<_>, _>>()?;
+    WpRestResponse::paginated(&request, users, None)
+}
+
+#[post("/users", data = "<body>")]
+fn create_user(
+    site: &State<Mutex<FakeSite>>,
+    request: WpRequest,
+    body: Json<Value>,
+) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    if !site.can(&authenticated, "create_users") {
+        return Err(WpRequest::forbidden(
+            &authenticated,
+            WpErrorCode::CannotCreateUser,
+            "Sorry, you are not allowed to create new users.",
+        ));
+    }
+    let [username, email, password] = ["username", "email", "password"].map(|f| {
+        body[f]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| WpRestError::missing_param(f))
+    });
+    let (username, email, password) = (username?, email?, password?);
+    validate_username(&username)?;
+    validate_password(&password)?;
+    validate_email(&email)?;
+    if site
+        .users
+        .values()
+        .any(|u| u.record["username"] == username)
+    {
+        return Err(WpRestError::custom(
+            Status::InternalServerError,
+            "existing_user_login",
+            "Sorry, that username already exists!",
+        ));
+    }
+    if site.users.values().any(|u| u.record["email"] == email) {
+        return Err(WpRestError::custom(
+            Status::InternalServerError,
+            "existing_user_email",
+            "Sorry, that email address is already used!",
+        ));
+    }
+    let roles = requested_roles(&body)?.unwrap_or_else(|| vec!["subscriber".to_string()]);
+    let user_id = site.insert_user(&username, &email, &username, &roles[0]);
+    update_user_record(&mut site, &authenticated, user_id, &body)?;
+    Ok(WpRestResponse::created(render_edit(&site, user_id)?))
+}
+
+#[get("/users/<user_id>", rank = 2)]
+fn retrieve_user(site: &State<Mutex<FakeSite>>, request: WpRequest, user_id: i32) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    retrieve(&site, &request, &authenticated, user_id)
+}
+
+#[get("/users/me")]
+fn retrieve_me(site: &State<Mutex<FakeSite>>, request: WpRequest) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    let user_id = current_user_id(&authenticated)?;
+    retrieve(&site, &request, &authenticated, user_id)
+}
+
+#[post("/users/<user_id>", data = "<body>", rank = 2)]
+fn update_user(
+    site: &State<Mutex<FakeSite>>,
+    request: WpRequest,
+    user_id: i32,
+    body: Json<Value>,
+) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    update(&mut site, &authenticated, user_id, &body)
+}
+
+#[post("/users/me", data = "<body>")]
+fn update_me(site: &State<Mutex<FakeSite>>, request: WpRequest, body: Json<Value>) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    let user_id = current_user_id(&authenticated)?;
+    update(&mut site, &authenticated, user_id, &body)
+}
+
+#[delete("/users/<user_id>", rank = 2)]
+fn delete_user(site: &State<Mutex<FakeSite>>, request: WpRequest, user_id: i32) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    delete(&mut site, &request, &authenticated, user_id)
+}
+
+#[delete("/users/me")]
+fn delete_me(site: &State<Mutex<FakeSite>>, request: WpRequest) -> WpRestResult {
+    let mut site = site.lock().expect("Fake site lock is not poisoned");
+    let authenticated = request.authenticate(&mut site)?;
+    let user_id = current_user_id(&authenticated)?;
+    delete(&mut site, &request, &authenticated, user_id)
+}
+
+fn retrieve(
+    site: &FakeSite,
+    request: &WpRequest,
+    authenticated: &Option<Authenticated>,
+    user_id: i32,
+) -> WpRestResult {
+    let user = site.user(user_id)?;
+    let is_current_user = authenticated.as_ref().is_some_and(|a| a.user_id == user_id);
+    let can_list_users = site.can(authenticated, "list_users");
+    if request.context()? == wp_api::WpContext::Edit && !is_current_user && !can_list_users {
+        return Err(WpRequest::forbidden(
+            authenticated,
+            WpErrorCode::UserCannotView,
+            "Sorry, you are not allowed to list users.",
+        ));
+    }
+    if !is_current_user && !can_list_users && !has_published_posts(site, &user.record) {
+        return Err(WpRequest::forbidden(
+            authenticated,
+            WpErrorCode::UserCannotView,
+            "Sorry, you are not allowed to list users.",
+        ));
+    }
+    Ok(WpRestResponse::ok(
+        request.render::<UserContexts>(&user.record)?,
+    ))
+}
+
+fn update(
+    site: &mut FakeSite,
+    authenticated: &Option<Authenticated>,
+    user_id: i32,
+    body: &Value,
+) -> WpRestResult {
+    site.user(user_id)?;
+    let is_current_user = authenticated.as_ref().is_some_and(|a| a.user_id == user_id);
+    if !is_current_user && !site.can(authenticated, "edit_users") {
+        return Err(WpRequest::forbidden(
+            authenticated,
+            WpErrorCode::CannotEdit,
+            "Sorry, you are not allowed to edit this user.",
+        ));
+    }
+    if body.get("username").is_some() {
+        return Err(WpRestError::new(
+            Status::BadRequest,
+            WpErrorCode::UserInvalidArgument,
+            "Username isn't editable.",
+        ));
+    }
+    update_user_record(site, authenticated, user_id, body)?;
+    Ok(WpRestResponse::ok(render_edit(site, user_id)?))
+}
+
+fn delete(
+    site: &mut FakeSite,
+    request: &WpRequest,
+    authenticated: &Option<Authenticated>,
+    user_id: i32,
+) -> WpRestResult {
+    site.user(user_id)?;
+    if !site.can(authenticated, "delete_users") {
+        return Err(WpRequest::forbidden(
+            authenticated,
+            WpErrorCode::UserCannotDelete,
+            "Sorry, you are not allowed to delete this user.",
+        ));
+    }
+    if request.bool_param("force")? != Some(true) {
+        return Err(WpRestError::new(
+            Status::NotImplemented,
+            WpErrorCode::TrashNotSupported,
+            "Users do not support trashing. Set 'force' to delete.",
+        ));
+    }
+    let reassign = request
+        .param("reassign")
+        .ok_or_else(|| WpRestError::missing_param("reassign"))?
+        .parse::<i32>()
+        .map_err(|_| WpRestError::invalid_param("reassign"))?;
+    if reassign == user_id || !site.users.contains_key(&reassign) {
+        return Err(WpRestError::new(
+            Status::BadRequest,
+            WpErrorCode::UserInvalidReassign,
+            "Invalid user ID for reassignment.",
+        ));
+    }
+    let previous = render_edit(site, user_id)?;
+    site.users.remove(&user_id);
+    site.posts
+        .values_mut()
+        .filter(|post| post["author"] == user_id)
+        .for_each(|post| post["author"] = json!(reassign));
+    Ok(WpRestResponse::ok(json!({
+        "deleted": true,
+        "previous": previous,
+    })))
+}
+
+fn update_user_record(
+    site: &mut FakeSite,
+    authenticated: &Option<Authenticated>,
+    user_id: i32,
+    body: &Value,
+) -> Result<(), WpRestError> {
+    if let Some(email) = body["email"].as_str() {
+        validate_email(email)?;
+        if site
+            .users
+            .values()
+            .any(|u| u.record["email"] == email && u.record["id"] != user_id)
+        {
+            return Err(WpRestError::new(
+                Status::BadRequest,
+                WpErrorCode::UserInvalidEmail,
+                "Invalid email address.",
+            ));
+        }
+    }
+    if let Some(slug) = body["slug"].as_str() {
+        if site
+            .users
+            .values()
+            .any(|u| u.record["slug"] == sanitize_slug(slug) && u.record["id"] != user_id)
+        {
+            return Err(WpRestError::new(
+                Status::BadRequest,
+                WpErrorCode::UserInvalidSlug,
+                "Invalid slug.",
+            ));
+        }
+    }
+    if let Some(password) = body["password"].as_str() {
+        validate_password(password)?;
+    }
+    let roles = requested_roles(body)?;
+    if roles.is_some() && !site.can(authenticated, "promote_users") {
+        return Err(WpRequest::forbidden(
+            authenticated,
+            WpErrorCode::CannotEditRoles,
+            "Sorry, you are not allowed to edit roles of this user.",
+        ));
+    }
+
+    let record = &mut site.user_mut(user_id)?.record;
+    for field in EDITABLE_FIELDS {
+        if let Some(value) = body[*field].as_str() {
+            record[*field] = json!(value);
+        }
+    }
+    if let Some(email) = body["email"].as_str() {
+        record["email"] = json!(email);
+    }
+    if let Some(slug) = body["slug"].as_str() {
+        record["slug"] = json!(sanitize_slug(slug));
+    }
+    if let Some(role) = roles.as_ref().and_then(|roles| roles.first()) {
+        record["roles"] = json!([role]);
+        record["capabilities"] = role_capabilities(role).unwrap_or_default();
+        record["extra_capabilities"] = json!({ role: true });
+    }
+    Ok(())
+}
+
+fn requested_roles(body: &Value) -> Result<Option<Vec<String>>, WpRestError> {
+    let Some(roles) = body.get("roles") else {
+        return Ok(None);
+    };
+    let roles = roles
+        .as_array()
+        .map(|roles| {
+            roles
+                .iter()
+                .filter_map(|r| r.as_str().map(str::to_string))
+                .collect::<Vec<_>>()
+        })
+        .ok_or_else(|| WpRestError::invalid_param("roles"))?;
+    if roles.is_empty() || roles.iter().any(|r| role_capabilities(r).is_none()) {
+        return Err(WpRestError::new(
+            Status::BadRequest,
+            WpErrorCode::UserInvalidRole,
+            "The role does not exist.",
+        ));
+    }
+    Ok(Some(roles))
+}
+
+fn validate_username(username: &str) -> Result<(), WpRestError> {
+    let is_valid = !username.trim().is_empty()
+        && username
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || " _.-@*".contains(c));
+    if is_valid {
+        Ok(())
+    } else {
+        Err(WpRestError::new(
+            Status::BadRequest,
+            WpErrorCode::UserInvalidUsername,
+            "This username is invalid because it uses illegal characters. Please enter a valid username.",
+        ))
+    }
+}
+
+fn validate_password(password: &str) -> Result<(), WpRestError> {
+    if password.contains('\\') {
+        Err(WpRestError::new(
+            Status::BadRequest,
+            WpErrorCode::UserInvalidPassword,
+            "Passwords cannot contain the \"\\\" character.",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_email(email: &str) -> Result<(), WpRestError> {
+    match email.split_once('@') {
+        Some((local, domain)) if !local.is_empty() && domain.contains('.') => Ok(()),
+        _ => Err(WpRestError::invalid_param("email")),
+    }
+}
+
+fn current_user_id(authenticated: &Option<Authenticated>) -> Result<i32, WpRestError> {
+    authenticated.as_ref().map(|a| a.user_id).ok_or_else(|| {
+        WpRestError::new(
+            Status::Unauthorized,
+            WpErrorCode::Unauthorized,
+            "You are not currently logged in.",
+        )
+    })
+}
+
+fn render_edit(site: &FakeSite, user_id: i32) -> Result<Value, WpRestError> {
+    Ok(UserContexts::filter(
+        &site.user(user_id)?.record,
+        wp_api::WpContext::Edit,
+    ))
+}
+
+fn has_published_posts(site: &FakeSite, user: &Value) -> bool {
+    site.posts
+        .values()
+        .any(|post| post["author"] == user["id"] && post["status"] == "publish")
+}
@@ -0,0 +1,176 @@
+use base64::prelude::*;
+use rocket::{
+    http::Status,
+    request::{FromRequest, Outcome},
+    Request,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use url::Url;
+use wp_api::{WpContext, WpErrorCode};
+
+use crate::{
+    response::WpRestError,
+    site::{Authenticated, FakeSite, FAKE_SITE_URL},
+};
+
+// The parts of an incoming request that are shared by all routes: the query parameters and the
+// credentials from the `Authorization` header.
+#[derive(Debug)]
+pub(crate) struct WpRequest {
+    path: String,
+    query_pairs: Vec<(String, String)>,
+    credentials: Option<(String, String)>,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for WpRequest {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, ()> {
+        let query_pairs = request
+            .uri()
+            .query()
+            .map(|query| {
+                url::form_urlencoded::parse(query.as_str().as_bytes())
+                    .into_owned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        let credentials = request
+            .headers()
+            .get_one("Authorization")
+            .map(|authorization| {
+                authorization
+                    .strip_prefix("Basic ")
+                    .and_then(|token| BASE64_STANDARD.decode(token).ok())
+                    .and_then(|decoded| String::from_utf8(decoded).ok())
+                    .and_then(|decoded| {
+                        decoded
+                            .split_once(':')
+                            .map(|(u, p)| (u.to_string(), p.to_string()))
+                    })
+                    .unwrap_or_default()
+            });
+        Outcome::Success(Self {
+            path: request.uri().path().to_string(),
+            query_pairs,
+            credentials,
+        })
+    }
+}
+
+impl WpRequest {
+    pub fn query_pairs(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.query_pairs
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    pub fn param(&self, key: &str) -> Option<&str> {
+        self.query_pairs()
+            .find_map(|(k, v)| (k == key).then_some(v))
+    }
+
+    pub fn u32_param(&self, key: &str) -> Result<Option<u32>, WpRestError> {
+        self.param(key)
+            .map(|v| v.parse().map_err(|_| WpRestError::invalid_param(key)))
+            .transpose()
+    }
+
+    pub fn bool_param(&self, key: &str) -> Result<Option<bool>, WpRestError> {
+        self.param(key)
+            .map(|v| match v {
+                "true" | "1" => Ok(true),
+                "false" | "0" => Ok(false),
+                _ => Err(WpRestError::invalid_param(key)),
+            })
+            .transpose()
+    }
+
+    pub fn context(&self) -> Result<WpContext, WpRestError> {
+        match self.param("context") {
+            None | Some("view") => Ok(WpContext::View),
+            Some("edit") => Ok(WpContext::Edit),
+            Some("embed") => Ok(WpContext::Embed),
+            Some(_) => Err(WpRestError::invalid_param("context")),
+        }
+    }
+
+    // Resolves the `Authorization` header the same way application passwords are checked by
+    // `wp_authenticate_application_password`. Unauthenticated requests resolve to `None`.
+    pub fn authenticate(&self, site: &mut FakeSite) -> Result<Option<Authenticated>, WpRestError> {
+        self.credentials
+            .as_ref()
+            .map(|(username, password)| site.authenticate(username, password))
+            .transpose()
+    }
+
+    // Returns the error a route uses for a missing capability: `401` for unauthenticated
+    // requests and `403` otherwise, just like `rest_authorization_required_code`.
+    pub fn forbidden(
+        authenticated: &Option<Authenticated>,
+        code: WpErrorCode,
+        message: &str,
+    ) -> WpRestError {
+        let status = match authenticated {
+            Some(_) => Status::Forbidden,
+            None => Status::Unauthorized,
+        };
+        WpRestError::new(status, code, message)
+    }
+
+    // Prepares a record for the response by removing the fields that are not available in the
+    // requested `context` & the ones that are not listed in `_fields`.
+    pub fn render<C: ContextualTypes>(&self, record: &Value) -> Result<Value, WpRestError> {
+        let mut value = C::filter(record, self.context()?);
+        if let (Some(fields), Value::Object(map)) = (self.param("_fields"), &mut value) {
+            let fields = fields.split(',').map(str::trim).collect::<Vec<_>>();
+            map.retain(|key, _| fields.contains(&key.as_str()));
+        }
+        Ok(value)
+    }
+
+    pub fn url_for_page(&self, page: u32) -> String {
+        let mut url = Url::parse(FAKE_SITE_URL)
+            .and_then(|url| url.join(&self.path))
+            .expect("Fake site links are valid");
+        url.query_pairs_mut()
+            .extend_pairs(self.query_pairs().filter(|(k, _)| *k != "page"))
+            .append_pair("page", &page.to_string());
+        url.to_string()
+    }
+}
+
+// The `(edit, view, embed)` context types of a record. Records are stored in their edit context
+// shape, and the other contexts are derived by round tripping them through the contextual types
+// generated by `WpContextual`.
+pub(crate) trait ContextualTypes {
+    fn filter(record: &Value, context: WpContext) -> Value;
+}
+
+impl<Edit, View, Embed> ContextualTypes for (Edit, View, Embed)
+where
+    Edit: Serialize + DeserializeOwned,
+    View: Serialize + DeserializeOwned,
+    Embed: Serialize + DeserializeOwned,
+{
+    fn filter(record: &Value, context: WpContext) -> Value {
+        match context {
+            WpContext::Edit => round_trip::<Edit>(record),
+            WpContext::View => round_trip::<View>(record),
+            WpContext::Embed => round_trip::<Embed>(record),
+        }
+    }
+}
+
+fn round_trip<T: Serialize + DeserializeOwned>(record: &Value) -> Value {
+    serde_json::from_value::<T>(record.clone())
+        .and_then(serde_json::to_value)
+        .unwrap_or_else(|e| {
+            panic!(
+                "Fake records should match their contextual types. Error: '{}', Record: '{}'",
+                e, record
+            )
+        })
+}
@@ -0,0 +1,629 @@
+use std::sync::Arc;
+
+use wp_api::{
+    application_passwords::{ApplicationPasswordCreateParams, ApplicationPasswordUpdateParams},
+    plugins::{
+        PluginActionError, PluginBulkActionResult, PluginCreateParams, PluginListParams,
+        PluginSlug, PluginStatus, PluginUpdateParams,
+    },
+    posts::{
+        merge_sparse_posts, PostId, PostListParams, PostUpdateError, PostUpdateParams,
+        PostWithViewContext, SparsePost,
+    },
+    request::RequestExecutor,
+    site_settings::SiteSettingsUpdateParams,
+    users::{
+        SparseUserFieldWithEditContext, UserCreateParams, UserDeleteParams, UserId, UserListParams,
+        UserUpdateParams,
+    },
+    WpApiError, WpApiRequestBuilder, WpAuthentication, WpErrorCode,
+};
+use wp_api_fake_server::{FakeWpServer, FAKE_ADMIN_PASSWORD, FAKE_ADMIN_USERNAME};
+
+const FIRST_USER_ID: UserId = UserId(1);
+const SECOND_USER_ID: UserId = UserId(2);
+const HELLO_DOLLY_PLUGIN_SLUG: &str = "hello-dolly/hello";
+const CLASSIC_EDITOR_PLUGIN_SLUG: &str = "classic-editor/classic-editor";
+
+async fn fake_server() -> Arc<FakeWpServer> {
+    Arc::new(FakeWpServer::new().await)
+}
+
+fn assert_wp_error<T: std::fmt::Debug>(
+    result: Result<T, WpApiError>,
+    expected_error_code: WpErrorCode,
+    expected_status_code: u16,
+) {
+    match result {
+        Err(WpApiError::WpError {
+            error_code,
+            status_code,
+            ..
+        }) => {
+            assert_eq!(expected_error_code, error_code);
+            assert_eq!(expected_status_code, status_code);
+        }
+        result => panic!("Expected '{:?}', found '{:?}'", expected_error_code, result),
+    }
+}
+
+#[tokio::test]
+async fn create_user() {
+    let server = fake_server().await;
+    let params = UserCreateParams::new(
+        "t_username".to_string(),
+        "t_email@example.com".to_string(),
+        "t_password".to_string(),
+    );
+    let created_user = server
+        .api_client_as_admin()
+        .users()
+        .create(&params)
+        .await
+        .unwrap();
+    assert_eq!(created_user.username, "t_username");
+    assert_eq!(created_user.roles, vec!["subscriber".to_string()]);
+
+    let retrieved_user = server
+        .api_client_as_admin()
+        .users()
+        .retrieve_with_edit_context(&created_user.id)
+        .await
+        .unwrap();
+    assert_eq!(retrieved_user.email, "t_email@example.com");
+}
+
+#[tokio::test]
+async fn create_user_err_existing_username() {
+    let server = fake_server().await;
+    let params = UserCreateParams::new(
+        FAKE_ADMIN_USERNAME.to_string(),
+        "t_email@example.com".to_string(),
+        "t_password".to_string(),
+    );
+    assert_wp_error(
+        server.api_client_as_admin().users().create(&params).await,
+        WpErrorCode::CustomError("existing_user_login".to_string()),
+        500,
+    );
+}
+
+#[tokio::test]
+async fn create_user_err_cannot_create_user() {
+    let server = fake_server().await;
+    let params = UserCreateParams::new(
+        "t_username".to_string(),
+        "t_email@example.com".to_string(),
+        "t_password".to_string(),
+    );
+    assert_wp_error(
+        server
+            .api_client_as_subscriber()
+            .users()
+            .create(&params)
+            .await,
+        WpErrorCode::CannotCreateUser,
+        403,
+    );
+}
+
+#[tokio::test]
+async fn update_user() {
+    let server = fake_server().await;
+    let params = UserUpdateParams {
+        first_name: Some("new_first_name".to_string()),
+        ..Default::default()
+    };
+    let updated_user = server
+        .api_client_as_admin()
+        .users()
+        .update(&SECOND_USER_ID, &params)
+        .await
+        .unwrap();
+    assert_eq!(updated_user.first_name, "new_first_name");
+
+    // Each server has its own site, so the change is not visible to other servers
+    let user_from_another_server = fake_server()
+        .await
+        .api_client_as_admin()
+        .users()
+        .retrieve_with_edit_context(&SECOND_USER_ID)
+        .await
+        .unwrap();
+    assert_eq!(user_from_another_server.first_name, "");
+}
+
+#[tokio::test]
+async fn delete_user_reassigns_posts() {
+    let server = fake_server().await;
+    let deleted_user = server
+        .api_client_as_admin()
+        .users()
+        .delete(&SECOND_USER_ID, &UserDeleteParams::new(FIRST_USER_ID))
+        .await
+        .unwrap();
+    assert!(deleted_user.deleted);
+    assert_eq!(deleted_user.previous.id, SECOND_USER_ID);
+
+    let posts = server
+        .api_client_as_admin()
+        .posts()
+        .list_with_edit_context(&PostListParams {
+            per_page: Some(100),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert!(posts.iter().all(|p| p.author == FIRST_USER_ID));
+    assert_wp_error(
+        server
+            .api_client_as_admin()
+            .users()
+            .retrieve_with_edit_context(&SECOND_USER_ID)
+            .await,
+        WpErrorCode::UserInvalidId,
+        404,
+    );
+}
+
+#[tokio::test]
+async fn delete_user_err_invalid_reassign() {
+    let server = fake_server().await;
+    assert_wp_error(
+        server
+            .api_client_as_admin()
+            .users()
+            .delete(&SECOND_USER_ID, &UserDeleteParams::new(SECOND_USER_ID))
+            .await,
+        WpErrorCode::UserInvalidReassign,
+        400,
+    );
+}
+
+#[tokio::test]
+async fn list_users_err_forbidden_context() {
+    let server = fake_server().await;
+    assert_wp_error(
+        server
+            .api_client_as_subscriber()
+            .users()
+            .list_with_edit_context(&UserListParams::default())
+            .await,
+        WpErrorCode::ForbiddenContext,
+        403,
+    );
+    assert_wp_error(
+        server
+            .api_client_as_unauthenticated()
+            .users()
+            .list_with_edit_context(&UserListParams::default())
+            .await,
+        WpErrorCode::ForbiddenContext,
+        401,
+    );
+}
+
+#[tokio::test]
+async fn retrieve_me_err_not_logged_in() {
+    let server = fake_server().await;
+    assert_wp_error(
+        server
+            .api_client_as_unauthenticated()
+            .users()
+            .retrieve_me_with_view_context()
+            .await,
+        WpErrorCode::Unauthorized,
+        401,
+    );
+}
+
+#[tokio::test]
+async fn filter_user_fields() {
+    let server = fake_server().await;
+    let user = server
+        .api_client_as_admin()
+        .users()
+        .filter_retrieve_with_edit_context(
+            &FIRST_USER_ID,
+            &[
+                SparseUserFieldWithEditContext::Id,
+                SparseUserFieldWithEditContext::Email,
+            ],
+        )
+        .await
+        .unwrap();
+    assert_eq!(user.id, Some(FIRST_USER_ID));
+    assert_eq!(user.email.as_deref(), Some("test@example.com"));
+    assert!(user.username.is_none());
+}
+
+#[tokio::test]
+async fn application_passwords() {
+    let server = fake_server().await;
+    let created = server
+        .api_client_as_admin()
+        .application_passwords()
+        .create(
+            &SECOND_USER_ID,
+            &ApplicationPasswordCreateParams {
+                app_id: None,
+                name: "fake-app".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    let password = created.password.expect("Password is returned on creation");
+
+    // The new application password can be used to authenticate as the user
+    let client = server.api_client(WpAuthentication::from_username_and_password(
+        "themedemos".to_string(),
+        password,
+    ));
+    let introspected = client
+        .application_passwords()
+        .retrieve_current_with_edit_context(&SECOND_USER_ID)
+        .await
+        .unwrap();
+    assert_eq!(introspected.uuid, created.uuid);
+    assert!(introspected.last_used.is_some());
+
+    let updated = server
+        .api_client_as_admin()
+        .application_passwords()
+        .update(
+            &SECOND_USER_ID,
+            &created.uuid,
+            &ApplicationPasswordUpdateParams {
+                app_id: None,
+                name: "renamed-app".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.name, "renamed-app");
+
+    let deleted = server
+        .api_client_as_admin()
+        .application_passwords()
+        .delete_all(&SECOND_USER_ID)
+        .await
+        .unwrap();
+    assert_eq!(deleted.count, 2);
+    assert_wp_error(
+        client
+            .application_passwords()
+            .retrieve_current_with_edit_context(&SECOND_USER_ID)
+            .await,
+        WpErrorCode::CustomError("incorrect_password".to_string()),
+        401,
+    );
+}
+
+#[tokio::test]
+async fn plugins() {
+    let server = fake_server().await;
+    let client = server.api_client_as_admin();
+    let created = client
+        .plugins()
+        .create(&PluginCreateParams {
+            slug: "classic-widgets".into(),
+            status: PluginStatus::Active,
+        })
+        .await
+        .unwrap();
+    assert_eq!(created.status, PluginStatus::Active);
+
+    let active_plugins = client
+        .plugins()
+        .list_with_edit_context(&PluginListParams {
+            search: None,
+            status: Some(PluginStatus::Active),
+        })
+        .await
+        .unwrap();
+    assert_eq!(active_plugins.len(), 2);
+
+    let hello_dolly = PluginSlug::new(HELLO_DOLLY_PLUGIN_SLUG.to_string());
+    let updated = client
+        .plugins()
+        .update(
+            &hello_dolly,
+            &PluginUpdateParams {
+                status: PluginStatus::Active,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.status, PluginStatus::Active);
+
+    assert_wp_error(
+        client
+            .plugins()
+            .delete(&PluginSlug::new(CLASSIC_EDITOR_PLUGIN_SLUG.to_string()))
+            .await,
+        WpErrorCode::CannotDeleteActivePlugin,
+        400,
+    );
+    assert_wp_error(
+        client
+            .plugins()
+            .create(&PluginCreateParams {
+                slug: "classic-widgets".into(),
+                status: PluginStatus::Inactive,
+            })
+            .await,
+        WpErrorCode::WpCoreFolderExists,
+        500,
+    );
+    assert_wp_error(
+        server
+            .api_client_as_subscriber()
+            .plugins()
+            .list_with_view_context(&PluginListParams::default())
+            .await,
+        WpErrorCode::CannotViewPlugins,
+        403,
+    );
+}
+
+#[tokio::test]
+async fn bulk_plugin_actions() {
+    let server = fake_server().await;
+    let client = server.api_client_as_admin();
+    let plugins = client.plugins();
+    let hello_dolly = PluginSlug::from(HELLO_DOLLY_PLUGIN_SLUG);
+    let classic_editor = PluginSlug::from(CLASSIC_EDITOR_PLUGIN_SLUG);
+    let missing_plugin = PluginSlug::from("missing/missing");
+
+    let results = plugins
+        .bulk_activate(vec![hello_dolly.clone(), missing_plugin.clone()])
+        .await
+        .unwrap();
+    assert!(matches!(
+        &results[..],
+        [
+            PluginBulkActionResult::Succeeded { plugin },
+            PluginBulkActionResult::Failed {
+                plugin: failed_plugin,
+                error: PluginActionError::RequestFailed {
+                    error: WpApiError::WpError {
+                        error_code: WpErrorCode::PluginNotFound,
+                        ..
+                    }
+                },
+            },
+        ] if plugin.plugin == hello_dolly && plugin.status == PluginStatus::Active && *failed_plugin == missing_plugin
+    ));
+
+    let results = plugins
+        .bulk_deactivate(vec![hello_dolly.clone(), classic_editor.clone()])
+        .await;
+    assert!(results.iter().all(|result| matches!(
+        result,
+        PluginBulkActionResult::Succeeded { plugin } if plugin.status == PluginStatus::Inactive
+    )));
+
+    let results = plugins
+        .bulk_delete(vec![hello_dolly.clone(), classic_editor.clone()])
+        .await;
+    assert!(results
+        .iter()
+        .all(|result| matches!(result, PluginBulkActionResult::Succeeded { .. })));
+    assert!(plugins
+        .list_with_edit_context(&PluginListParams::default())
+        .await
+        .unwrap()
+        .iter()
+        .all(|p| p.plugin != hello_dolly && p.plugin != classic_editor));
+}
+
+#[tokio::test]
+async fn update_site_settings() {
+    let server = fake_server().await;
+    let params = SiteSettingsUpdateParams {
+        title: Some("New Title".to_string()),
+        posts_per_page: Some(5),
+        ..Default::default()
+    };
+    let settings = server
+        .api_client_as_admin()
+        .site_settings()
+        .update(&params)
+        .await
+        .unwrap();
+    assert_eq!(settings.title, "New Title");
+    assert_eq!(settings.posts_per_page, 5);
+
+    assert_wp_error(
+        server
+            .api_client_as_subscriber()
+            .site_settings()
+            .retrieve_with_view_context()
+            .await,
+        WpErrorCode::Forbidden,
+        403,
+    );
+}
+
+#[tokio::test]
+async fn list_posts_pagination_headers() {
+    let server = fake_server().await;
+    let authentication = WpAuthentication::from_username_and_password(
+        FAKE_ADMIN_USERNAME.to_string(),
+        FAKE_ADMIN_PASSWORD.to_string(),
+    );
+    let request_builder = WpApiRequestBuilder::new(FakeWpServer::site_url(), authentication);
+    let params = PostListParams {
+        per_page: Some(5),
+        ..Default::default()
+    };
+    let response = server
+        .execute(
+            request_builder
+                .posts()
+                .list_with_view_context(&params)
+                .into(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status_code, 200);
+    let headers = response.header_map.as_header_map();
+    assert_eq!(headers["X-WP-Total"], "12");
+    assert_eq!(headers["X-WP-TotalPages"], "3");
+
+    let next_page = response.get_link_header("next");
+    assert_eq!(
+        next_page.first().and_then(|url| url.query()),
+        Some("context=view&per_page=5&page=2")
+    );
+    let posts = response.parse::<Vec<PostWithViewContext>>().unwrap();
+    assert_eq!(posts.len(), 5);
+    // Posts are listed newest first by default
+    assert!(posts.windows(2).all(|p| p[0].date > p[1].date));
+
+    assert_wp_error(
+        server
+            .api_client_as_admin()
+            .posts()
+            .list_with_view_context(&PostListParams {
+                page: Some(4),
+                per_page: Some(5),
+                ..Default::default()
+            })
+            .await,
+        WpErrorCode::CustomError("rest_post_invalid_page_number".to_string()),
+        400,
+    );
+}
+
+#[tokio::test]
+async fn list_posts_err_forbidden_status() {
+    let server = fake_server().await;
+    assert_wp_error(
+        server
+            .api_client_as_unauthenticated()
+            .posts()
+            .list_with_view_context(&PostListParams {
+                status: vec![wp_api::posts::PostStatus::Draft],
+                ..Default::default()
+            })
+            .await,
+        WpErrorCode::CustomError("rest_forbidden_status".to_string()),
+        401,
+    );
+}
+
+#[tokio::test]
+async fn retrieve_post_with_edit_context_includes_raw_values() {
+    let server = fake_server().await;
+    let post = server
+        .api_client_as_admin()
+        .posts()
+        .retrieve_with_edit_context(&PostId(2))
+        .await
+        .unwrap();
+    assert_eq!(post.content.raw, "Welcome to post #2. This is the content.");
+    assert_eq!(
+        post.content.rendered,
+        "<p>Welcome to post #2. This is the content.</p>\n"
+    );
+    assert_eq!(post.content.block_version, 0);
+    assert_eq!(post.title.raw, post.title.rendered);
+}
+
+#[tokio::test]
+async fn update_post_if_unmodified() {
+    let server = fake_server().await;
+    let client = server.api_client_as_admin();
+    let post = client
+        .posts()
+        .retrieve_with_edit_context(&PostId(2))
+        .await
+        .unwrap();
+    let params = PostUpdateParams {
+        title: Some("Updated title".to_string()),
+        ..Default::default()
+    };
+    let updated_post = client
+        .posts()
+        .update_if_unmodified(&PostId(2), post.modified_gmt, &params)
+        .await
+        .unwrap();
+    assert_eq!(updated_post.title.raw, "Updated title");
+    assert_eq!(updated_post.title.rendered, "Updated title");
+}
+
+#[tokio::test]
+async fn update_post_if_unmodified_err_conflict() {
+    let server = fake_server().await;
+    let client = server.api_client_as_admin();
+    let base = client
+        .posts()
+        .retrieve_with_edit_context(&PostId(2))
+        .await
+        .unwrap();
+    server.edit_post_title(2, "Edited elsewhere");
+
+    let params = PostUpdateParams {
+        slug: Some("edited-locally".to_string()),
+        ..Default::default()
+    };
+    let result = client
+        .posts()
+        .update_if_unmodified(&PostId(2), base.modified_gmt.clone(), &params)
+        .await;
+    let Err(PostUpdateError::Conflict {
+        client_modified_gmt,
+        server_version,
+    }) = result
+    else {
+        panic!("Expected a conflict, found '{:?}'", result);
+    };
+    assert_eq!(client_modified_gmt, base.modified_gmt);
+    assert_eq!(server_version.title.rendered, "Edited elsewhere");
+
+    // The local slug change doesn't conflict with the remote title change
+    let to_sparse_post =
+        |value: serde_json::Value| -> SparsePost { serde_json::from_value(value).unwrap() };
+    let mut local = serde_json::to_value(&base).unwrap();
+    local["slug"] = "edited-locally".into();
+    let merge = merge_sparse_posts(
+        to_sparse_post(serde_json::to_value(&base).unwrap()),
+        to_sparse_post(local),
+        to_sparse_post(serde_json::to_value(&server_version).unwrap()),
+    );
+    assert!(merge.conflicting_fields.is_empty());
+    assert_eq!(merge.merged.slug.as_deref(), Some("edited-locally"));
+    assert_eq!(
+        merge.merged.title.unwrap().raw.as_deref(),
+        Some("Edited elsewhere")
+    );
+}
+
+#[tokio::test]
+async fn parallel_servers_do_not_share_state() {
+    let create_and_count = |username: &'static str| async move {
+        let server = fake_server().await;
+        let params = UserCreateParams::new(
+            username.to_string(),
+            format!("{}@example.com", username),
+            "t_password".to_string(),
+        );
+        server
+            .api_client_as_admin()
+            .users()
+            .create(&params)
+            .await
+            .unwrap();
+        server
+            .api_client_as_admin()
+            .users()
+            .list_with_edit_context(&UserListParams::default())
+            .await
+            .unwrap()
+            .len()
+    };
+    let counts =
+        futures::future::join_all(["first", "second", "third"].map(create_and_count)).await;
+    assert_eq!(counts, vec![4, 4, 4]);
+}
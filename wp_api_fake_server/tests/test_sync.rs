@@ -0,0 +1,172 @@
+use std::sync::Arc;
+
+use wp_api::{
+    posts::PostId,
+    sync::{InMemorySyncStore, MutationQueue, PostsSyncEngine, SqliteSyncStore, SyncStore},
+    users::{UserCreateParams, UserDeleteParams, UserId, UserUpdateParams},
+    WpApiError, WpApiRequestBuilder, WpAuthentication, WpErrorCode,
+};
+use wp_api_fake_server::{FakeWpServer, FAKE_ADMIN_PASSWORD, FAKE_ADMIN_USERNAME};
+
+const SEEDED_POST_COUNT: u64 = 14;
+
+async fn fake_server() -> Arc<FakeWpServer> {
+    Arc::new(FakeWpServer::new().await)
+}
+
+fn admin_authentication() -> WpAuthentication {
+    WpAuthentication::from_username_and_password(
+        FAKE_ADMIN_USERNAME.to_string(),
+        FAKE_ADMIN_PASSWORD.to_string(),
+    )
+}
+
+fn sync_engine(server: &Arc<FakeWpServer>, store: Arc<dyn SyncStore>) -> PostsSyncEngine {
+    PostsSyncEngine::new(
+        FakeWpServer::site_url(),
+        admin_authentication(),
+        server.clone(),
+        store,
+    )
+}
+
+#[tokio::test]
+async fn initial_sync_pulls_all_posts() {
+    let server = fake_server().await;
+    let engine = sync_engine(&server, Arc::new(InMemorySyncStore::new()));
+    let summary = engine.sync().await.unwrap();
+    assert_eq!(summary.generation, 1);
+    assert_eq!(summary.upserted_count, SEEDED_POST_COUNT);
+    assert_eq!(summary.deleted_count, 0);
+
+    let changes = engine.changes_since(0).unwrap();
+    assert_eq!(changes.len() as u64, SEEDED_POST_COUNT);
+    assert!(changes.iter().all(|c| c.post.is_some()));
+    let last_sync = engine.last_sync().unwrap().unwrap();
+    assert_eq!(last_sync.generation, 1);
+    assert_eq!(
+        last_sync.last_modified,
+        Some("2024-01-14T10:00:00".to_string())
+    );
+}
+
+#[tokio::test]
+async fn incremental_sync_only_pulls_modified_posts() {
+    let server = fake_server().await;
+    let engine = sync_engine(&server, Arc::new(InMemorySyncStore::new()));
+    engine.sync().await.unwrap();
+    server.edit_post_title(3, "Edited elsewhere");
+
+    let summary = engine.sync().await.unwrap();
+    assert_eq!(summary.generation, 2);
+    assert_eq!(summary.upserted_count, 1);
+    let changes = engine.changes_since(1).unwrap();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].post_id, PostId(3));
+    assert_eq!(
+        changes[0].post.as_ref().unwrap().title.rendered,
+        "Edited elsewhere"
+    );
+}
+
+#[tokio::test]
+async fn sync_records_tombstones_for_removed_posts() {
+    let server = fake_server().await;
+    let engine = sync_engine(&server, Arc::new(InMemorySyncStore::new()));
+    engine.sync().await.unwrap();
+    server.trash_post(5);
+
+    let summary = engine.sync().await.unwrap();
+    assert_eq!(summary.upserted_count, 0);
+    assert_eq!(summary.deleted_count, 1);
+    let changes = engine.changes_since(1).unwrap();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].post_id, PostId(5));
+    assert!(changes[0].post.is_none());
+
+    // Tombstones are only recorded once
+    let summary = engine.sync().await.unwrap();
+    assert_eq!(summary.deleted_count, 0);
+    assert!(engine.changes_since(2).unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn sync_resumes_from_a_persisted_sqlite_store() {
+    let server = fake_server().await;
+    let path = std::env::temp_dir().join(format!("wp_api_sync_{}.sqlite", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let open_store = || -> Arc<dyn SyncStore> {
+        Arc::new(SqliteSyncStore::open(path.to_string_lossy().to_string()).unwrap())
+    };
+
+    sync_engine(&server, open_store()).sync().await.unwrap();
+    server.edit_post_title(1, "Edited while offline");
+
+    // A new engine picks up the state of the previous one from the database
+    let engine = sync_engine(&server, open_store());
+    let summary = engine.sync().await.unwrap();
+    assert_eq!(summary.generation, 2);
+    assert_eq!(summary.upserted_count, 1);
+    assert_eq!(
+        engine.changes_since(0).unwrap().len() as u64,
+        SEEDED_POST_COUNT
+    );
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn mutation_queue_replays_with_server_ids() {
+    let server = fake_server().await;
+    let queue = MutationQueue::new(
+        Arc::new(SqliteSyncStore::open_in_memory().unwrap()),
+        admin_authentication(),
+        server.clone(),
+        3,
+    );
+    let request_builder =
+        WpApiRequestBuilder::new(FakeWpServer::site_url(), admin_authentication());
+    let temporary_id = UserId(-1);
+    let create = request_builder.users().create(&UserCreateParams::new(
+        "offline_user".to_string(),
+        "offline_user@example.com".to_string(),
+        "offline_password".to_string(),
+    ));
+    queue
+        .enqueue(create.into(), Some(temporary_id.0 as i64))
+        .unwrap();
+    let update = request_builder.users().update(
+        &temporary_id,
+        &UserUpdateParams {
+            first_name: Some("Offline".to_string()),
+            ..Default::default()
+        },
+    );
+    queue.enqueue(update.into(), None).unwrap();
+    // Users can't be reassigned their own posts, so this fails permanently
+    let delete = request_builder
+        .users()
+        .delete(&UserId(2), &UserDeleteParams::new(UserId(2)));
+    queue.enqueue(delete.into(), None).unwrap();
+
+    let summary = queue.replay().await.unwrap();
+    assert_eq!(summary.replayed_count, 2);
+    assert_eq!(summary.remaining_count, 0);
+    assert_eq!(summary.failures.len(), 1);
+    assert!(matches!(
+        summary.failures[0].error,
+        WpApiError::WpError {
+            error_code: WpErrorCode::UserInvalidReassign,
+            ..
+        }
+    ));
+    assert!(queue.pending().unwrap().is_empty());
+
+    let users = server
+        .api_client_as_admin()
+        .users()
+        .list_with_edit_context(&Default::default())
+        .await
+        .unwrap();
+    let created_user = users.iter().find(|u| u.username == "offline_user").unwrap();
+    assert_eq!(created_user.first_name, "Offline");
+}
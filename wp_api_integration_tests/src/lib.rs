@@ -1,6 +1,7 @@
 use async_trait::async_trait;
-use std::sync::Arc;
+use std::{cell::RefCell, path::PathBuf, sync::Arc};
 use wp_api::{
+    cassette::CassetteRequestExecutor,
     request::{
         RequestExecutor, RequestMethod, WpNetworkHeaderMap, WpNetworkRequest, WpNetworkResponse,
     },
@@ -22,16 +23,16 @@ pub const HELLO_DOLLY_PLUGIN_SLUG: &str = "hello-dolly/hello";
 pub const CLASSIC_EDITOR_PLUGIN_SLUG: &str = "classic-editor/classic-editor";
 pub const WP_ORG_PLUGIN_SLUG_CLASSIC_WIDGETS: &str = "classic-widgets";
 
+// Set to `record` to save the requests of each test to a cassette, or to `replay` to run the
+// tests using the saved cassettes instead of the test server.
+const CASSETTE_MODE_ENV_KEY: &str = "WP_API_CASSETTE_MODE";
+
 pub fn api_client() -> WpApiClient {
     let authentication = WpAuthentication::from_username_and_password(
         TEST_CREDENTIALS_ADMIN_USERNAME.to_string(),
         TEST_CREDENTIALS_ADMIN_PASSWORD.to_string(),
     );
-    WpApiClient::new(
-        test_site_url(),
-        authentication,
-        Arc::new(AsyncWpNetworking::default()),
-    )
+    WpApiClient::new(test_site_url(), authentication, request_executor())
 }
 
 pub fn api_client_as_subscriber() -> WpApiClient {
@@ -39,19 +40,61 @@ pub fn api_client_as_subscriber() -> WpApiClient {
         TEST_CREDENTIALS_SUBSCRIBER_USERNAME.to_string(),
         TEST_CREDENTIALS_SUBSCRIBER_PASSWORD.to_string(),
     );
-    WpApiClient::new(
-        test_site_url(),
-        authentication,
-        Arc::new(AsyncWpNetworking::default()),
-    )
+    WpApiClient::new(test_site_url(), authentication, request_executor())
 }
 
 pub fn api_client_as_unauthenticated() -> WpApiClient {
-    WpApiClient::new(
-        test_site_url(),
-        WpAuthentication::None,
-        Arc::new(AsyncWpNetworking::default()),
-    )
+    WpApiClient::new(test_site_url(), WpAuthentication::None, request_executor())
+}
+
+thread_local! {
+    // Tests may create multiple clients, so the cassette is shared for the test's thread
+    static CASSETTE_REQUEST_EXECUTOR: RefCell<Option<Arc<CassetteRequestExecutor>>> =
+        const { RefCell::new(None) };
+}
+
+pub fn request_executor() -> Arc<dyn RequestExecutor> {
+    let mode = std::env::var(CASSETTE_MODE_ENV_KEY).unwrap_or_default();
+    if mode.is_empty() {
+        return Arc::new(AsyncWpNetworking::default());
+    }
+    CASSETTE_REQUEST_EXECUTOR.with_borrow_mut(|request_executor| {
+        request_executor
+            .get_or_insert_with(|| Arc::new(cassette_request_executor(&mode)))
+            .clone()
+    })
+}
+
+fn cassette_request_executor(mode: &str) -> CassetteRequestExecutor {
+    match mode {
+        "record" => {
+            CassetteRequestExecutor::record(cassette_path(), Arc::new(AsyncWpNetworking::default()))
+        }
+        "replay" => CassetteRequestExecutor::replay(cassette_path())
+            .unwrap_or_else(|e| panic!("Failed to load the cassette for the test: {}", e)),
+        _ => panic!("Unexpected {}: '{}'", CASSETTE_MODE_ENV_KEY, mode),
+    }
+}
+
+// Cassettes are saved as `cassettes/{test_file}/{test_name}.json`. The test runner names each
+// test's thread after the test, i.e. `list_users_with_edit_context::case_01`.
+fn cassette_path() -> PathBuf {
+    let test_file = std::env::current_exe()
+        .ok()
+        .and_then(|exe| {
+            exe.file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .and_then(|s| s.split('-').next().map(str::to_string))
+        })
+        .unwrap_or_default();
+    let test_name = std::thread::current()
+        .name()
+        .expect("Cassettes can only be used from the test's thread")
+        .replace("::", "__");
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("cassettes")
+        .join(test_file)
+        .join(format!("{}.json", test_name))
 }
 
 pub fn test_site_url() -> Arc<ParsedUrl> {
@@ -112,6 +155,9 @@ impl AsyncWpNetworking {
         if let Some(body) = wp_request.body() {
             request = request.body(body.contents());
         }
+        if let Some(timeout_ms) = wp_request.timeout_ms() {
+            request = request.timeout(std::time::Duration::from_millis(timeout_ms));
+        }
         let mut response = request.send().await?;
 
         let header_map = std::mem::take(response.headers_mut());
@@ -127,8 +173,10 @@ impl AsyncWpNetworking {
             RequestMethod::GET => reqwest::Method::GET,
             RequestMethod::POST => reqwest::Method::POST,
             RequestMethod::PUT => reqwest::Method::PUT,
+            RequestMethod::PATCH => reqwest::Method::PATCH,
             RequestMethod::DELETE => reqwest::Method::DELETE,
             RequestMethod::HEAD => reqwest::Method::HEAD,
+            RequestMethod::OPTIONS => reqwest::Method::OPTIONS,
         }
     }
 }
@@ -178,4 +226,4 @@ impl<T: std::fmt::Debug, E: std::error::Error> AssertError for Result<T, E> {
         assert!(self.is_err(), "Request was successful");
         self.unwrap_err()
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,28 @@
+use rstest::*;
+use serial_test::parallel;
+
+use wp_api_integration_tests::{api_client, AssertResponse};
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn list_block_patterns() {
+    let patterns = api_client()
+        .block_patterns()
+        .list_patterns()
+        .await
+        .assert_response();
+    assert!(patterns.iter().all(|p| !p.content.is_empty()));
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn list_block_pattern_categories() {
+    let categories = api_client()
+        .block_patterns()
+        .list_categories()
+        .await
+        .assert_response();
+    assert!(categories.iter().any(|c| c.name == "text"));
+}
@@ -0,0 +1,100 @@
+use rstest::*;
+use serial_test::parallel;
+use wp_api::{
+    block_types::{
+        BlockRendererParams, BlockTypeListParams, BlockTypeName,
+        SparseBlockTypeFieldWithEditContext,
+    },
+    generate, JsonValue, WpContext,
+};
+
+use wp_api_integration_tests::{api_client, AssertResponse};
+
+#[rstest]
+#[case(BlockTypeListParams::default())]
+#[case(generate!(BlockTypeListParams, (namespace, Some("core".to_string()))))]
+#[trace]
+#[tokio::test]
+#[parallel]
+async fn list_block_types(
+    #[case] params: BlockTypeListParams,
+    #[values(WpContext::Edit, WpContext::Embed, WpContext::View)] context: WpContext,
+) {
+    let names = match context {
+        WpContext::Edit => api_client()
+            .block_types()
+            .list_with_edit_context(&params)
+            .await
+            .assert_response()
+            .into_iter()
+            .map(|b| b.name)
+            .collect::<Vec<_>>(),
+        WpContext::Embed => api_client()
+            .block_types()
+            .list_with_embed_context(&params)
+            .await
+            .assert_response()
+            .into_iter()
+            .map(|b| b.name)
+            .collect(),
+        WpContext::View => api_client()
+            .block_types()
+            .list_with_view_context(&params)
+            .await
+            .assert_response()
+            .into_iter()
+            .map(|b| b.name)
+            .collect(),
+    };
+    assert!(names.contains(&BlockTypeName::from("core/paragraph")));
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn retrieve_block_type_with_edit_context() {
+    let block_type = api_client()
+        .block_types()
+        .retrieve_with_edit_context(&BlockTypeName::from("core/paragraph"))
+        .await
+        .assert_response();
+    assert_eq!(block_type.name, BlockTypeName::from("core/paragraph"));
+    assert!(block_type
+        .attributes
+        .expect("Paragraph block has attributes")
+        .contains_key("content"));
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn filter_retrieve_block_type_with_edit_context() {
+    let block_type = api_client()
+        .block_types()
+        .filter_retrieve_with_edit_context(
+            &BlockTypeName::from("core/paragraph"),
+            &[SparseBlockTypeFieldWithEditContext::Supports],
+        )
+        .await
+        .assert_response();
+    assert!(block_type.supports.is_some());
+    assert!(block_type.name.is_none());
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn render_block() {
+    let response = api_client()
+        .block_renderer()
+        .render(
+            &BlockTypeName::from("core/archives"),
+            &BlockRendererParams {
+                attributes: JsonValue(serde_json::json!({ "showLabel": false })),
+                post_id: None,
+            },
+        )
+        .await
+        .assert_response();
+    assert!(response.rendered.contains("wp-block-archives"));
+}
@@ -0,0 +1,44 @@
+use rstest::*;
+use serial_test::parallel;
+use wp_api::fonts::{FontFamilyListParams, SparseFontCollectionFieldWithViewContext};
+
+use wp_api_integration_tests::{api_client, AssertResponse};
+
+#[rstest]
+#[case(FontFamilyListParams::default())]
+#[case(FontFamilyListParams { per_page: Some(100), ..Default::default() })]
+#[case(FontFamilyListParams { slug: vec!["foo".to_string()], ..Default::default() })]
+#[tokio::test]
+#[parallel]
+async fn list_font_families(#[case] params: FontFamilyListParams) {
+    api_client()
+        .font_families()
+        .list_with_edit_context(&params)
+        .await
+        .assert_response();
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn list_and_retrieve_font_collections() {
+    // Only request the fields that don't need the collection's data to be fetched
+    let fields = [
+        SparseFontCollectionFieldWithViewContext::Slug,
+        SparseFontCollectionFieldWithViewContext::Name,
+        SparseFontCollectionFieldWithViewContext::Description,
+    ];
+    let font_collections = api_client()
+        .font_collections()
+        .filter_list_with_view_context(&fields)
+        .await
+        .assert_response();
+    for slug in font_collections.into_iter().filter_map(|c| c.slug) {
+        let font_collection = api_client()
+            .font_collections()
+            .filter_retrieve_with_view_context(&slug, &fields)
+            .await
+            .assert_response();
+        assert_eq!(font_collection.slug, Some(slug));
+    }
+}
@@ -0,0 +1,124 @@
+use serial_test::serial;
+use wp_api::{
+    fonts::{
+        FontFaceCreateParams, FontFaceCreateWithFilesParams, FontFaceListParams, FontFaceSettings,
+        FontFamilyCreateParams, FontFamilySettings, FontFamilyUpdateParams,
+    },
+    request::WpMultipartFile,
+    ForceDeleteParams,
+};
+
+use wp_api_integration_tests::backend::RestoreServer;
+use wp_api_integration_tests::{api_client, AssertResponse};
+
+#[tokio::test]
+#[serial]
+async fn create_update_and_delete_font_family_with_font_faces() {
+    let font_family = api_client()
+        .font_families()
+        .create(&FontFamilyCreateParams::new(FontFamilySettings {
+            name: "Test Font".to_string(),
+            slug: "test-font".to_string(),
+            font_family: "\"Test Font\", serif".to_string(),
+            preview: None,
+        }))
+        .await
+        .assert_response();
+    assert_eq!(font_family.font_family_settings.slug, "test-font");
+    assert!(font_family.font_faces.is_empty());
+
+    let updated_font_family = api_client()
+        .font_families()
+        .update(
+            &font_family.id,
+            &FontFamilyUpdateParams {
+                font_family_settings: Some(FontFamilySettings {
+                    name: "Updated Test Font".to_string(),
+                    ..font_family.font_family_settings.clone()
+                }),
+                ..Default::default()
+            },
+        )
+        .await
+        .assert_response();
+    assert_eq!(
+        updated_font_family.font_family_settings.name,
+        "Updated Test Font"
+    );
+
+    // A font face that refers to a remote font file
+    let remote_font_face = api_client()
+        .font_faces()
+        .create(
+            &font_family.id,
+            &FontFaceCreateParams::new(FontFaceSettings {
+                font_weight: Some("400".to_string()),
+                font_style: Some("normal".to_string()),
+                ..FontFaceSettings::new(
+                    "\"Test Font\", serif".to_string(),
+                    vec!["https://example.com/test-font-400.woff2".to_string()],
+                )
+            }),
+        )
+        .await
+        .assert_response();
+    assert_eq!(remote_font_face.parent, font_family.id);
+
+    // A font face whose font file is uploaded to the site
+    let uploaded_font_face = api_client()
+        .font_faces()
+        .create_with_files(
+            &font_family.id,
+            &FontFaceCreateWithFilesParams::new(
+                FontFaceSettings {
+                    font_weight: Some("700".to_string()),
+                    font_style: Some("normal".to_string()),
+                    ..FontFaceSettings::new(
+                        "\"Test Font\", serif".to_string(),
+                        vec!["file-0".to_string()],
+                    )
+                },
+                vec![WpMultipartFile {
+                    field_name: "file-0".to_string(),
+                    file_name: "test-font-700.woff2".to_string(),
+                    mime_type: "font/woff2".to_string(),
+                    contents: b"wOF2\x00\x01\x00\x00".to_vec(),
+                }],
+            ),
+        )
+        .await
+        .assert_response();
+    assert!(uploaded_font_face.font_face_settings.src[0].ends_with(".woff2"));
+
+    let font_faces = api_client()
+        .font_faces()
+        .list_with_edit_context(&font_family.id, &FontFaceListParams::default())
+        .await
+        .assert_response();
+    assert_eq!(font_faces.len(), 2);
+
+    let deleted_font_face = api_client()
+        .font_faces()
+        .delete(
+            &font_family.id,
+            &remote_font_face.id,
+            &ForceDeleteParams::default(),
+        )
+        .await
+        .assert_response();
+    assert!(deleted_font_face.deleted);
+
+    // Deleting the font family deletes its remaining font faces as well
+    let deleted_font_family = api_client()
+        .font_families()
+        .delete(&font_family.id, &ForceDeleteParams::default())
+        .await
+        .assert_response();
+    assert!(deleted_font_family.deleted);
+    assert_eq!(
+        deleted_font_family.previous.font_faces,
+        vec![uploaded_font_face.id]
+    );
+
+    RestoreServer::db().await;
+}
@@ -0,0 +1,51 @@
+use rstest::*;
+use serial_test::parallel;
+use wp_api::{
+    generate,
+    global_styles::{global_styles_presets, GlobalStylesPresetType},
+    themes::{ThemeListParams, ThemeStatus, ThemeStylesheet},
+};
+
+use wp_api_integration_tests::{api_client, AssertResponse};
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn retrieve_active_theme_global_styles() {
+    let theme_global_styles = api_client()
+        .global_styles()
+        .retrieve_theme(&active_theme_stylesheet().await)
+        .await
+        .assert_response();
+    let palette =
+        global_styles_presets(&theme_global_styles.settings, GlobalStylesPresetType::Color);
+    assert!(!palette.is_empty());
+    assert!(palette.iter().all(|p| p
+        .css_custom_property_name
+        .starts_with("--wp--preset--color--")));
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn list_active_theme_global_styles_variations() {
+    api_client()
+        .global_styles()
+        .list_theme_variations(&active_theme_stylesheet().await)
+        .await
+        .assert_response();
+}
+
+async fn active_theme_stylesheet() -> ThemeStylesheet {
+    api_client()
+        .themes()
+        .list_with_edit_context(&generate!(
+            ThemeListParams,
+            (status, vec![ThemeStatus::Active])
+        ))
+        .await
+        .assert_response()
+        .pop()
+        .expect("There should always be an active theme")
+        .stylesheet
+}
@@ -0,0 +1,94 @@
+use serde_json::json;
+use serial_test::serial;
+use wp_api::{
+    global_styles::{
+        GlobalStylesId, GlobalStylesUpdateParams, SparseGlobalStylesFieldWithEditContext,
+    },
+    JsonValue,
+};
+use wp_api_integration_tests::backend::RestoreServer;
+use wp_api_integration_tests::{
+    api_client, AssertResponse, TEST_CREDENTIALS_ADMIN_PASSWORD, TEST_CREDENTIALS_ADMIN_USERNAME,
+    TEST_CREDENTIALS_SITE_URL,
+};
+
+#[tokio::test]
+#[serial]
+async fn retrieve_user_global_styles() {
+    let global_styles_id = user_global_styles_id().await;
+    let global_styles = api_client()
+        .global_styles()
+        .retrieve_with_edit_context(&global_styles_id)
+        .await
+        .assert_response();
+    assert_eq!(global_styles.id, global_styles_id);
+
+    let global_styles = api_client()
+        .global_styles()
+        .filter_retrieve_with_edit_context(
+            &global_styles_id,
+            &[SparseGlobalStylesFieldWithEditContext::Styles],
+        )
+        .await
+        .assert_response();
+    assert!(global_styles.id.is_none());
+    assert!(global_styles.styles.is_some());
+
+    RestoreServer::db().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn update_user_global_styles() {
+    let global_styles_id = user_global_styles_id().await;
+    let styles = json!({ "color": { "background": "#123456" } });
+    let updated = api_client()
+        .global_styles()
+        .update(
+            &global_styles_id,
+            &GlobalStylesUpdateParams {
+                styles: Some(JsonValue(styles.clone())),
+                ..Default::default()
+            },
+        )
+        .await
+        .assert_response();
+    assert_eq!(updated.id, global_styles_id);
+    assert_eq!(updated.styles.0["color"], styles["color"]);
+
+    let retrieved = api_client()
+        .global_styles()
+        .retrieve_with_edit_context(&global_styles_id)
+        .await
+        .assert_response();
+    assert_eq!(retrieved.styles.0["color"], styles["color"]);
+
+    RestoreServer::db().await;
+}
+
+// The id of the user's global styles is only exposed through the `wp:user-global-styles` link of
+// the active theme. WordPress creates the global styles post when the link is first requested,
+// which is why these tests restore the database.
+async fn user_global_styles_id() -> GlobalStylesId {
+    let themes = reqwest::Client::new()
+        .get(format!(
+            "{}/wp-json/wp/v2/themes?status=active",
+            TEST_CREDENTIALS_SITE_URL.trim_end_matches('/')
+        ))
+        .basic_auth(
+            TEST_CREDENTIALS_ADMIN_USERNAME,
+            Some(TEST_CREDENTIALS_ADMIN_PASSWORD),
+        )
+        .send()
+        .await
+        .expect("Active theme should be listed")
+        .json::<serde_json::Value>()
+        .await
+        .expect("Themes response should be JSON");
+    themes[0]["_links"]["wp:user-global-styles"][0]["href"]
+        .as_str()
+        .and_then(|href| href.rsplit('/').next())
+        .and_then(|id| id.parse().ok())
+        .map(GlobalStylesId)
+        .expect("The active theme should link to the user's global styles")
+}
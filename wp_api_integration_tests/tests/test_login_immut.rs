@@ -1,8 +1,7 @@
 use rstest::rstest;
 use serial_test::serial;
-use std::sync::Arc;
 use wp_api::login::WpLoginClient;
-use wp_api_integration_tests::{AssertError, AssertResponse, AsyncWpNetworking};
+use wp_api_integration_tests::{request_executor, AssertError, AssertResponse};
 
 const LOCALHOST_AUTH_URL: &str = "http://localhost/wp-admin/authorize-application.php";
 const AUTOMATTIC_WIDGETS_AUTH_URL: &str =
@@ -45,7 +44,7 @@ const VANILLA_WP_SITE_URL: &str = "https://vanilla.wpmt.co/wp-admin/authorize-ap
 #[tokio::test]
 #[serial]
 async fn test_login_flow(#[case] site_url: &str, #[case] expected_auth_url: &str) {
-    let client = WpLoginClient::new(Arc::new(AsyncWpNetworking::default()));
+    let client = WpLoginClient::new(request_executor());
     let url_discovery = client
         .api_discovery(site_url.to_string())
         .await
@@ -63,7 +62,7 @@ async fn test_login_flow(#[case] site_url: &str, #[case] expected_auth_url: &str
 #[tokio::test]
 #[serial]
 async fn test_login_flow_for_empty_authentication_schemes(#[case] site_url: &str) {
-    let client = WpLoginClient::new(Arc::new(AsyncWpNetworking::default()));
+    let client = WpLoginClient::new(request_executor());
     let url_discovery_error = client
         .api_discovery(site_url.to_string())
         .await
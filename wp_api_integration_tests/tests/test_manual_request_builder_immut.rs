@@ -12,7 +12,7 @@ use wp_api::{
     WpApiParamOrder, WpApiRequestBuilder, WpAuthentication,
 };
 use wp_api_integration_tests::{
-    test_site_url, AsyncWpNetworking, FIRST_USER_ID, SECOND_USER_ID,
+    request_executor, test_site_url, FIRST_USER_ID, SECOND_USER_ID,
     TEST_CREDENTIALS_ADMIN_PASSWORD, TEST_CREDENTIALS_ADMIN_USERNAME,
 };
 
@@ -26,11 +26,11 @@ async fn list_users_with_edit_context(#[case] params: UserListParams) {
         TEST_CREDENTIALS_ADMIN_USERNAME.to_string(),
         TEST_CREDENTIALS_ADMIN_PASSWORD.to_string(),
     );
-    let async_wp_networking = AsyncWpNetworking::default();
+    let request_executor = request_executor();
 
     let request_builder = WpApiRequestBuilder::new(test_site_url(), authentication);
     let wp_request = request_builder.users().list_with_edit_context(&params);
-    let response = async_wp_networking.async_request(wp_request.into()).await;
+    let response = request_executor.execute(wp_request.into()).await;
     let result = response.unwrap().parse::<Vec<UserWithEditContext>>();
     assert!(result.is_ok(), "Response was: '{:?}'", result);
 }
@@ -0,0 +1,124 @@
+use rstest::*;
+use serial_test::parallel;
+use wp_api::{
+    menus::{MenuItemListParams, MenuListParams},
+    navigation::NavigationListParams,
+    WpContext,
+};
+
+use wp_api_integration_tests::{api_client, AssertResponse};
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn list_menus(
+    #[values(WpContext::Edit, WpContext::Embed, WpContext::View)] context: WpContext,
+) {
+    let params = MenuListParams::default();
+    match context {
+        WpContext::Edit => {
+            api_client()
+                .menus()
+                .list_with_edit_context(&params)
+                .await
+                .assert_response();
+        }
+        WpContext::Embed => {
+            api_client()
+                .menus()
+                .list_with_embed_context(&params)
+                .await
+                .assert_response();
+        }
+        WpContext::View => {
+            api_client()
+                .menus()
+                .list_with_view_context(&params)
+                .await
+                .assert_response();
+        }
+    };
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn list_menu_items(
+    #[values(WpContext::Edit, WpContext::Embed, WpContext::View)] context: WpContext,
+) {
+    let params = MenuItemListParams::default();
+    match context {
+        WpContext::Edit => {
+            api_client()
+                .menu_items()
+                .list_with_edit_context(&params)
+                .await
+                .assert_response();
+        }
+        WpContext::Embed => {
+            api_client()
+                .menu_items()
+                .list_with_embed_context(&params)
+                .await
+                .assert_response();
+        }
+        WpContext::View => {
+            api_client()
+                .menu_items()
+                .list_with_view_context(&params)
+                .await
+                .assert_response();
+        }
+    };
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn list_menu_locations_with_edit_context() {
+    let response = api_client()
+        .menu_locations()
+        .list_with_edit_context()
+        .await
+        .assert_response();
+    for (name, location) in response.menu_locations {
+        let retrieved = api_client()
+            .menu_locations()
+            .retrieve_with_edit_context(&name)
+            .await
+            .assert_response();
+        assert_eq!(retrieved.name, location.name);
+    }
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn list_navigation(
+    #[values(WpContext::Edit, WpContext::Embed, WpContext::View)] context: WpContext,
+) {
+    let params = NavigationListParams::default();
+    match context {
+        WpContext::Edit => {
+            api_client()
+                .navigation()
+                .list_with_edit_context(&params)
+                .await
+                .assert_response();
+        }
+        WpContext::Embed => {
+            api_client()
+                .navigation()
+                .list_with_embed_context(&params)
+                .await
+                .assert_response();
+        }
+        WpContext::View => {
+            api_client()
+                .navigation()
+                .list_with_view_context(&params)
+                .await
+                .assert_response();
+        }
+    };
+}
@@ -0,0 +1,122 @@
+use serial_test::serial;
+use wp_api::{
+    menus::{
+        build_menu_item_tree, MenuCreateParams, MenuItemCreateParams, MenuItemListParams,
+        MenuItemOrder, MenuItemType, MenuItemWithEditContext,
+    },
+    navigation::{NavigationCreateParams, NavigationUpdateParams},
+    posts::PostStatus,
+    ForceDeleteParams,
+};
+
+use wp_api_integration_tests::backend::RestoreServer;
+use wp_api_integration_tests::{api_client, AssertResponse};
+
+#[tokio::test]
+#[serial]
+async fn create_reorder_and_delete_menu() {
+    let menu = api_client()
+        .menus()
+        .create(&MenuCreateParams::new("Test Menu".to_string()))
+        .await
+        .assert_response();
+    assert_eq!(menu.name, "Test Menu");
+
+    let mut menu_items: Vec<MenuItemWithEditContext> = Vec::new();
+    for title in ["First", "Second", "Third"] {
+        let menu_item = api_client()
+            .menu_items()
+            .create(&MenuItemCreateParams {
+                item_type: Some(MenuItemType::Custom),
+                url: Some("https://example.com".to_string()),
+                status: Some(PostStatus::Publish),
+                menu_order: Some(menu_items.len() as u32 + 1),
+                ..MenuItemCreateParams::new(menu.id, title.to_string())
+            })
+            .await
+            .assert_response();
+        menu_items.push(menu_item);
+    }
+
+    // Move the third item under the first one
+    let desired_order = vec![
+        MenuItemOrder {
+            id: menu_items[0].id,
+            children: vec![MenuItemOrder {
+                id: menu_items[2].id,
+                children: Vec::new(),
+            }],
+        },
+        MenuItemOrder {
+            id: menu_items[1].id,
+            children: Vec::new(),
+        },
+    ];
+    let updated_menu_items = api_client()
+        .menu_items()
+        .reorder(&menu_items, &desired_order)
+        .await
+        .assert_response();
+    assert_eq!(updated_menu_items.len(), 1);
+
+    let menu_items = api_client()
+        .menu_items()
+        .list_with_edit_context(&MenuItemListParams {
+            menus: vec![menu.id],
+            ..Default::default()
+        })
+        .await
+        .assert_response();
+    let tree = build_menu_item_tree(menu_items);
+    assert_eq!(
+        tree.iter().map(MenuItemOrder::from).collect::<Vec<_>>(),
+        desired_order
+    );
+
+    let delete_response = api_client()
+        .menus()
+        .delete(&menu.id, &ForceDeleteParams::default())
+        .await
+        .assert_response();
+    assert!(delete_response.deleted);
+
+    RestoreServer::db().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn create_update_and_delete_navigation() {
+    let navigation = api_client()
+        .navigation()
+        .create(&NavigationCreateParams {
+            title: Some("Test Navigation".to_string()),
+            content: Some("<!-- wp:page-list /-->".to_string()),
+            status: Some(PostStatus::Publish),
+            ..Default::default()
+        })
+        .await
+        .assert_response();
+    assert_eq!(navigation.title.raw, "Test Navigation");
+
+    let updated_navigation = api_client()
+        .navigation()
+        .update(
+            &navigation.id,
+            &NavigationUpdateParams {
+                title: Some("Updated Navigation".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .assert_response();
+    assert_eq!(updated_navigation.title.raw, "Updated Navigation");
+
+    let delete_response = api_client()
+        .navigation()
+        .delete(&navigation.id, &ForceDeleteParams::default())
+        .await
+        .assert_response();
+    assert!(delete_response.deleted);
+
+    RestoreServer::db().await;
+}
@@ -0,0 +1,47 @@
+use rstest::*;
+use serial_test::parallel;
+use wp_api::{
+    oembed::{OEmbedParams, OEmbedProxyParams},
+    WpErrorCode,
+};
+use wp_api_integration_tests::{api_client, api_client_as_subscriber, AssertWpError};
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn embed_err_oembed_invalid_url() {
+    api_client()
+        .oembed()
+        .embed(&OEmbedParams::new(
+            "https://example.com/does-not-exist".to_string(),
+        ))
+        .await
+        .assert_wp_error(WpErrorCode::OEmbedInvalidUrl);
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn proxy_err_forbidden() {
+    api_client_as_subscriber()
+        .oembed()
+        .proxy(&OEmbedProxyParams::new(
+            "https://example.com/does-not-exist".to_string(),
+        ))
+        .await
+        .assert_wp_error(WpErrorCode::Forbidden);
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn proxy_err_oembed_invalid_url() {
+    api_client()
+        .oembed()
+        .proxy(&OEmbedProxyParams {
+            discover: Some(false),
+            ..OEmbedProxyParams::new("https://example.com/does-not-exist".to_string())
+        })
+        .await
+        .assert_wp_error(WpErrorCode::OEmbedInvalidUrl);
+}
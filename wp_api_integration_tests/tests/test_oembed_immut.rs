@@ -0,0 +1,32 @@
+use rstest::*;
+use serial_test::parallel;
+use wp_api::{
+    oembed::{OEmbedParams, OEmbedResponse},
+    posts::PostId,
+};
+
+use wp_api_integration_tests::{api_client, AssertResponse};
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn embed_post() {
+    let post = api_client()
+        .posts()
+        .retrieve_with_view_context(&PostId(1))
+        .await
+        .assert_response();
+    let response = api_client()
+        .oembed()
+        .embed(&OEmbedParams {
+            maxwidth: Some(400),
+            ..OEmbedParams::new(post.link)
+        })
+        .await
+        .assert_response();
+    let OEmbedResponse::Rich(rich) = response else {
+        panic!("Posts should be embedded as rich content");
+    };
+    assert_eq!(rich.details.title, Some(post.title.rendered));
+    assert_eq!(rich.width, Some(400));
+}
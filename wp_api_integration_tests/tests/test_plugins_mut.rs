@@ -1,6 +1,8 @@
 use rstest::rstest;
 use serial_test::serial;
-use wp_api::plugins::{PluginCreateParams, PluginSlug, PluginStatus, PluginUpdateParams};
+use wp_api::plugins::{
+    PluginBulkActionResult, PluginCreateParams, PluginSlug, PluginStatus, PluginUpdateParams,
+};
 use wp_api_integration_tests::backend::RestoreServer;
 use wp_api_integration_tests::{
     api_client, AssertResponse, CLASSIC_EDITOR_PLUGIN_SLUG, HELLO_DOLLY_PLUGIN_SLUG,
@@ -54,3 +56,39 @@ async fn delete_plugin() {
 
     RestoreServer::all().await;
 }
+
+#[tokio::test]
+#[serial]
+async fn bulk_activate_deactivate_and_delete_plugins() {
+    let plugins = vec![
+        PluginSlug::new(HELLO_DOLLY_PLUGIN_SLUG.into()),
+        PluginSlug::new(CLASSIC_EDITOR_PLUGIN_SLUG.into()),
+    ];
+    let assert_succeeded = |results: Vec<PluginBulkActionResult>, status: PluginStatus| {
+        assert_eq!(results.len(), plugins.len());
+        for result in results {
+            match result {
+                PluginBulkActionResult::Succeeded { plugin } => assert_eq!(plugin.status, status),
+                PluginBulkActionResult::Failed { plugin, error } => {
+                    panic!("Bulk action failed for '{}': {}", plugin, error)
+                }
+            }
+        }
+    };
+
+    let results = api_client()
+        .plugins()
+        .bulk_activate(plugins.clone())
+        .await
+        .assert_response();
+    assert_succeeded(results, PluginStatus::Active);
+    let results = api_client()
+        .plugins()
+        .bulk_deactivate(plugins.clone())
+        .await;
+    assert_succeeded(results, PluginStatus::Inactive);
+    let results = api_client().plugins().bulk_delete(plugins.clone()).await;
+    assert_succeeded(results, PluginStatus::Inactive);
+
+    RestoreServer::all().await;
+}
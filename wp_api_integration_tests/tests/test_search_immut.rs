@@ -0,0 +1,48 @@
+use rstest::*;
+use serial_test::parallel;
+use wp_api::{
+    generate,
+    posts::PostId,
+    search::{SearchListParams, SearchResult, SearchSubtype, SearchTermId, SearchType},
+};
+
+use wp_api_integration_tests::{api_client, AssertResponse};
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn search_posts() {
+    let results = api_client()
+        .search()
+        .list(&generate!(
+            SearchListParams,
+            (search, Some("Hello".to_string()))
+        ))
+        .await
+        .assert_response();
+    assert!(results.iter().any(|r| matches!(
+        r,
+        SearchResult::Post(post) if post.id == PostId(1)
+    )));
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn search_categories() {
+    let results = api_client()
+        .search()
+        .list(&generate!(
+            SearchListParams,
+            (search, Some("Uncategorized".to_string())),
+            (search_type, Some(SearchType::Term)),
+            (subtype, vec![SearchSubtype::Category])
+        ))
+        .await
+        .assert_response();
+    assert!(!results.is_empty());
+    assert!(results.iter().all(|r| matches!(
+        r,
+        SearchResult::Term(term) if matches!(term.id, SearchTermId::Category { .. })
+    )));
+}
@@ -0,0 +1,65 @@
+use rstest::*;
+use serial_test::parallel;
+use wp_api::{
+    post_types::PostType,
+    posts::PostStatus,
+    statuses::{post_status_filters, SparseStatusFieldWithEditContext},
+};
+
+use wp_api_integration_tests::{api_client, AssertResponse};
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn list_statuses_with_edit_context() {
+    let response = api_client()
+        .statuses()
+        .list_with_edit_context()
+        .await
+        .assert_response();
+    let publish = response
+        .statuses
+        .get(&PostStatus::Publish)
+        .expect("`publish` status is always registered");
+    assert!(publish.public);
+    assert!(publish.show_in_list);
+    let trash = response
+        .statuses
+        .get(&PostStatus::Trash)
+        .expect("`trash` status is exposed to admins");
+    assert!(!trash.show_in_list);
+
+    let filters = post_status_filters(&response, &PostType::Post);
+    assert!(filters.contains(&PostStatus::Draft));
+    assert!(filters.contains(&PostStatus::Trash));
+    assert!(!filters.contains(&PostStatus::AutoDraft));
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn retrieve_status_with_view_context() {
+    let status = api_client()
+        .statuses()
+        .retrieve_with_view_context(&PostStatus::Draft)
+        .await
+        .assert_response();
+    assert_eq!(status.slug, PostStatus::Draft);
+    assert!(!status.queryable);
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn filter_retrieve_status_with_edit_context() {
+    let status = api_client()
+        .statuses()
+        .filter_retrieve_with_edit_context(
+            &PostStatus::Private,
+            &[SparseStatusFieldWithEditContext::Private],
+        )
+        .await
+        .assert_response();
+    assert_eq!(status.private, Some(true));
+    assert!(status.name.is_none());
+}
@@ -0,0 +1,102 @@
+use rstest::*;
+use serial_test::parallel;
+use wp_api::{
+    generate,
+    templates::{
+        SparseTemplateFieldWithEditContext, TemplateListParams, TemplateLookupParams,
+        TemplatePartArea, TemplatePartListParams,
+    },
+    WpContext,
+};
+
+use wp_api_integration_tests::{api_client, AssertResponse};
+
+#[rstest]
+#[case(TemplateListParams::default())]
+#[trace]
+#[tokio::test]
+#[parallel]
+async fn list_templates(
+    #[case] params: TemplateListParams,
+    #[values(WpContext::Edit, WpContext::Embed, WpContext::View)] context: WpContext,
+) {
+    match context {
+        WpContext::Edit => {
+            api_client()
+                .templates()
+                .list_with_edit_context(&params)
+                .await
+                .assert_response();
+        }
+        WpContext::Embed => {
+            api_client()
+                .templates()
+                .list_with_embed_context(&params)
+                .await
+                .assert_response();
+        }
+        WpContext::View => {
+            api_client()
+                .templates()
+                .list_with_view_context(&params)
+                .await
+                .assert_response();
+        }
+    };
+}
+
+#[rstest]
+#[case(TemplatePartListParams::default())]
+#[case(generate!(TemplatePartListParams, (area, Some(TemplatePartArea::Header))))]
+#[trace]
+#[tokio::test]
+#[parallel]
+async fn list_template_parts_with_edit_context(#[case] params: TemplatePartListParams) {
+    let template_parts = api_client()
+        .template_parts()
+        .list_with_edit_context(&params)
+        .await
+        .assert_response();
+    if let Some(area) = params.area {
+        assert!(template_parts
+            .iter()
+            .all(|t| t.area.as_ref() == Some(&area)));
+    }
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn retrieve_template_with_edit_context() {
+    let templates = api_client()
+        .templates()
+        .filter_list_with_edit_context(
+            &TemplateListParams::default(),
+            &[SparseTemplateFieldWithEditContext::Id],
+        )
+        .await
+        .assert_response();
+    let Some(template_id) = templates.into_iter().find_map(|t| t.id) else {
+        // Classic themes don't have any templates
+        return;
+    };
+    let template = api_client()
+        .templates()
+        .retrieve_with_edit_context(&template_id)
+        .await
+        .assert_response();
+    assert_eq!(template.id, template_id);
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn lookup_template() {
+    api_client()
+        .templates()
+        .lookup(&TemplateLookupParams::new(
+            "single-post-hello-world".to_string(),
+        ))
+        .await
+        .assert_response();
+}
@@ -0,0 +1,49 @@
+use serial_test::serial;
+use wp_api::templates::{
+    TemplateCreateParams, TemplatePartArea, TemplateSource, TemplateUpdateParams,
+};
+use wp_api::ForceDeleteParams;
+
+use wp_api_integration_tests::backend::RestoreServer;
+use wp_api_integration_tests::{api_client, AssertResponse};
+
+#[tokio::test]
+#[serial]
+async fn create_update_and_delete_template_part() {
+    let created = api_client()
+        .template_parts()
+        .create(&TemplateCreateParams {
+            title: Some("Test Header".to_string()),
+            content: Some("<!-- wp:paragraph --><p>Header</p><!-- /wp:paragraph -->".to_string()),
+            area: Some(TemplatePartArea::Header),
+            ..TemplateCreateParams::new("test-header".to_string())
+        })
+        .await
+        .assert_response();
+    assert_eq!(created.slug, "test-header");
+    assert_eq!(created.source, TemplateSource::Custom);
+    assert_eq!(created.area, Some(TemplatePartArea::Header));
+
+    let updated = api_client()
+        .template_parts()
+        .update(
+            &created.id,
+            &TemplateUpdateParams {
+                title: Some("Updated Header".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .assert_response();
+    assert_eq!(updated.title.raw, "Updated Header");
+
+    let delete_response = api_client()
+        .template_parts()
+        .delete(&created.id, &ForceDeleteParams::default())
+        .await
+        .assert_response();
+    assert!(delete_response.deleted);
+    assert_eq!(delete_response.previous.id, created.id);
+
+    RestoreServer::db().await;
+}
@@ -0,0 +1,94 @@
+use rstest::*;
+use serial_test::parallel;
+use wp_api::{
+    generate,
+    themes::{SparseThemeFieldWithEditContext, ThemeListParams, ThemeStatus, ThemeSupports},
+    WpContext,
+};
+
+use wp_api_integration_tests::{api_client, AssertResponse};
+
+#[rstest]
+#[case(ThemeListParams::default())]
+#[case(generate!(ThemeListParams, (status, vec![ThemeStatus::Active])))]
+#[case(generate!(ThemeListParams, (status, vec![ThemeStatus::Active, ThemeStatus::Inactive])))]
+#[trace]
+#[tokio::test]
+#[parallel]
+async fn list_themes(
+    #[case] params: ThemeListParams,
+    #[values(WpContext::Edit, WpContext::Embed, WpContext::View)] context: WpContext,
+) {
+    let statuses = match context {
+        WpContext::Edit => api_client()
+            .themes()
+            .list_with_edit_context(&params)
+            .await
+            .assert_response()
+            .into_iter()
+            .map(|t| t.status)
+            .collect::<Vec<_>>(),
+        WpContext::Embed => api_client()
+            .themes()
+            .list_with_embed_context(&params)
+            .await
+            .assert_response()
+            .into_iter()
+            .map(|t| t.status)
+            .collect(),
+        WpContext::View => api_client()
+            .themes()
+            .list_with_view_context(&params)
+            .await
+            .assert_response()
+            .into_iter()
+            .map(|t| t.status)
+            .collect(),
+    };
+    assert!(statuses.contains(&ThemeStatus::Active));
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn retrieve_active_theme_with_edit_context() {
+    let active_theme = api_client()
+        .themes()
+        .list_with_edit_context(&generate!(
+            ThemeListParams,
+            (status, vec![ThemeStatus::Active])
+        ))
+        .await
+        .assert_response()
+        .pop()
+        .expect("There should always be an active theme");
+    let theme = api_client()
+        .themes()
+        .retrieve_with_edit_context(&active_theme.stylesheet)
+        .await
+        .assert_response();
+    assert_eq!(theme.stylesheet, active_theme.stylesheet);
+    let theme_supports = ThemeSupports::from(
+        &theme
+            .theme_supports
+            .expect("The active theme should include its theme supports"),
+    );
+    assert!(!theme_supports.post_formats().is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn filter_list_themes_with_edit_context() {
+    let themes = api_client()
+        .themes()
+        .filter_list_with_edit_context(
+            &ThemeListParams::default(),
+            &[SparseThemeFieldWithEditContext::Stylesheet],
+        )
+        .await
+        .assert_response();
+    assert!(themes
+        .iter()
+        .all(|t| t.stylesheet.is_some() && t.name.is_none()));
+}
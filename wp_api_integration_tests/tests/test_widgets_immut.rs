@@ -0,0 +1,67 @@
+use rstest::*;
+use serial_test::parallel;
+use wp_api::widgets::{SidebarId, SidebarStatus, WidgetTypeId, WidgetTypeRenderParams};
+
+use wp_api_integration_tests::{api_client, AssertResponse};
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn list_sidebars_include_inactive_widgets() {
+    let sidebars = api_client()
+        .sidebars()
+        .list_with_edit_context()
+        .await
+        .assert_response();
+    let inactive_widgets = sidebars
+        .iter()
+        .find(|s| s.id == SidebarId::inactive_widgets())
+        .expect("The inactive widgets sidebar should always be listed");
+    assert_eq!(inactive_widgets.status, SidebarStatus::Inactive);
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn retrieve_inactive_widgets_sidebar() {
+    api_client()
+        .sidebars()
+        .retrieve_with_view_context(&SidebarId::inactive_widgets())
+        .await
+        .assert_response();
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn list_and_retrieve_widget_types() {
+    let widget_types = api_client()
+        .widget_types()
+        .list_with_view_context()
+        .await
+        .assert_response();
+    assert!(widget_types
+        .iter()
+        .any(|w| w.id == WidgetTypeId::from("block")));
+
+    let search = api_client()
+        .widget_types()
+        .retrieve_with_edit_context(&WidgetTypeId::from("search"))
+        .await
+        .assert_response();
+    assert!(search.is_multi);
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn render_widget_type() {
+    api_client()
+        .widget_types()
+        .render(
+            &WidgetTypeId::from("search"),
+            &WidgetTypeRenderParams::default(),
+        )
+        .await
+        .assert_response();
+}
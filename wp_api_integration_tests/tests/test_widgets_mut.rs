@@ -0,0 +1,99 @@
+use serde_json::json;
+use serial_test::serial;
+use wp_api::{
+    widgets::{
+        SidebarId, SidebarUpdateParams, WidgetCreateParams, WidgetInstance, WidgetTypeId,
+        WidgetUpdateParams,
+    },
+    ForceDeleteParams, JsonValue,
+};
+
+use wp_api_integration_tests::backend::RestoreServer;
+use wp_api_integration_tests::{api_client, AssertResponse};
+
+#[tokio::test]
+#[serial]
+async fn create_move_reorder_and_delete_widgets() {
+    let inactive_widgets = SidebarId::inactive_widgets();
+    let mut widget_ids = Vec::new();
+    for text in ["First", "Second"] {
+        let widget = api_client()
+            .widgets()
+            .create(&WidgetCreateParams {
+                instance: Some(WidgetInstance::raw(JsonValue(json!({
+                    "content": format!("<!-- wp:paragraph --><p>{}</p><!-- /wp:paragraph -->", text)
+                })))),
+                ..WidgetCreateParams::new(WidgetTypeId::from("block"), inactive_widgets.clone())
+            })
+            .await
+            .assert_response();
+        assert_eq!(widget.sidebar, inactive_widgets);
+        assert!(widget.rendered.contains(text));
+        widget_ids.push(widget.id);
+    }
+
+    // Reorder the widgets by updating the sidebar
+    let sidebar = api_client()
+        .sidebars()
+        .retrieve_with_edit_context(&inactive_widgets)
+        .await
+        .assert_response();
+    let mut reordered_widgets: Vec<_> = sidebar
+        .widgets
+        .into_iter()
+        .filter(|id| !widget_ids.contains(id))
+        .collect();
+    reordered_widgets.insert(0, widget_ids[1].clone());
+    reordered_widgets.insert(1, widget_ids[0].clone());
+    let sidebar = api_client()
+        .sidebars()
+        .update(
+            &inactive_widgets,
+            &SidebarUpdateParams {
+                widgets: Some(reordered_widgets.clone()),
+            },
+        )
+        .await
+        .assert_response();
+    assert_eq!(sidebar.widgets, reordered_widgets);
+
+    // Move a widget back & forth between sidebars, if the theme registers any
+    if let Some(theme_sidebar) = api_client()
+        .sidebars()
+        .list_with_edit_context()
+        .await
+        .assert_response()
+        .into_iter()
+        .find(|s| s.id != inactive_widgets)
+    {
+        let widget = api_client()
+            .widgets()
+            .update(
+                &widget_ids[0],
+                &WidgetUpdateParams {
+                    sidebar: Some(theme_sidebar.id.clone()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .assert_response();
+        assert_eq!(widget.sidebar, theme_sidebar.id);
+
+        let widget = api_client()
+            .widgets()
+            .deactivate(&widget_ids[0])
+            .await
+            .assert_response();
+        assert_eq!(widget.sidebar, inactive_widgets);
+    }
+
+    for widget_id in widget_ids.iter() {
+        let response = api_client()
+            .widgets()
+            .delete(widget_id, &ForceDeleteParams::default())
+            .await
+            .assert_response();
+        assert!(response.deleted);
+    }
+    RestoreServer::db().await;
+}
@@ -0,0 +1,51 @@
+use rstest::*;
+use serial_test::parallel;
+use wp_api::{
+    wp_blocks::{WpBlockListParams, WpPatternCategoryListParams},
+    WpContext,
+};
+
+use wp_api_integration_tests::{api_client, AssertResponse};
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn list_wp_blocks(
+    #[values(WpContext::Edit, WpContext::Embed, WpContext::View)] context: WpContext,
+) {
+    let params = WpBlockListParams::default();
+    match context {
+        WpContext::Edit => {
+            api_client()
+                .wp_blocks()
+                .list_with_edit_context(&params)
+                .await
+                .assert_response();
+        }
+        WpContext::Embed => {
+            api_client()
+                .wp_blocks()
+                .list_with_embed_context(&params)
+                .await
+                .assert_response();
+        }
+        WpContext::View => {
+            api_client()
+                .wp_blocks()
+                .list_with_view_context(&params)
+                .await
+                .assert_response();
+        }
+    };
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn list_pattern_categories() {
+    api_client()
+        .wp_blocks()
+        .list_pattern_categories_with_edit_context(&WpPatternCategoryListParams::default())
+        .await
+        .assert_response();
+}
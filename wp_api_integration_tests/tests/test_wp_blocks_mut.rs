@@ -0,0 +1,76 @@
+use serial_test::serial;
+use wp_api::{
+    posts::PostStatus,
+    wp_blocks::{
+        wp_block_pattern_sync_status, WpBlockCreateParams, WpBlockUpdateParams,
+        WpPatternCategoryCreateParams, WpPatternSyncStatus,
+    },
+    ForceDeleteParams,
+};
+use wp_api_integration_tests::backend::RestoreServer;
+use wp_api_integration_tests::{api_client, AssertResponse};
+
+const BLOCK_CONTENT: &str = "<!-- wp:paragraph -->\n<p>Synced</p>\n<!-- /wp:paragraph -->";
+
+#[tokio::test]
+#[serial]
+async fn create_update_and_delete_wp_block() {
+    let category = api_client()
+        .wp_blocks()
+        .create_pattern_category(&WpPatternCategoryCreateParams {
+            name: "Integration Test".to_string(),
+            description: None,
+            slug: None,
+        })
+        .await
+        .assert_response();
+    let created_block = api_client()
+        .wp_blocks()
+        .create(&WpBlockCreateParams {
+            status: Some(PostStatus::Publish),
+            wp_pattern_category: vec![category.id],
+            ..WpBlockCreateParams::new("Synced pattern".to_string(), BLOCK_CONTENT.to_string())
+        })
+        .await
+        .assert_response();
+    assert_eq!(created_block.title.raw, "Synced pattern");
+    assert_eq!(created_block.content.raw, BLOCK_CONTENT);
+    assert_eq!(
+        wp_block_pattern_sync_status(&created_block),
+        WpPatternSyncStatus::Synced
+    );
+    assert_eq!(created_block.wp_pattern_category, vec![category.id]);
+
+    let updated_block = api_client()
+        .wp_blocks()
+        .update(
+            &created_block.id,
+            &WpBlockUpdateParams {
+                wp_pattern_sync_status: Some(WpPatternSyncStatus::Unsynced),
+                ..Default::default()
+            },
+        )
+        .await
+        .assert_response();
+    assert_eq!(
+        wp_block_pattern_sync_status(&updated_block),
+        WpPatternSyncStatus::Unsynced
+    );
+
+    let trashed_block = api_client()
+        .wp_blocks()
+        .trash(&created_block.id)
+        .await
+        .assert_response();
+    assert_eq!(trashed_block.status, PostStatus::Trash);
+
+    let delete_response = api_client()
+        .wp_blocks()
+        .delete(&created_block.id, &ForceDeleteParams::default())
+        .await
+        .assert_response();
+    assert!(delete_response.deleted);
+    assert_eq!(delete_response.previous.id, created_block.id);
+
+    RestoreServer::db().await;
+}
@@ -0,0 +1,45 @@
+use wp_contextual::WpContextual;
+
+#[derive(WpContextual)]
+pub struct SparseFoo {
+    #[WpContext(edit, embed, view)]
+    #[WpContextualField]
+    pub bar: Option<SparseBar>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseBar {
+    #[WpContext(edit)]
+    pub raw: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub rendered: Option<String>,
+    #[WpContext(edit)]
+    pub version: Option<u32>,
+}
+
+fn main() {
+    let _ = FooWithEditContext {
+        bar: BarWithEditContext {
+            raw: "raw".to_string(),
+            rendered: "rendered".to_string(),
+            version: 1,
+        },
+    };
+    let _ = FooWithEmbedContext {
+        bar: BarWithEmbedContext {
+            rendered: "rendered".to_string(),
+        },
+    };
+    let _ = FooWithViewContext {
+        bar: BarWithViewContext {
+            rendered: "rendered".to_string(),
+        },
+    };
+    let _ = SparseFooWithViewContext {
+        bar: Some(SparseBarWithViewContext { rendered: None }),
+    };
+    let raw_field = SparseBarFieldWithEditContext::Raw;
+    assert_eq!(raw_field.as_field_name(), "raw");
+}
+
+uniffi::setup_scaffolding!();
@@ -22,18 +22,24 @@ pub(crate) fn generate_types(parsed_enum: &ParsedEnum) -> TokenStream {
             generate_endpoint_type(&config, parsed_enum),
             generate_request_builder(&config, parsed_enum),
             generate_async_request_executor(&config, parsed_enum),
+            generate_request_mock(&config, parsed_enum),
         ]
         .into_iter(),
     )
 }
 
-fn generate_async_request_executor(config: &Config, parsed_enum: &ParsedEnum) -> TokenStream {
+// Generates a mock counterpart of the request executor that has the same functions, but instead
+// of executing the request, returns a handle to stub its response.
+fn generate_request_mock(config: &Config, parsed_enum: &ParsedEnum) -> TokenStream {
+    if !cfg!(feature = "generate_mock") {
+        return TokenStream::new();
+    }
     let static_api_base_url_type = &config.static_types.api_base_url;
     let static_wp_authentication_type = &config.static_types.wp_authentication;
-    let static_request_executor_type = &config.static_types.request_executor;
-    let static_wp_api_error_type = &config.static_types.wp_api_error;
+    let static_mock_request_executor_type = &config.static_types.mock_request_executor;
+    let static_mocked_request_type = &config.static_types.mocked_request;
     let generated_request_builder_ident = &config.generated_idents.request_builder;
-    let generated_request_executor_ident = &config.generated_idents.request_executor;
+    let generated_request_mock_ident = &config.generated_idents.request_mock;
 
     let functions = parsed_enum.variants.iter().map(|variant| {
         let url_parts = variant.attr.url_parts.as_slice();
@@ -52,6 +58,7 @@ fn generate_async_request_executor(config: &Config, parsed_enum: &ParsedEnum) ->
                 params_type.as_ref(),
                 variant.attr.request_type,
                 &context_and_filter_handler,
+                &RequestOptionsParam::None,
             );
             let fn_signature = fn_signature(
                 PartOf::RequestExecutor,
@@ -60,13 +67,82 @@ fn generate_async_request_executor(config: &Config, parsed_enum: &ParsedEnum) ->
                 params_type.as_ref(),
                 variant.attr.request_type,
                 &context_and_filter_handler,
+                &RequestOptionsParam::None,
             );
             quote! {
-                pub async #fn_signature -> Result<#output_type, #static_wp_api_error_type> {
+                pub #fn_signature -> #static_mocked_request_type<#output_type> {
                     #request_from_request_builder
-                    self.request_executor.execute(std::sync::Arc::new(request)).await?.parse()
-               }
+                    #static_mocked_request_type::new(self.mock_request_executor.clone(), request)
+                }
+            }
+        })
+        .collect::<TokenStream>()
+    });
+
+    quote! {
+        #[derive(Debug)]
+        pub struct #generated_request_mock_ident {
+            request_builder: #generated_request_builder_ident,
+            mock_request_executor: #static_mock_request_executor_type,
+        }
+        impl #generated_request_mock_ident {
+            pub fn new(api_base_url: #static_api_base_url_type, authentication: #static_wp_authentication_type, mock_request_executor: #static_mock_request_executor_type) -> Self {
+                Self {
+                    request_builder: #generated_request_builder_ident::new(api_base_url, authentication),
+                    mock_request_executor,
+                }
             }
+
+            #(#functions)*
+        }
+    }
+}
+
+fn generate_async_request_executor(config: &Config, parsed_enum: &ParsedEnum) -> TokenStream {
+    let static_api_base_url_type = &config.static_types.api_base_url;
+    let static_wp_authentication_type = &config.static_types.wp_authentication;
+    let static_request_executor_type = &config.static_types.request_executor;
+    let static_wp_api_error_type = &config.static_types.wp_api_error;
+    let generated_request_builder_ident = &config.generated_idents.request_builder;
+    let generated_request_executor_ident = &config.generated_idents.request_executor;
+
+    let functions = parsed_enum.variants.iter().map(|variant| {
+        let url_parts = variant.attr.url_parts.as_slice();
+        let params_type = &variant.attr.params;
+
+        ContextAndFilterHandler::from_request_type(
+            variant.attr.request_type,
+            variant.attr.filter_by.clone(),
+        )
+        .into_iter()
+        .flat_map(|context_and_filter_handler| {
+            RequestOptionsParam::variants(config).map(move |request_options_param| {
+                let output_type =
+                    output_type(variant.attr.output.clone(), &context_and_filter_handler);
+                let request_from_request_builder = fn_body_get_request_from_request_builder(
+                    &variant.variant_ident,
+                    url_parts,
+                    params_type.as_ref(),
+                    variant.attr.request_type,
+                    &context_and_filter_handler,
+                    &request_options_param,
+                );
+                let fn_signature = fn_signature(
+                    PartOf::RequestExecutor,
+                    &variant.variant_ident,
+                    url_parts,
+                    params_type.as_ref(),
+                    variant.attr.request_type,
+                    &context_and_filter_handler,
+                    &request_options_param,
+                );
+                quote! {
+                    pub async #fn_signature -> Result<#output_type, #static_wp_api_error_type> {
+                        #request_from_request_builder
+                        self.request_executor.execute(std::sync::Arc::new(request)).await?.parse()
+                   }
+                }
+            })
         })
         .collect::<TokenStream>()
     });
@@ -109,30 +185,36 @@ fn generate_request_builder(config: &Config, parsed_enum: &ParsedEnum) -> TokenS
             variant.attr.filter_by.clone(),
         )
         .into_iter()
-        .map(|context_and_filter_handler| {
-            let url_from_endpoint = fn_body_get_url_from_endpoint(
-                &variant.variant_ident,
-                url_parts,
-                params_type.as_ref(),
-                variant.attr.request_type,
-                &context_and_filter_handler,
-            );
-            let fn_signature = fn_signature(
-                PartOf::RequestBuilder,
-                &variant.variant_ident,
-                url_parts,
-                params_type.as_ref(),
-                variant.attr.request_type,
-                &context_and_filter_handler,
-            );
-            let fn_body_build_request_from_url =
-                fn_body_build_request_from_url(params_type.as_ref(), variant.attr.request_type);
-            quote! {
-                pub #fn_signature -> #static_wp_network_request_type {
-                    #url_from_endpoint
-                    #fn_body_build_request_from_url
+        .flat_map(|context_and_filter_handler| {
+            RequestOptionsParam::variants(config).map(move |request_options_param| {
+                let url_from_endpoint = fn_body_get_url_from_endpoint(
+                    &variant.variant_ident,
+                    url_parts,
+                    params_type.as_ref(),
+                    variant.attr.request_type,
+                    &context_and_filter_handler,
+                );
+                let fn_signature = fn_signature(
+                    PartOf::RequestBuilder,
+                    &variant.variant_ident,
+                    url_parts,
+                    params_type.as_ref(),
+                    variant.attr.request_type,
+                    &context_and_filter_handler,
+                    &request_options_param,
+                );
+                let fn_body_build_request_from_url = fn_body_build_request_from_url(
+                    params_type.as_ref(),
+                    variant.attr.request_type,
+                    &request_options_param,
+                );
+                quote! {
+                    pub #fn_signature -> #static_wp_network_request_type {
+                        #url_from_endpoint
+                        #fn_body_build_request_from_url
+                    }
                 }
-            }
+            })
         })
         .collect::<TokenStream>()
     });
@@ -181,6 +263,7 @@ fn generate_endpoint_type(config: &Config, parsed_enum: &ParsedEnum) -> TokenStr
                     params_type.as_ref(),
                     request_type,
                     &context_and_filter_handler,
+                    &RequestOptionsParam::None,
                 );
                 let context_query_pair =
                     fn_body_context_query_pairs(&config.crate_ident, &context_and_filter_handler);
@@ -222,6 +305,24 @@ pub enum PartOf {
     RequestExecutor,
 }
 
+// Request builder & executor functions are generated twice; once without request options, and
+// once taking a `WpRequestOptions` that'll be merged into the request.
+#[derive(Debug, Clone)]
+pub enum RequestOptionsParam {
+    None,
+    Provided(TokenStream),
+}
+
+impl RequestOptionsParam {
+    fn variants(config: &Config) -> impl Iterator<Item = Self> {
+        [
+            Self::None,
+            Self::Provided(config.static_types.wp_request_options.clone()),
+        ]
+        .into_iter()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum ContextAndFilterHandler {
     None,
@@ -256,7 +357,12 @@ impl ContextAndFilterHandler {
                 });
                 v
             }
-            crate::parse::RequestType::Delete | crate::parse::RequestType::Post => {
+            crate::parse::RequestType::Delete
+            | crate::parse::RequestType::Options
+            | crate::parse::RequestType::Patch
+            | crate::parse::RequestType::Post
+            | crate::parse::RequestType::PostMultipart
+            | crate::parse::RequestType::Put => {
                 vec![Self::None]
             }
         }
@@ -290,14 +396,7 @@ pub struct Config {
 
 impl Config {
     fn new(parsed_enum: &ParsedEnum) -> Self {
-        let crate_name = "wp_api";
-        let found_crate = proc_macro_crate::crate_name(crate_name)
-            .unwrap_or_else(|_| panic!("{} is not present in `Cargo.toml`", crate_name));
-
-        let crate_ident = match found_crate {
-            FoundCrate::Itself => format_ident!("crate"),
-            FoundCrate::Name(name) => Ident::new(&name, Span::call_site()),
-        };
+        let crate_ident = wp_api_crate_ident();
         let generated_idents = ConfigGeneratedIdents::new(parsed_enum);
         let static_types = ConfigStaticTypes::new(&crate_ident);
 
@@ -309,15 +408,29 @@ impl Config {
     }
 }
 
+pub(crate) fn wp_api_crate_ident() -> Ident {
+    let crate_name = "wp_api";
+    let found_crate = proc_macro_crate::crate_name(crate_name)
+        .unwrap_or_else(|_| panic!("{} is not present in `Cargo.toml`", crate_name));
+
+    match found_crate {
+        FoundCrate::Itself => format_ident!("crate"),
+        FoundCrate::Name(name) => Ident::new(&name, Span::call_site()),
+    }
+}
+
 #[derive(Debug)]
 pub struct ConfigStaticTypes {
     pub api_base_url: TokenStream,
     pub api_endpoint_url: TokenStream,
     pub inner_request_builder: TokenStream,
+    pub mock_request_executor: TokenStream,
+    pub mocked_request: TokenStream,
     pub request_executor: TokenStream,
     pub wp_api_error: TokenStream,
     pub wp_authentication: TokenStream,
     pub wp_network_request: TokenStream,
+    pub wp_request_options: TokenStream,
 }
 
 impl ConfigStaticTypes {
@@ -326,10 +439,13 @@ impl ConfigStaticTypes {
             api_base_url: quote! { std::sync::Arc<#crate_ident::request::endpoint::ApiBaseUrl> },
             api_endpoint_url: quote! { #crate_ident::request::endpoint::ApiEndpointUrl },
             inner_request_builder: quote! { #crate_ident::request::InnerRequestBuilder },
+            mock_request_executor: quote! { std::sync::Arc<#crate_ident::mock::MockRequestExecutor> },
+            mocked_request: quote! { #crate_ident::mock::MockedRequest },
             request_executor: quote! { std::sync::Arc<dyn #crate_ident::request::RequestExecutor> },
             wp_api_error: quote! { #crate_ident::WpApiError },
             wp_authentication: quote! { #crate_ident::WpAuthentication },
             wp_network_request: quote! { #crate_ident::request::WpNetworkRequest },
+            wp_request_options: quote! { #crate_ident::request::WpRequestOptions },
         }
     }
 }
@@ -339,6 +455,7 @@ pub struct ConfigGeneratedIdents {
     pub endpoint: Ident,
     pub request_builder: Ident,
     pub request_executor: Ident,
+    pub request_mock: Ident,
 }
 
 impl ConfigGeneratedIdents {
@@ -347,6 +464,7 @@ impl ConfigGeneratedIdents {
             endpoint: format_ident!("{}Endpoint", parsed_enum.enum_ident),
             request_builder: format_ident!("{}Builder", parsed_enum.enum_ident),
             request_executor: format_ident!("{}Executor", parsed_enum.enum_ident),
+            request_mock: format_ident!("{}Mock", parsed_enum.enum_ident),
         }
     }
 }
@@ -3,7 +3,7 @@ use proc_macro2::{TokenStream, TokenTree};
 use quote::{format_ident, quote};
 use syn::Ident;
 
-use super::{ContextAndFilterHandler, PartOf, WpContext};
+use super::{ContextAndFilterHandler, PartOf, RequestOptionsParam, WpContext};
 use crate::{
     parse::RequestType,
     variant_attr::{ParamsType, UrlPart},
@@ -76,12 +76,29 @@ pub fn fn_signature(
     params_type: Option<&ParamsType>,
     request_type: RequestType,
     context_and_filter_handler: &ContextAndFilterHandler,
+    request_options_param: &RequestOptionsParam,
 ) -> TokenStream {
-    let fn_name = fn_name(variant_ident, context_and_filter_handler);
+    let fn_name = fn_name_for_request_options(
+        fn_name(variant_ident, context_and_filter_handler),
+        request_options_param,
+    );
     let url_params = fn_url_params(url_parts);
     let provided_param = fn_provided_param(part_of, params_type, request_type);
     let fields_param = fn_fields_param(context_and_filter_handler);
-    quote! { fn #fn_name(&self, #url_params #provided_param #fields_param) }
+    match request_options_param {
+        RequestOptionsParam::None => {
+            quote! { fn #fn_name(&self, #url_params #provided_param #fields_param) }
+        }
+        RequestOptionsParam::Provided(request_options_type) => {
+            // `fields_param` doesn't have a trailing comma
+            let fields_param = if fields_param.is_empty() {
+                fields_param
+            } else {
+                quote! { #fields_param, }
+            };
+            quote! { fn #fn_name(&self, #url_params #provided_param #fields_param request_options: &#request_options_type) }
+        }
+    }
 }
 
 pub fn fn_url_params(url_parts: &[UrlPart]) -> TokenStream {
@@ -108,13 +125,17 @@ pub fn fn_provided_param(
             quote! { params: #params_type_token_stream, }
         };
         match part_of {
-            // Endpoints don't need the params type if it's a Post request because params will
-            // be part of the body.
+            // Endpoints don't need the params type if it's a Post, Put or Patch request because
+            // params will be part of the body.
             PartOf::Endpoint => match request_type {
                 crate::parse::RequestType::ContextualGet
                 | crate::parse::RequestType::Delete
-                | crate::parse::RequestType::Get => tokens,
-                crate::parse::RequestType::Post => TokenStream::new(),
+                | crate::parse::RequestType::Get
+                | crate::parse::RequestType::Options => tokens,
+                crate::parse::RequestType::Patch
+                | crate::parse::RequestType::Post
+                | crate::parse::RequestType::PostMultipart
+                | crate::parse::RequestType::Put => TokenStream::new(),
             },
             PartOf::RequestBuilder | PartOf::RequestExecutor => tokens,
         }
@@ -180,6 +201,17 @@ pub fn fn_name(
     }
 }
 
+// The variant of a function that takes `WpRequestOptions` is suffixed with `_with_options`
+pub fn fn_name_for_request_options(
+    fn_name: Ident,
+    request_options_param: &RequestOptionsParam,
+) -> Ident {
+    match request_options_param {
+        RequestOptionsParam::None => fn_name,
+        RequestOptionsParam::Provided(_) => format_ident!("{}_with_options", fn_name),
+    }
+}
+
 fn fn_arg_url_parts(url_parts: &[UrlPart]) -> TokenStream {
     url_parts
         .iter()
@@ -201,13 +233,17 @@ fn fn_arg_provided_params(
     if params_type.is_some() {
         let tokens = quote! { params, };
         match part_of {
-            // Endpoints don't need the params type if it's a Post request because params will
-            // be part of the body.
+            // Endpoints don't need the params type if it's a Post, Put or Patch request because
+            // params will be part of the body.
             PartOf::Endpoint => match request_type {
                 crate::parse::RequestType::ContextualGet
                 | crate::parse::RequestType::Delete
-                | crate::parse::RequestType::Get => tokens,
-                crate::parse::RequestType::Post => TokenStream::new(),
+                | crate::parse::RequestType::Get
+                | crate::parse::RequestType::Options => tokens,
+                crate::parse::RequestType::Patch
+                | crate::parse::RequestType::Post
+                | crate::parse::RequestType::PostMultipart
+                | crate::parse::RequestType::Put => TokenStream::new(),
             },
             PartOf::RequestBuilder | PartOf::RequestExecutor => tokens,
         }
@@ -265,7 +301,10 @@ pub fn fn_body_query_pairs(
     request_type: RequestType,
 ) -> TokenStream {
     match request_type {
-        RequestType::ContextualGet | RequestType::Delete | RequestType::Get => {
+        RequestType::ContextualGet
+        | RequestType::Delete
+        | RequestType::Get
+        | RequestType::Options => {
             if let Some(params_type) = params_type {
                 let is_option = if let Some(TokenTree::Ident(ref ident)) =
                     params_type.tokens.clone().into_iter().next()
@@ -288,7 +327,9 @@ pub fn fn_body_query_pairs(
                 TokenStream::new()
             }
         }
-        RequestType::Post => TokenStream::new(),
+        RequestType::Patch | RequestType::Post | RequestType::PostMultipart | RequestType::Put => {
+            TokenStream::new()
+        }
     }
 }
 
@@ -336,6 +377,21 @@ pub fn fn_body_context_query_pairs(
 pub fn fn_body_build_request_from_url(
     params_type: Option<&ParamsType>,
     request_type: RequestType,
+    request_options_param: &RequestOptionsParam,
+) -> TokenStream {
+    let build_request =
+        fn_body_build_request_from_url_without_request_options(params_type, request_type);
+    match request_options_param {
+        RequestOptionsParam::None => build_request,
+        RequestOptionsParam::Provided(_) => quote! {
+            #build_request.with_request_options(request_options)
+        },
+    }
+}
+
+fn fn_body_build_request_from_url_without_request_options(
+    params_type: Option<&ParamsType>,
+    request_type: RequestType,
 ) -> TokenStream {
     match request_type {
         RequestType::ContextualGet | RequestType::Get => quote! {
@@ -344,14 +400,27 @@ pub fn fn_body_build_request_from_url(
         RequestType::Delete => quote! {
             self.inner.delete(url)
         },
-        RequestType::Post => {
+        RequestType::Options => quote! {
+            self.inner.options(url)
+        },
+        // The params of a multipart request are sent as a `multipart/form-data` body, so the params
+        // type is expected to build its own form.
+        RequestType::PostMultipart => quote! {
+            self.inner.post_multipart(url, params.multipart_form())
+        },
+        RequestType::Patch | RequestType::Post | RequestType::Put => {
+            let fn_name = match request_type {
+                RequestType::Patch => format_ident!("patch"),
+                RequestType::Put => format_ident!("put"),
+                _ => format_ident!("post"),
+            };
             if params_type.is_some() {
                 quote! {
-                    self.inner.post(url, params)
+                    self.inner.#fn_name(url, params)
                 }
             } else {
                 quote! {
-                    self.inner.post(url)
+                    self.inner.#fn_name(url)
                 }
             }
         }
@@ -364,15 +433,23 @@ pub fn fn_body_get_request_from_request_builder(
     params_type: Option<&ParamsType>,
     request_type: RequestType,
     context_and_filter_handler: &ContextAndFilterHandler,
+    request_options_param: &RequestOptionsParam,
 ) -> TokenStream {
-    let fn_name = fn_name(variant_ident, context_and_filter_handler);
+    let fn_name = fn_name_for_request_options(
+        fn_name(variant_ident, context_and_filter_handler),
+        request_options_param,
+    );
     let fn_arg_url_parts = fn_arg_url_parts(url_parts);
     let fn_arg_provided_params =
         fn_arg_provided_params(PartOf::RequestExecutor, params_type, request_type);
     let fn_arg_fields = fn_arg_fields(context_and_filter_handler);
+    let fn_arg_request_options = match request_options_param {
+        RequestOptionsParam::None => TokenStream::new(),
+        RequestOptionsParam::Provided(_) => quote! { request_options },
+    };
 
     quote! {
-        let request = self.request_builder.#fn_name(#fn_arg_url_parts #fn_arg_provided_params #fn_arg_fields);
+        let request = self.request_builder.#fn_name(#fn_arg_url_parts #fn_arg_provided_params #fn_arg_fields #fn_arg_request_options);
     }
 }
 
@@ -419,12 +496,42 @@ mod tests {
         RequestType::ContextualGet,
         "params : & UserListParams ,"
     )]
+    #[case(
+        PartOf::Endpoint,
+        referenced_params_type("UserUpdateParams"),
+        RequestType::Put,
+        ""
+    )]
+    #[case(
+        PartOf::RequestBuilder,
+        referenced_params_type("UserUpdateParams"),
+        RequestType::Patch,
+        "params : & UserUpdateParams ,"
+    )]
+    #[case(
+        PartOf::Endpoint,
+        referenced_params_type("UserListParams"),
+        RequestType::Options,
+        "params : & UserListParams ,"
+    )]
     #[case(
         PartOf::Endpoint,
         referenced_params_type("UserListParams"),
         RequestType::ContextualGet,
         "params : & UserListParams ,"
     )]
+    #[case(
+        PartOf::Endpoint,
+        referenced_params_type("MediaCreateParams"),
+        RequestType::PostMultipart,
+        ""
+    )]
+    #[case(
+        PartOf::RequestBuilder,
+        referenced_params_type("MediaCreateParams"),
+        RequestType::PostMultipart,
+        "params : & MediaCreateParams ,"
+    )]
     fn test_fn_provided_param(
         #[case] part_of: PartOf,
         #[case] params_type: Option<ParamsType>,
@@ -765,6 +872,7 @@ mod tests {
                 params_type.as_ref(),
                 request_type,
                 &context_and_filter_handler,
+                &RequestOptionsParam::None,
             )
             .to_string(),
             expected_str
@@ -918,6 +1026,13 @@ mod tests {
         "if let Some (params) = params { url . query_pairs_mut () . extend_pairs (params . query_pairs ()) ; }"
     )]
     #[case(option_referenced_params_type("UserListParams"), RequestType::Post, "")]
+    #[case(referenced_params_type("UserUpdateParams"), RequestType::Put, "")]
+    #[case(referenced_params_type("UserUpdateParams"), RequestType::Patch, "")]
+    #[case(
+        referenced_params_type("UserListParams"),
+        RequestType::Options,
+        "url . query_pairs_mut () . extend_pairs (params . query_pairs ()) ;"
+    )]
     fn test_fn_body_query_pairs(
         #[case] params: Option<ParamsType>,
         #[case] request_type: RequestType,
@@ -991,13 +1106,34 @@ mod tests {
         RequestType::Post,
         "self . inner . post (url , params)"
     )]
+    #[case(
+        referenced_params_type("UserUpdateParams"),
+        RequestType::Put,
+        "self . inner . put (url , params)"
+    )]
+    #[case(
+        referenced_params_type("UserUpdateParams"),
+        RequestType::Patch,
+        "self . inner . patch (url , params)"
+    )]
+    #[case(None, RequestType::Options, "self . inner . options (url)")]
+    #[case(
+        referenced_params_type("MediaCreateParams"),
+        RequestType::PostMultipart,
+        "self . inner . post_multipart (url , params . multipart_form ())"
+    )]
     fn test_fn_body_build_request_from_url(
         #[case] params: Option<ParamsType>,
         #[case] request_type: RequestType,
         #[case] expected_str: &str,
     ) {
         assert_eq!(
-            fn_body_build_request_from_url(params.as_ref(), request_type).to_string(),
+            fn_body_build_request_from_url(
+                params.as_ref(),
+                request_type,
+                &RequestOptionsParam::None
+            )
+            .to_string(),
             expected_str
         );
     }
@@ -1094,13 +1230,132 @@ mod tests {
                 &url_parts,
                 params_type.as_ref(),
                 request_type,
-                &context_and_filter_handler
+                &context_and_filter_handler,
+                &RequestOptionsParam::None
+            )
+            .to_string(),
+            expected_str
+        );
+    }
+
+    #[rstest]
+    #[case(
+        PartOf::RequestBuilder,
+        format_ident!("List"),
+        url_static_users(),
+        referenced_params_type("UserListParams"),
+        RequestType::ContextualGet,
+        ContextAndFilterHandler::NoFilterTakeContextAsFunctionName(WpContext::Edit),
+        "fn list_with_edit_context_with_options (& self , params : & UserListParams , request_options : & crate :: request :: WpRequestOptions)")]
+    #[case(
+        PartOf::RequestExecutor,
+        format_ident!("List"),
+        url_static_users(),
+        referenced_params_type("UserListParams"),
+        RequestType::ContextualGet,
+        filter_take_context_as_argument(),
+        "fn filter_list_with_edit_context_with_options (& self , params : & UserListParams , fields : & [crate :: SparseUserFieldWithEditContext] , request_options : & crate :: request :: WpRequestOptions)")]
+    #[case(
+        PartOf::RequestBuilder,
+        format_ident!("Update"),
+        url_users_with_user_id(),
+        referenced_params_type("UserUpdateParams"),
+        RequestType::Post,
+        ContextAndFilterHandler::None,
+        "fn update_with_options (& self , user_id : & UserId , params : & UserUpdateParams , request_options : & crate :: request :: WpRequestOptions)")]
+    fn test_fn_signature_with_request_options(
+        #[case] part_of: PartOf,
+        #[case] variant_ident: Ident,
+        #[case] url_parts: Vec<UrlPart>,
+        #[case] params_type: Option<ParamsType>,
+        #[case] request_type: RequestType,
+        #[case] context_and_filter_handler: ContextAndFilterHandler,
+        #[case] expected_str: &str,
+    ) {
+        assert_eq!(
+            fn_signature(
+                part_of,
+                &variant_ident,
+                &url_parts,
+                params_type.as_ref(),
+                request_type,
+                &context_and_filter_handler,
+                &request_options_param(),
             )
             .to_string(),
             expected_str
         );
     }
 
+    #[rstest]
+    #[case(
+        None,
+        RequestType::Get,
+        "self . inner . get (url) . with_request_options (request_options)"
+    )]
+    #[case(
+        referenced_params_type("UserUpdateParams"),
+        RequestType::Post,
+        "self . inner . post (url , params) . with_request_options (request_options)"
+    )]
+    #[case(
+        referenced_params_type("MediaCreateParams"),
+        RequestType::PostMultipart,
+        "self . inner . post_multipart (url , params . multipart_form ()) . with_request_options (request_options)"
+    )]
+    fn test_fn_body_build_request_from_url_with_request_options(
+        #[case] params: Option<ParamsType>,
+        #[case] request_type: RequestType,
+        #[case] expected_str: &str,
+    ) {
+        assert_eq!(
+            fn_body_build_request_from_url(params.as_ref(), request_type, &request_options_param())
+                .to_string(),
+            expected_str
+        );
+    }
+
+    #[rstest]
+    #[case(
+        format_ident!("List"),
+        url_static_users(),
+        referenced_params_type("UserListParams"),
+        RequestType::ContextualGet,
+        filter_take_context_as_argument(),
+        "let request = self . request_builder . filter_list_with_edit_context_with_options (params , fields , request_options) ;")]
+    #[case(
+        format_ident!("Delete"),
+        url_users_with_user_id(),
+        referenced_params_type("UserDeleteParams"),
+        RequestType::Delete,
+        ContextAndFilterHandler::None,
+        "let request = self . request_builder . delete_with_options (user_id , params , request_options) ;")]
+    fn test_fn_body_get_request_from_request_builder_with_request_options(
+        #[case] variant_ident: Ident,
+        #[case] url_parts: Vec<UrlPart>,
+        #[case] params_type: Option<ParamsType>,
+        #[case] request_type: RequestType,
+        #[case] context_and_filter_handler: ContextAndFilterHandler,
+        #[case] expected_str: &str,
+    ) {
+        assert_eq!(
+            fn_body_get_request_from_request_builder(
+                &variant_ident,
+                &url_parts,
+                params_type.as_ref(),
+                request_type,
+                &context_and_filter_handler,
+                &request_options_param()
+            )
+            .to_string(),
+            expected_str
+        );
+    }
+
+    fn request_options_param() -> RequestOptionsParam {
+        RequestOptionsParam::Provided(quote! { crate::request::WpRequestOptions })
+    }
+
     fn referenced_params_type(str: &str) -> Option<ParamsType> {
         let ident = format_ident!("{}", str);
         Some(ParamsType {
@@ -3,11 +3,23 @@ use syn::parse_macro_input;
 
 mod generate;
 mod parse;
+mod query_params;
 mod variant_attr;
 
 #[proc_macro_derive(
     WpDerivedRequest,
-    attributes(SparseField, Namespace, contextual_get, delete, get, post)
+    attributes(
+        SparseField,
+        Namespace,
+        contextual_get,
+        delete,
+        get,
+        options,
+        patch,
+        post,
+        post_multipart,
+        put
+    )
 )]
 pub fn derive(input: TokenStream) -> TokenStream {
     let parsed_enum = parse_macro_input!(input as parse::ParsedEnum);
@@ -18,3 +30,18 @@ pub fn derive(input: TokenStream) -> TokenStream {
         TokenStream::new()
     }
 }
+
+#[proc_macro_derive(WpQueryParams, attributes(WpQueryParam))]
+pub fn derive_query_params(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    let parsed = match query_params::ParsedQueryParams::parse(&input) {
+        Ok(parsed) => parsed,
+        Err(e) => return e.into_compile_error().into(),
+    };
+
+    if cfg!(feature = "generate_request_builder") {
+        query_params::generate(&parsed, &generate::wp_api_crate_ident()).into()
+    } else {
+        TokenStream::new()
+    }
+}
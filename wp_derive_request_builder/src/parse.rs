@@ -50,5 +50,9 @@ pub(crate) enum RequestType {
     ContextualGet,
     Delete,
     Get,
+    Options,
+    Patch,
     Post,
+    PostMultipart,
+    Put,
 }
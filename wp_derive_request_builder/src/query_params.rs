@@ -0,0 +1,296 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    spanned::Spanned, Data, DeriveInput, Fields, GenericArgument, Ident, LitStr, PathArguments,
+    Type,
+};
+
+const ATTR_NAME: &str = "WpQueryParam";
+
+#[derive(Debug)]
+pub(crate) struct ParsedQueryParams {
+    pub struct_ident: Ident,
+    pub fields: Vec<ParsedQueryParamField>,
+}
+
+#[derive(Debug)]
+pub(crate) struct ParsedQueryParamField {
+    pub field_ident: Ident,
+    pub kind: QueryParamKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum QueryParamKind {
+    // `Option<T>` is serialized as `key=value`
+    Optional { key: String },
+    // `Vec<T>` is serialized as `key=value1,value2`
+    Csv { key: String },
+    // `Vec<T>` is serialized as `key[]=value1&key[]=value2`
+    Array { key: String },
+    Skip,
+}
+
+impl ParsedQueryParams {
+    pub(crate) fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let fields = match &input.data {
+            Data::Struct(data) => match &data.fields {
+                Fields::Named(fields) => fields
+                    .named
+                    .iter()
+                    .map(ParsedQueryParamField::parse)
+                    .collect::<syn::Result<Vec<_>>>()?,
+                _ => {
+                    return Err(QueryParamsParseError::OnlyNamedFields.into_syn_error(input.span()))
+                }
+            },
+            _ => return Err(QueryParamsParseError::OnlyStructs.into_syn_error(input.span())),
+        };
+        Ok(Self {
+            struct_ident: input.ident.clone(),
+            fields,
+        })
+    }
+}
+
+impl ParsedQueryParamField {
+    fn parse(field: &syn::Field) -> syn::Result<Self> {
+        let field_ident = field
+            .ident
+            .clone()
+            .expect("Only named fields are parsed at this point");
+        let mut rename = None;
+        let mut is_array = false;
+        let mut is_skip = false;
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident(ATTR_NAME)) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    let value: LitStr = meta.value()?.parse()?;
+                    rename = Some(value.value());
+                    Ok(())
+                } else if meta.path.is_ident("array") {
+                    is_array = true;
+                    Ok(())
+                } else if meta.path.is_ident("skip") {
+                    is_skip = true;
+                    Ok(())
+                } else {
+                    Err(QueryParamsParseError::UnsupportedAttribute
+                        .into_syn_error(meta.path.span()))
+                }
+            })?;
+        }
+        if is_skip {
+            return Ok(Self {
+                field_ident,
+                kind: QueryParamKind::Skip,
+            });
+        }
+        let key = rename.unwrap_or_else(|| field_ident.to_string());
+        let kind = match outer_type_ident(&field.ty).as_deref() {
+            Some("Option") if is_array => {
+                return Err(QueryParamsParseError::ArrayOnlyForVec.into_syn_error(field.ty.span()))
+            }
+            Some("Option") => QueryParamKind::Optional { key },
+            Some("Vec") if is_array => QueryParamKind::Array { key },
+            Some("Vec") => QueryParamKind::Csv { key },
+            _ => {
+                return Err(
+                    QueryParamsParseError::UnsupportedFieldType.into_syn_error(field.ty.span())
+                )
+            }
+        };
+        Ok(Self { field_ident, kind })
+    }
+}
+
+// Returns the ident of the outer type if it has a single generic argument. For example, given
+// `Option<u32>` it'll return `Option`.
+//
+// TODO: This won't work with `std::option::Option` or `std::vec::Vec`
+fn outer_type_ident(ty: &Type) -> Option<String> {
+    if let Type::Path(type_path) = ty {
+        if type_path.qself.is_none() && type_path.path.segments.len() == 1 {
+            let segment = type_path.path.segments.first()?;
+            if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                if args.args.len() == 1
+                    && matches!(args.args.first(), Some(GenericArgument::Type(_)))
+                {
+                    return Some(segment.ident.to_string());
+                }
+            }
+        }
+    }
+    None
+}
+
+pub(crate) fn generate(parsed: &ParsedQueryParams, crate_ident: &Ident) -> TokenStream {
+    let struct_ident = &parsed.struct_ident;
+    let query_pairs = parsed
+        .fields
+        .iter()
+        .map(|f| fn_body_query_pair(f, crate_ident));
+    let from_query_pairs = parsed.fields.iter().map(fn_body_field_from_query_pairs);
+    quote! {
+        impl #struct_ident {
+            pub fn query_pairs(&self) -> impl IntoIterator<Item = (&str, String)> {
+                let mut query_pairs: Vec<(&str, String)> = Vec::new();
+                #(#query_pairs)*
+                query_pairs
+            }
+
+            pub fn from_query_pairs<K, V>(query_pairs: impl IntoIterator<Item = (K, V)>) -> Self
+            where
+                K: AsRef<str>,
+                V: AsRef<str>,
+            {
+                let query_pairs = #crate_ident::query_params::ParsedQueryPairs::new(query_pairs);
+                Self {
+                    #(#from_query_pairs)*
+                }
+            }
+        }
+    }
+}
+
+fn fn_body_query_pair(field: &ParsedQueryParamField, crate_ident: &Ident) -> TokenStream {
+    let field_ident = &field.field_ident;
+    let helpers = quote! { #crate_ident::query_params };
+    match &field.kind {
+        QueryParamKind::Optional { key } => quote! {
+            if let Some(value) = #helpers::optional_query_value(&self.#field_ident) {
+                query_pairs.push((#key, value));
+            }
+        },
+        QueryParamKind::Csv { key } => quote! {
+            if let Some(value) = #helpers::csv_query_value(&self.#field_ident) {
+                query_pairs.push((#key, value));
+            }
+        },
+        QueryParamKind::Array { key } => {
+            let key = format!("{}[]", key);
+            quote! {
+                query_pairs.extend(
+                    #helpers::array_query_values(&self.#field_ident)
+                        .into_iter()
+                        .map(|value| (#key, value)),
+                );
+            }
+        }
+        QueryParamKind::Skip => TokenStream::new(),
+    }
+}
+
+fn fn_body_field_from_query_pairs(field: &ParsedQueryParamField) -> TokenStream {
+    let field_ident = &field.field_ident;
+    match &field.kind {
+        QueryParamKind::Optional { key } => quote! {
+            #field_ident: query_pairs.optional(#key),
+        },
+        QueryParamKind::Csv { key } => quote! {
+            #field_ident: query_pairs.csv(#key),
+        },
+        QueryParamKind::Array { key } => {
+            let key = format!("{}[]", key);
+            quote! {
+                #field_ident: query_pairs.array(#key),
+            }
+        }
+        QueryParamKind::Skip => quote! {
+            #field_ident: Default::default(),
+        },
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum QueryParamsParseError {
+    #[error("WpQueryParams can only be derived for structs")]
+    OnlyStructs,
+    #[error("WpQueryParams can only be derived for structs with named fields")]
+    OnlyNamedFields,
+    #[error("Only 'rename = \"foo\"', 'array' & 'skip' are supported")]
+    UnsupportedAttribute,
+    #[error("'array' can only be used with 'Vec' fields")]
+    ArrayOnlyForVec,
+    #[error("Only 'Option' & 'Vec' fields are supported. Use #[WpQueryParam(skip)] to ignore it")]
+    UnsupportedFieldType,
+}
+
+impl QueryParamsParseError {
+    fn into_syn_error(self, span: proc_macro2::Span) -> syn::Error {
+        syn::Error::new(span, self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::format_ident;
+    use rstest::rstest;
+    use syn::parse_quote;
+
+    #[rstest]
+    #[case(parse_quote! { pub page: Option<u32> }, QueryParamKind::Optional { key: "page".to_string() })]
+    #[case(parse_quote! { pub include: Vec<UserId> }, QueryParamKind::Csv { key: "include".to_string() })]
+    #[case(parse_quote! { #[WpQueryParam(array)] pub include: Vec<UserId> }, QueryParamKind::Array { key: "include".to_string() })]
+    #[case(parse_quote! { #[WpQueryParam(rename = "type")] pub post_type: Option<String> }, QueryParamKind::Optional { key: "type".to_string() })]
+    #[case(parse_quote! { #[WpQueryParam(rename = "type", array)] pub types: Vec<String> }, QueryParamKind::Array { key: "type".to_string() })]
+    #[case(parse_quote! { #[WpQueryParam(skip)] pub force: bool }, QueryParamKind::Skip)]
+    fn test_parse_field(#[case] field: syn::Field, #[case] expected_kind: QueryParamKind) {
+        assert_eq!(
+            ParsedQueryParamField::parse(&field).unwrap().kind,
+            expected_kind
+        );
+    }
+
+    #[rstest]
+    #[case(parse_quote! { pub force: bool })]
+    #[case(parse_quote! { #[WpQueryParam(array)] pub page: Option<u32> })]
+    #[case(parse_quote! { #[WpQueryParam(unknown)] pub page: Option<u32> })]
+    fn test_parse_field_error(#[case] field: syn::Field) {
+        assert!(ParsedQueryParamField::parse(&field).is_err());
+    }
+
+    #[rstest]
+    #[case(
+        QueryParamKind::Optional { key: "page".to_string() },
+        "if let Some (value) = crate :: query_params :: optional_query_value (& self . foo) { query_pairs . push ((\"page\" , value)) ; }"
+    )]
+    #[case(
+        QueryParamKind::Csv { key: "include".to_string() },
+        "if let Some (value) = crate :: query_params :: csv_query_value (& self . foo) { query_pairs . push ((\"include\" , value)) ; }"
+    )]
+    #[case(
+        QueryParamKind::Array { key: "include".to_string() },
+        "query_pairs . extend (crate :: query_params :: array_query_values (& self . foo) . into_iter () . map (| value | (\"include[]\" , value)) ,) ;"
+    )]
+    #[case(QueryParamKind::Skip, "")]
+    fn test_fn_body_query_pair(#[case] kind: QueryParamKind, #[case] expected_str: &str) {
+        let field = ParsedQueryParamField {
+            field_ident: format_ident!("foo"),
+            kind,
+        };
+        assert_eq!(
+            fn_body_query_pair(&field, &format_ident!("crate")).to_string(),
+            expected_str
+        );
+    }
+
+    #[rstest]
+    #[case(QueryParamKind::Optional { key: "page".to_string() }, "foo : query_pairs . optional (\"page\") ,")]
+    #[case(QueryParamKind::Csv { key: "include".to_string() }, "foo : query_pairs . csv (\"include\") ,")]
+    #[case(QueryParamKind::Array { key: "include".to_string() }, "foo : query_pairs . array (\"include[]\") ,")]
+    #[case(QueryParamKind::Skip, "foo : Default :: default () ,")]
+    fn test_fn_body_field_from_query_pairs(
+        #[case] kind: QueryParamKind,
+        #[case] expected_str: &str,
+    ) {
+        let field = ParsedQueryParamField {
+            field_ident: format_ident!("foo"),
+            kind,
+        };
+        assert_eq!(
+            fn_body_field_from_query_pairs(&field).to_string(),
+            expected_str
+        );
+    }
+}
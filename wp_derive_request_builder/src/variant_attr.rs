@@ -113,7 +113,11 @@ impl ParsedVariantAttribute {
             "contextual_get" => Ok(RequestType::ContextualGet),
             "delete" => Ok(RequestType::Delete),
             "get" => Ok(RequestType::Get),
+            "options" => Ok(RequestType::Options),
+            "patch" => Ok(RequestType::Patch),
             "post" => Ok(RequestType::Post),
+            "post_multipart" => Ok(RequestType::PostMultipart),
+            "put" => Ok(RequestType::Put),
             _ => build_err(path_segment.ident.span()),
         }
     }
@@ -224,6 +228,73 @@ impl ParsedVariantAttribute {
     }
 }
 
+impl ParsedVariantAttribute {
+    // Params are always passed by reference to the generated functions, so we expect them to be
+    // declared as such: `params = &UserListParams`
+    //
+    // Errors:
+    // * `ParamsShouldBeReference` if the first token is not `&`
+    fn validate_params(params_tokens: &[TokenTree]) -> syn::Result<()> {
+        match params_tokens.first() {
+            Some(TokenTree::Punct(p)) if p.as_char() == '&' => Ok(()),
+            Some(t) => {
+                Err(ItemVariantAttributeParseError::ParamsShouldBeReference
+                    .into_syn_error(t.span()))
+            }
+            // Empty params are ignored
+            None => Ok(()),
+        }
+    }
+
+    // The generated `filter_*` functions of a `contextual_get` request return the sparse type,
+    // so the output should have a `Sparse*` type and its `filter_by` should be the field type
+    // of the same sparse type.
+    //
+    // ```
+    // #[contextual_get(url = "/users", output = Vec<crate::SparseUser>, filter_by = crate::SparseUserField)]
+    // ```
+    //
+    // Errors:
+    // * `ContextualGetOutputShouldBeSparse` if the output doesn't have a `Sparse*` type
+    // * `FilterByDoesNotMatchOutput` if the last segment of `filter_by` is not `{Sparse*}Field`
+    fn validate_contextual_get_output(
+        output: &[TokenTree],
+        filter_by: Option<&[TokenTree]>,
+    ) -> syn::Result<()> {
+        let output_span = output
+            .first()
+            .map(|t| t.span())
+            .unwrap_or_else(Span::call_site);
+        let sparse_type = output
+            .iter()
+            .rev()
+            .find_map(|t| match t {
+                TokenTree::Ident(ident) if ident.to_string().starts_with("Sparse") => Some(ident),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                ItemVariantAttributeParseError::ContextualGetOutputShouldBeSparse
+                    .into_syn_error(output_span)
+            })?;
+        if let Some(filter_by) = filter_by {
+            let expected = format!("{}Field", sparse_type);
+            let filter_by_ident = filter_by.iter().rev().find_map(|t| match t {
+                TokenTree::Ident(ident) => Some(ident),
+                _ => None,
+            });
+            if filter_by_ident.map(|i| i.to_string()) != Some(expected.clone()) {
+                let span = filter_by_ident.map(|i| i.span()).unwrap_or(output_span);
+                return Err(ItemVariantAttributeParseError::FilterByDoesNotMatchOutput {
+                    expected,
+                    output: sparse_type.to_string(),
+                }
+                .into_syn_error(span));
+            }
+        }
+        Ok(())
+    }
+}
+
 impl Parse for ParsedVariantAttribute {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let meta_list = Self::meta_list(input)?;
@@ -276,7 +347,18 @@ impl Parse for ParsedVariantAttribute {
             ItemVariantAttributeParseError::MissingOutput.into_syn_error(input.span())
         })?;
 
-        let url_parts = UrlPart::split(url_str.to_string(), &meta_list_span)?;
+        let url_parts = UrlPart::split(url_str.to_string(), &url_str.span())?;
+
+        if let Some(params_tokens) = params_tokens.as_ref() {
+            Self::validate_params(params_tokens)?;
+        } else if matches!(request_type, RequestType::PostMultipart) {
+            return Err(
+                ItemVariantAttributeParseError::MultipartMissingParams.into_syn_error(input.span())
+            );
+        }
+        if matches!(request_type, RequestType::ContextualGet) {
+            Self::validate_contextual_get_output(&output, filter_by_tokens.as_deref())?;
+        }
 
         Ok(ParsedVariantAttribute::new(
             request_type,
@@ -306,8 +388,31 @@ enum ItemVariantAttributeParseError {
     UrlShouldBeLiteral,
     #[error("Missing (output = crate::Foo)")]
     MissingOutput,
-    #[error("Only 'contextual_get', 'get', 'post' & 'delete' are supported")]
+    #[error("'post_multipart' requests build their body from params: (params = &FooParams)")]
+    MultipartMissingParams,
+    #[error(
+        "Only 'contextual_get', 'get', 'post', 'post_multipart', 'put', 'patch', 'delete' & 'options' are supported"
+    )]
     UnsupportedRequestType,
+    #[error("Url should start with a '/': (url = \"/foo\")")]
+    UrlMissingLeadingSlash,
+    #[error(
+        "Url placeholders should be a whole path segment in the form of '<foo>', found '{}'",
+        segment
+    )]
+    UnbalancedUrlPlaceholder { segment: String },
+    #[error("Url placeholder '<{}>' is used more than once", name)]
+    DuplicateUrlPlaceholder { name: String },
+    #[error("Params should be a reference: (params = &FooParams)")]
+    ParamsShouldBeReference,
+    #[error("'contextual_get' output should have a Sparse type: (output = crate::SparseFoo)")]
+    ContextualGetOutputShouldBeSparse,
+    #[error(
+        "Expecting 'filter_by = {}' to match the output type '{}'",
+        expected,
+        output
+    )]
+    FilterByDoesNotMatchOutput { expected: String, output: String },
 }
 
 impl ItemVariantAttributeParseError {
@@ -332,43 +437,114 @@ impl UrlPart {
                 ItemVariantAttributeParseError::UrlShouldBeLiteral.into_syn_error(*error_span)
             );
         }
+        if !url.starts_with('/') {
+            return Err(
+                ItemVariantAttributeParseError::UrlMissingLeadingSlash.into_syn_error(*error_span)
+            );
+        }
         let parts = url
             .split('/')
-            .filter_map(|p| {
-                if p.starts_with('<') && p.ends_with('>') {
-                    let mut p = p.to_string();
-                    // Remove first and last character
-                    p.pop();
-                    p.remove(0);
-                    Some(Self::Dynamic(p))
-                } else {
-                    let p = p.trim();
-                    if p.is_empty() {
-                        None
-                    } else {
-                        Some(Self::Static(p.to_string()))
+            .filter_map(|p| Self::parse_segment(p, error_span).transpose())
+            .collect::<syn::Result<Vec<Self>>>()?;
+
+        let mut placeholders = std::collections::HashSet::new();
+        for p in parts.iter() {
+            if let Self::Dynamic(name) = p {
+                if !placeholders.insert(name) {
+                    return Err(ItemVariantAttributeParseError::DuplicateUrlPlaceholder {
+                        name: name.to_string(),
                     }
+                    .into_syn_error(*error_span));
                 }
-            })
-            .collect::<Vec<Self>>();
+            }
+        }
         Ok(parts)
     }
+
+    fn parse_segment(segment: &str, error_span: &Span) -> syn::Result<Option<Self>> {
+        if !segment.contains(['<', '>']) {
+            let segment = segment.trim();
+            return Ok(if segment.is_empty() {
+                None
+            } else {
+                Some(Self::Static(segment.to_string()))
+            });
+        }
+        segment
+            .strip_prefix('<')
+            .and_then(|s| s.strip_suffix('>'))
+            .filter(|name| !name.is_empty() && !name.contains(['<', '>']))
+            .map(|name| Some(Self::Dynamic(name.to_string())))
+            .ok_or_else(|| {
+                ItemVariantAttributeParseError::UnbalancedUrlPlaceholder {
+                    segment: segment.to_string(),
+                }
+                .into_syn_error(*error_span)
+            })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use quote::quote;
     use rstest::rstest;
 
     #[rstest]
-    #[case("\"users\"", &[UrlPart::Static("users".to_string())])]
-    #[case("\"<user_id>\"", &[UrlPart::Dynamic("user_id".to_string())])]
-    #[case("\"users/<user_id>\"", &[UrlPart::Static("users".to_string()), UrlPart::Dynamic("user_id".to_string())])]
-    #[case("\"users/<user_id>/<user_type>\"", &[UrlPart::Static("users".to_string()), UrlPart::Dynamic("user_id".to_string()), UrlPart::Dynamic("user_type".to_string())])]
+    #[case("\"/users\"", &[UrlPart::Static("users".to_string())])]
+    #[case("\"/<user_id>\"", &[UrlPart::Dynamic("user_id".to_string())])]
+    #[case("\"/users/<user_id>\"", &[UrlPart::Static("users".to_string()), UrlPart::Dynamic("user_id".to_string())])]
+    #[case("\"/users/<user_id>/<user_type>\"", &[UrlPart::Static("users".to_string()), UrlPart::Dynamic("user_id".to_string()), UrlPart::Dynamic("user_type".to_string())])]
     fn test_fn_url_params(#[case] input: &str, #[case] expected_url_parts: &[UrlPart]) {
         assert_eq!(
             UrlPart::split(input.into(), &proc_macro2::Span::call_site()).unwrap(),
             expected_url_parts
         );
     }
+
+    #[rstest]
+    #[case("\"users\"")]
+    #[case("\"/users/<user_id\"")]
+    #[case("\"/users/user_id>\"")]
+    #[case("\"/users/<>\"")]
+    #[case("\"/users/<<user_id>>\"")]
+    #[case("\"/users/me<user_id>\"")]
+    #[case("\"/users/<user_id>/<user_id>\"")]
+    fn test_fn_url_params_error(#[case] input: &str) {
+        assert!(UrlPart::split(input.into(), &proc_macro2::Span::call_site()).is_err());
+    }
+
+    #[rstest]
+    #[case(quote! { Vec<SparseUser> }, None)]
+    #[case(quote! { Vec<crate::SparseUser> }, Some(quote! { crate::SparseUserField }))]
+    #[case(quote! { crate::post_types::SparsePostTypesResponse }, None)]
+    fn test_validate_contextual_get_output(
+        #[case] output: TokenStream,
+        #[case] filter_by: Option<TokenStream>,
+    ) {
+        let output = output.into_iter().collect::<Vec<_>>();
+        let filter_by = filter_by.map(|t| t.into_iter().collect::<Vec<_>>());
+        assert!(ParsedVariantAttribute::validate_contextual_get_output(
+            &output,
+            filter_by.as_deref()
+        )
+        .is_ok());
+    }
+
+    #[rstest]
+    #[case(quote! { Vec<User> }, None)]
+    #[case(quote! { Vec<SparseUser> }, Some(quote! { SparsePostField }))]
+    #[case(quote! { SparseUser }, Some(quote! { SparseUserField::Name }))]
+    fn test_validate_contextual_get_output_error(
+        #[case] output: TokenStream,
+        #[case] filter_by: Option<TokenStream>,
+    ) {
+        let output = output.into_iter().collect::<Vec<_>>();
+        let filter_by = filter_by.map(|t| t.into_iter().collect::<Vec<_>>());
+        assert!(ParsedVariantAttribute::validate_contextual_get_output(
+            &output,
+            filter_by.as_deref()
+        )
+        .is_err());
+    }
 }
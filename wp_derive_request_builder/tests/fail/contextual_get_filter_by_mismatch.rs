@@ -0,0 +1,7 @@
+#[derive(wp_derive_request_builder::WpDerivedRequest)]
+enum UsersRequest {
+    #[contextual_get(url = "/users", output = Vec<SparseUser>, filter_by = SparsePostField)]
+    List,
+}
+
+fn main() {}
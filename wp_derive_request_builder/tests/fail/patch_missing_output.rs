@@ -0,0 +1,7 @@
+#[derive(wp_derive_request_builder::WpDerivedRequest)]
+enum PostsRequest {
+    #[patch(url = "/posts/<post_id>", params = &PostUpdateParams)]
+    Patch,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(wp_derive_request_builder::WpDerivedRequest)]
+enum MediaRequest {
+    #[post_multipart(url = "/media", output = MediaWithEditContext)]
+    Create,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(wp_derive_request_builder::WpDerivedRequest)]
+enum PostsRequest {
+    #[put(params = &PostUpdateParams, output = SparsePost)]
+    Update,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(wp_derive_request_builder::WpQueryParams)]
+struct PostListParams {
+    #[WpQueryParam(array)]
+    page: Option<u32>,
+}
+
+fn main() {}
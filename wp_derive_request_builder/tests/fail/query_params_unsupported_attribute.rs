@@ -0,0 +1,7 @@
+#[derive(wp_derive_request_builder::WpQueryParams)]
+struct PostListParams {
+    #[WpQueryParam(csv)]
+    include: Vec<PostId>,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[derive(wp_derive_request_builder::WpQueryParams)]
+struct UserDeleteParams {
+    reassign: UserId,
+}
+
+fn main() {}
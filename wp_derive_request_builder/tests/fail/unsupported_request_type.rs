@@ -0,0 +1,7 @@
+#[derive(wp_derive_request_builder::WpDerivedRequest)]
+enum PostsRequest {
+    #[head(url = "/posts", output = SparsePost)]
+    Head,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(wp_derive_request_builder::WpDerivedRequest)]
+enum UsersRequest {
+    #[contextual_get(url = "/users/<user_id>/posts/<user_id>", output = SparseUser)]
+    Retrieve,
+}
+
+fn main() {}
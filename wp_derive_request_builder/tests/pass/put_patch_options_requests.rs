@@ -0,0 +1,11 @@
+#[derive(wp_derive_request_builder::WpDerivedRequest)]
+enum PostsRequest {
+    #[options(url = "/posts", output = PostsSchema)]
+    Options,
+    #[patch(url = "/posts/<post_id>", params = &PostUpdateParams, output = SparsePost)]
+    Patch,
+    #[put(url = "/posts/<post_id>", params = &PostUpdateParams, output = SparsePost)]
+    Update,
+}
+
+fn main() {}
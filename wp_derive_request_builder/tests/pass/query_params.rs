@@ -0,0 +1,16 @@
+struct PostId(i32);
+struct TagId(i32);
+
+#[derive(wp_derive_request_builder::WpQueryParams)]
+struct PostListParams {
+    page: Option<u32>,
+    include: Vec<PostId>,
+    #[WpQueryParam(rename = "type")]
+    post_type: Option<String>,
+    #[WpQueryParam(array)]
+    tags: Vec<TagId>,
+    #[WpQueryParam(skip)]
+    force: bool,
+}
+
+fn main() {}
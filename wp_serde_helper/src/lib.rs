@@ -43,6 +43,74 @@ where
     deserializer.deserialize_any(DeserializeI64OrStringVisitor)
 }
 
+struct DeserializeOptionU32OrStringVisitor;
+
+impl<'de> de::Visitor<'de> for DeserializeOptionU32OrStringVisitor {
+    type Value = Option<u32>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("u32, a string or null")
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        u32::try_from(v)
+            .map(Some)
+            .map_err(|_| E::invalid_value(Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        u32::try_from(v)
+            .map(Some)
+            .map_err(|_| E::invalid_value(Unexpected::Signed(v), &self))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if v >= 0.0 && v <= u32::MAX as f64 {
+            Ok(Some(v.round() as u32))
+        } else {
+            Err(E::invalid_value(Unexpected::Float(v), &self))
+        }
+    }
+
+    // Values that aren't numbers, such as `100%`, are treated as missing
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(v.trim().parse::<u32>().ok())
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+}
+
+pub fn deserialize_option_u32_or_string<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(DeserializeOptionU32OrStringVisitor)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -66,4 +134,25 @@ mod tests {
         let foo: Foo = serde_json::from_str(test_case).expect("Test case should be a valid JSON");
         assert_eq!(expected_result, foo.bar);
     }
+
+    #[derive(Debug, Deserialize)]
+    pub struct Baz {
+        #[serde(default, deserialize_with = "deserialize_option_u32_or_string")]
+        pub qux: Option<u32>,
+    }
+
+    #[rstest]
+    #[case(r#"{"qux": "1"}"#, Some(1))]
+    #[case(r#"{"qux": 1}"#, Some(1))]
+    #[case(r#"{"qux": 1.0}"#, Some(1))]
+    #[case(r#"{"qux": "100%"}"#, None)]
+    #[case(r#"{"qux": null}"#, None)]
+    #[case(r#"{}"#, None)]
+    fn test_deserialize_option_u32_or_string(
+        #[case] test_case: &str,
+        #[case] expected_result: Option<u32>,
+    ) {
+        let baz: Baz = serde_json::from_str(test_case).expect("Test case should be a valid JSON");
+        assert_eq!(expected_result, baz.qux);
+    }
 }